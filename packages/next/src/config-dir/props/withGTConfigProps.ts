@@ -28,6 +28,77 @@ export type CompilerOptions = {
    * @default false
    */
   disableBuildChecks?: boolean;
+  /**
+   * Wrap dynamic content inside <T> in <Var> at build time instead of failing the build.
+   * Requires compileTimeHash.
+   * @default false
+   */
+  autoWrapVar?: boolean;
+  /**
+   * Rewrite interpolated template literals passed to t() and msg() into ICU messages at build
+   * time, e.g. t(`Hello ${name}`) becomes t('Hello {name}', { name }). Requires compileTimeHash.
+   * @default false
+   */
+  autoIcuTemplates?: boolean;
+  /**
+   * Directory the compiler plugin writes a JSON extraction manifest per file to, listing every
+   * entry it hashed. The path is resolved inside the plugin's WASI sandbox.
+   * Requires compileTimeHash.
+   */
+  manifestDir?: string;
+  /**
+   * Locales of the app, the source locale first. <Plural> components are then checked for a
+   * branch for every plural category of the source locale, e.g. 'few' and 'many' for 'pl'.
+   */
+  locales?: string[];
+  /**
+   * Per-rule severity for build checks. Only 'error' fails the build.
+   * @default 'error', except 'unused-suppression', 'dictionary-dynamic-key',
+   * 'dictionary-unused-entry', 'icu-missing-variable', 'icu-unused-variable' and the
+   * 'plural-*' and 'branch-*' rules which warn. 'dictionary-unused-entry' is only reported
+   * by the `gt-swc check` CLI, which sees every file.
+   */
+  rules?: Partial<
+    Record<
+      | 'jsx-dynamic-content'
+      | 't-dynamic-argument'
+      | 'derive-shape'
+      | 'unused-suppression'
+      | 'dictionary-dynamic-key'
+      | 'dictionary-missing-key'
+      | 'dictionary-namespace-key'
+      | 'dictionary-unused-entry'
+      | 'message-syntax'
+      | 'icu-missing-variable'
+      | 'icu-unused-variable'
+      | 'unsupported-format'
+      | 'plural-missing-n'
+      | 'plural-missing-branch'
+      | 'plural-unreachable-branch'
+      | 'branch-missing-prop'
+      | 'branch-unmatched-key'
+      | 'branch-empty'
+      | 'branch-ignored-children',
+      'off' | 'warn' | 'error'
+    >
+  >;
+  /**
+   * Dictionary used to validate useTranslations()/getTranslations() keys at build time.
+   * Either the dictionary object, a path to a JSON dictionary file, or `true` for the app's
+   * own JSON dictionary. The build fails when the file cannot be read.
+   */
+  dictionary?: true | string | Record<string, unknown>;
+  /**
+   * Extra modules to track GT imports from, e.g. a wrapper that re-exports gt-next.
+   * Use `{ source, preset }` when the wrapper re-exports another GT package.
+   */
+  importSources?: Array<
+    | string
+    | {
+        source: string;
+        preset: 'next' | 'react' | 'react-native' | 'tanstack-start' | 'i18n';
+      }
+  >;
   /**
    * Whether to automatically wrap translatable JSX.
    * @default false
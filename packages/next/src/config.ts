@@ -23,6 +23,7 @@ import {
   standardizedLocalesWarning,
   unresolvedLoadDictionaryBuildError,
   unresolvedLoadTranslationsBuildError,
+  unreadableCompilerDictionaryBuildError,
 } from './errors/createErrors';
 import { compilePathRegex } from './utils/pathRegex';
 import {
@@ -699,8 +700,29 @@ export function withGTConfig<TNextConfig extends object = NextConfig>(
       ? rawAutoderive
       : (rawAutoderive.strings ?? false);
 
+  // Inline the dictionary so the plugin doesn't need filesystem access. `true` checks keys
+  // against the dictionary resolved above.
+  let compilerDictionary = compilerOptions.dictionary;
+  if (compilerDictionary === true || typeof compilerDictionary === 'string') {
+    const dictionaryPath =
+      compilerDictionary === true ? resolvedDictionaryFilePath : compilerDictionary;
+    try {
+      if (!dictionaryPath?.endsWith('.json')) {
+        throw new Error('The dictionary must be a JSON file');
+      }
+      compilerDictionary = JSON.parse(
+        fs.readFileSync(dictionaryPath, 'utf-8')
+      );
+    } catch (error) {
+      throw new Error(
+        unreadableCompilerDictionaryBuildError(dictionaryPath, error as Error)
+      );
+    }
+  }
+
   const swcPluginOptions: Record<string, unknown> = {
     ...compilerOptions,
+    dictionary: compilerDictionary,
     autoderiveJsx,
     autoderiveStrings,
   };
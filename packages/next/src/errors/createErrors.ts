@@ -70,6 +70,17 @@ export const unresolvedLoadTranslationsBuildError = (path: string) =>
     fix: 'Check the configured path and try again',
   });
 
+export const unreadableCompilerDictionaryBuildError = (
+  path: string | undefined,
+  error: Error
+) =>
+  createGtNextDiagnostic({
+    severity: 'Error',
+    whatHappened: `The compiler dictionary could not be read${path ? ` from ${path}` : ''}`,
+    fix: 'Point experimentalCompilerOptions.dictionary at a JSON dictionary file',
+    details: error.message,
+  });
+
 export const conflictingConfigurationBuildError = (conflicts: string[]) =>
   `gt-next Error: Conflicting configuration${
     conflicts.length > 1 ? 's' : ''
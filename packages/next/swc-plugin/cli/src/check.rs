@@ -0,0 +1,228 @@
+use std::fmt;
+use std::path::PathBuf;
+
+use gt_swc_core::{
+  config::{PluginConfig, PluginSettings, RuleSeverity},
+  logging::LogLevel,
+  source::transform_source,
+  visitor::errors::{create_unused_dictionary_entry_warning, DiagnosticCode, GtDiagnostic},
+};
+use rayon::prelude::*;
+
+use crate::files::read_source;
+
+/// A build check violation with the location it was found at
+#[derive(Debug)]
+pub struct Violation {
+  /// `path:line:column`, or what the violation is about when it has no span
+  pub location: String,
+  pub severity: RuleSeverity,
+  pub diagnostic: GtDiagnostic,
+}
+
+impl fmt::Display for Violation {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let severity = match self.severity {
+      RuleSeverity::Error => "error",
+      _ => "warning",
+    };
+    write!(
+      f,
+      "{}: {severity}[{}]: {}",
+      self.location,
+      self.diagnostic.code.as_str(),
+      self.diagnostic.message
+    )
+  }
+}
+
+/// Violations of a single file and the dictionary keys it uses
+#[derive(Debug, Default)]
+pub struct FileCheck {
+  pub violations: Vec<Violation>,
+  pub dictionary_keys: Vec<Option<String>>,
+}
+
+/// Result of checking a set of files
+#[derive(Debug, Default)]
+pub struct CheckOutcome {
+  pub violations: Vec<Violation>,
+  /// Files that could not be read or parsed
+  pub failures: Vec<String>,
+}
+
+impl CheckOutcome {
+  /// Whether the check should fail: an error-level violation or a file that wasn't checked
+  pub fn has_errors(&self) -> bool {
+    !self.failures.is_empty()
+      || self
+        .violations
+        .iter()
+        .any(|violation| violation.severity == RuleSeverity::Error)
+  }
+}
+
+/// Run the build checks on a single source file
+pub fn check_source(
+  source: &str,
+  filename: &str,
+  config: &PluginConfig,
+) -> Result<FileCheck, String> {
+  let (report, source_map) = transform_source(source, filename, config)?;
+  let violations = report
+    .findings
+    .into_iter()
+    .map(|finding| {
+      let loc = source_map.lookup_char_pos(finding.diagnostic.span.lo);
+      Violation {
+        location: format!("{filename}:{}:{}", loc.line, loc.col_display + 1),
+        severity: finding.severity,
+        diagnostic: finding.diagnostic,
+      }
+    })
+    .collect();
+  Ok(FileCheck {
+    violations,
+    dictionary_keys: report.dictionary_keys,
+  })
+}
+
+/// Dictionary entries no file uses. Nothing is reported when a key could not be resolved at
+/// build time, since it may be the one using an entry.
+pub fn unused_dictionary_entries(
+  settings: &PluginSettings,
+  dictionary_keys: &[Option<String>],
+) -> Vec<Violation> {
+  let severity = settings.severity(DiagnosticCode::UnusedDictionaryEntry);
+  let Some(dictionary) = &settings.dictionary else {
+    return Vec::new();
+  };
+  let Some(used) = dictionary_keys
+    .iter()
+    .map(Option::as_deref)
+    .collect::<Option<Vec<_>>>()
+  else {
+    return Vec::new();
+  };
+  if severity == RuleSeverity::Off {
+    return Vec::new();
+  }
+  dictionary
+    .unused_entries(&used)
+    .into_iter()
+    .map(|key| Violation {
+      location: "dictionary".to_string(),
+      severity,
+      diagnostic: create_unused_dictionary_entry_warning(key),
+    })
+    .collect()
+}
+
+/// Check every file in parallel, then look for dictionary entries none of them use
+pub fn run(files: &[PathBuf], config: &PluginConfig) -> CheckOutcome {
+  let config = PluginConfig {
+    log_level: LogLevel::Silent,
+    disable_build_checks: false,
+    ..config.clone()
+  };
+  let results: Vec<Result<FileCheck, String>> = files
+    .par_iter()
+    .map(|path| {
+      let (source, filename) = read_source(path)?;
+      check_source(&source, &filename, &config)
+    })
+    .collect();
+
+  let mut outcome = CheckOutcome::default();
+  let mut dictionary_keys = Vec::new();
+  for result in results {
+    match result {
+      Ok(file) => {
+        outcome.violations.extend(file.violations);
+        dictionary_keys.extend(file.dictionary_keys);
+      }
+      Err(error) => outcome.failures.push(error),
+    }
+  }
+  // Keys of files that failed to parse are unknown
+  if outcome.failures.is_empty() {
+    let settings = PluginSettings::from_config(&config, None);
+    outcome
+      .violations
+      .extend(unused_dictionary_entries(&settings, &dictionary_keys));
+  }
+  outcome
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use gt_swc_core::dictionary::Dictionary;
+  use serde_json::json;
+  use std::sync::Arc;
+
+  fn config_with_dictionary() -> PluginConfig {
+    PluginConfig {
+      log_level: LogLevel::Silent,
+      dictionary: Some(Arc::new(
+        Dictionary::from_json(&json!({ "nav": { "home": "Home", "about": "About" } })).unwrap(),
+      )),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn reports_violations_with_locations() {
+    let source = "import { T } from 'gt-next';\n\nexport const el = <T>Hello {name}</T>;\n";
+    let file = check_source(source, "app/page.tsx", &config_with_dictionary()).unwrap();
+
+    assert_eq!(file.violations.len(), 1);
+    let violation = file.violations[0].to_string();
+    assert!(
+      violation.starts_with("app/page.tsx:3:29: error[GT001]: "),
+      "{violation}"
+    );
+  }
+
+  #[test]
+  fn collects_dictionary_keys() {
+    let source = "import { useTranslations } from 'gt-next';\n\
+      export function Nav() { const t = useTranslations('nav'); return t('home'); }\n";
+    let file = check_source(source, "nav.tsx", &config_with_dictionary()).unwrap();
+
+    assert!(file.violations.is_empty());
+    assert_eq!(file.dictionary_keys, vec![Some("nav.home".to_string())]);
+  }
+
+  #[test]
+  fn reports_entries_no_file_uses() {
+    let settings = PluginSettings::from_config(&config_with_dictionary(), None);
+    let violations = unused_dictionary_entries(&settings, &[Some("nav.home".to_string())]);
+
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].severity, RuleSeverity::Warn);
+    assert_eq!(
+      violations[0].diagnostic.code,
+      DiagnosticCode::UnusedDictionaryEntry
+    );
+    assert!(violations[0].diagnostic.message.contains("nav.about"));
+  }
+
+  #[test]
+  fn skips_unused_entries_when_a_key_is_dynamic() {
+    let settings = PluginSettings::from_config(&config_with_dictionary(), None);
+    let keys = [Some("nav.home".to_string()), None];
+
+    assert!(unused_dictionary_entries(&settings, &keys).is_empty());
+  }
+
+  #[test]
+  fn fails_on_unparsable_files() {
+    let outcome = CheckOutcome {
+      failures: vec!["failed to parse broken.tsx".to_string()],
+      ..Default::default()
+    };
+
+    assert!(outcome.has_errors());
+  }
+}
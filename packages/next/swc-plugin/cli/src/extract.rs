@@ -0,0 +1,74 @@
+use std::path::PathBuf;
+
+use gt_swc_core::{config::PluginConfig, manifest::Manifest, source::extract_with_config};
+use rayon::prelude::*;
+
+use crate::files::read_source;
+
+/// Result of extracting a set of files
+#[derive(Debug, Default)]
+pub struct ExtractOutcome {
+  /// Manifests of the files with at least one entry, in file order
+  pub manifests: Vec<Manifest>,
+  /// Files that could not be read or parsed
+  pub failures: Vec<String>,
+}
+
+impl ExtractOutcome {
+  pub fn to_json(&self) -> serde_json::Result<String> {
+    serde_json::to_string_pretty(&self.manifests)
+  }
+}
+
+/// Extract the `<T>` and t() entries of every file in parallel
+pub fn run(files: &[PathBuf], config: &PluginConfig) -> ExtractOutcome {
+  let results: Vec<Result<Manifest, String>> = files
+    .par_iter()
+    .map(|path| {
+      let (source, filename) = read_source(path)?;
+      extract_with_config(&source, &filename, config)
+    })
+    .collect();
+
+  let mut outcome = ExtractOutcome::default();
+  for result in results {
+    match result {
+      Ok(manifest) if manifest.entries.is_empty() => {}
+      Ok(manifest) => outcome.manifests.push(manifest),
+      Err(error) => outcome.failures.push(error),
+    }
+  }
+  outcome
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn extracts_files_with_entries() {
+    let scratch = tempfile::tempdir().unwrap();
+    let dir = scratch.path();
+    let page = dir.join("page.tsx");
+    let util = dir.join("util.ts");
+    let broken = dir.join("broken.tsx");
+    std::fs::write(
+      &page,
+      "import { T } from 'gt-next';\nexport const el = <T>Hello</T>;\n",
+    )
+    .unwrap();
+    std::fs::write(&util, "export const answer = 42;\n").unwrap();
+    std::fs::write(&broken, "const = ;").unwrap();
+
+    let outcome = run(&[page.clone(), util, broken], &PluginConfig::default());
+
+    assert_eq!(outcome.manifests.len(), 1);
+    assert_eq!(
+      outcome.manifests[0].filename,
+      Some(page.display().to_string())
+    );
+    assert_eq!(outcome.failures.len(), 1);
+    let json: serde_json::Value = serde_json::from_str(&outcome.to_json().unwrap()).unwrap();
+    assert_eq!(json[0]["entries"][0]["format"], "JSX");
+  }
+}
@@ -0,0 +1,141 @@
+use std::path::{Path, PathBuf};
+
+use gt_swc_core::config::PluginConfig;
+use ignore::WalkBuilder;
+use serde_json::Value;
+
+/// Extensions of the files the transform parses
+const SOURCE_EXTENSIONS: &[&str] = &["js", "jsx", "ts", "tsx"];
+
+/// Source files under `paths`, sorted. Directories are walked respecting .gitignore, and
+/// node_modules is always skipped.
+pub fn collect_source_files(paths: &[PathBuf]) -> Result<Vec<PathBuf>, String> {
+  let Some((first, rest)) = paths.split_first() else {
+    return Ok(Vec::new());
+  };
+  let mut builder = WalkBuilder::new(first);
+  for path in rest {
+    builder.add(path);
+  }
+  builder
+    .require_git(false)
+    .filter_entry(|entry| entry.file_name() != "node_modules");
+
+  let mut files = Vec::new();
+  for entry in builder.build() {
+    let entry = entry.map_err(|error| error.to_string())?;
+    let path = entry.path();
+    let is_source = path
+      .extension()
+      .and_then(|extension| extension.to_str())
+      .is_some_and(|extension| SOURCE_EXTENSIONS.contains(&extension));
+    if is_source
+      && entry
+        .file_type()
+        .is_some_and(|file_type| file_type.is_file())
+    {
+      files.push(path.strip_prefix(".").unwrap_or(path).to_path_buf());
+    }
+  }
+  files.sort();
+  files.dedup();
+  Ok(files)
+}
+
+/// Read plugin options from a JSON file, as passed to `swcPluginOptions`. A dictionary given
+/// as a path is read once here instead of once per file.
+pub fn load_config(path: Option<&Path>) -> Result<PluginConfig, String> {
+  let Some(path) = path else {
+    return Ok(PluginConfig::default());
+  };
+  let contents = std::fs::read_to_string(path)
+    .map_err(|error| format!("failed to read {}: {error}", path.display()))?;
+  let mut options: Value = serde_json::from_str(&contents)
+    .map_err(|error| format!("failed to parse {}: {error}", path.display()))?;
+  if let Some(Value::String(dictionary_path)) = options.get("dictionary") {
+    let contents = std::fs::read_to_string(dictionary_path)
+      .map_err(|error| format!("failed to read dictionary {dictionary_path}: {error}"))?;
+    let dictionary: Value = serde_json::from_str(&contents)
+      .map_err(|error| format!("failed to parse dictionary {dictionary_path}: {error}"))?;
+    options["dictionary"] = dictionary;
+  }
+  PluginConfig::from_json(&options.to_string())
+    .map_err(|error| format!("failed to parse {}: {error}", path.display()))
+}
+
+/// Read a source file and name it the way diagnostics and manifests refer to it
+pub fn read_source(path: &Path) -> Result<(String, String), String> {
+  let filename = path.display().to_string();
+  let source =
+    std::fs::read_to_string(path).map_err(|error| format!("failed to read {filename}: {error}"))?;
+  Ok((source, filename))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use gt_swc_core::dictionary::DictionaryEntry;
+
+  #[test]
+  fn collects_sources_and_skips_node_modules() {
+    let scratch = tempfile::tempdir().unwrap();
+    let dir = scratch.path().to_path_buf();
+    std::fs::create_dir_all(dir.join("app")).unwrap();
+    std::fs::create_dir_all(dir.join("node_modules/gt-next")).unwrap();
+    std::fs::write(dir.join("app/page.tsx"), "").unwrap();
+    std::fs::write(dir.join("app/util.js"), "").unwrap();
+    std::fs::write(dir.join("app/styles.css"), "").unwrap();
+    std::fs::write(dir.join("node_modules/gt-next/index.js"), "").unwrap();
+
+    let files = collect_source_files(std::slice::from_ref(&dir)).unwrap();
+
+    assert_eq!(
+      files,
+      vec![dir.join("app/page.tsx"), dir.join("app/util.js")]
+    );
+  }
+
+  #[test]
+  fn inlines_a_dictionary_path() {
+    let scratch = tempfile::tempdir().unwrap();
+    let dir = scratch.path();
+    std::fs::write(dir.join("dictionary.json"), r#"{ "title": "Welcome" }"#).unwrap();
+    let config_path = dir.join("gt-swc.json");
+    let dictionary_path = dir.join("dictionary.json").display().to_string();
+    std::fs::write(
+      &config_path,
+      serde_json::json!({ "dictionary": dictionary_path }).to_string(),
+    )
+    .unwrap();
+
+    let config = load_config(Some(&config_path)).unwrap();
+
+    let dictionary = config.dictionary.unwrap();
+    assert_eq!(dictionary.get("title"), Some(DictionaryEntry::Leaf));
+  }
+
+  #[test]
+  fn rejects_an_unknown_rule() {
+    let scratch = tempfile::tempdir().unwrap();
+    let config_path = scratch.path().join("gt-swc.json");
+    std::fs::write(
+      &config_path,
+      r#"{ "rules": { "jsx-dynamic-contents": "warn" } }"#,
+    )
+    .unwrap();
+
+    let error = load_config(Some(&config_path)).unwrap_err();
+
+    assert!(error.contains("unknown rule `jsx-dynamic-contents`"));
+  }
+
+  #[test]
+  fn rejects_an_invalid_dictionary() {
+    let scratch = tempfile::tempdir().unwrap();
+    let dir = scratch.path();
+    let config_path = dir.join("gt-swc.json");
+    std::fs::write(&config_path, r#"{ "dictionary": ["not", "an", "object"] }"#).unwrap();
+
+    assert!(load_config(Some(&config_path)).is_err());
+  }
+}
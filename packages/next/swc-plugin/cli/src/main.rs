@@ -0,0 +1,103 @@
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, Parser, Subcommand};
+
+mod check;
+mod extract;
+mod files;
+
+/// Check and extract GT translations across a source tree with the SWC plugin's transform
+#[derive(Parser)]
+#[command(name = "gt-swc", version)]
+struct Cli {
+  #[command(subcommand)]
+  command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+  /// Run the build checks on every file; exits non-zero on error-level violations
+  Check(SourceArgs),
+  /// Write every <T> and t() entry with its hash as JSON
+  Extract {
+    #[command(flatten)]
+    source: SourceArgs,
+    /// File to write the entries to instead of stdout
+    #[arg(short, long)]
+    out: Option<PathBuf>,
+  },
+}
+
+/// The files to process and the plugin options to process them with
+#[derive(Args)]
+struct SourceArgs {
+  /// Files or directories to scan for .js, .jsx, .ts and .tsx files
+  #[arg(default_value = ".")]
+  paths: Vec<PathBuf>,
+  /// JSON file with the plugin options, as passed to `swcPluginOptions`
+  #[arg(short, long)]
+  config: Option<PathBuf>,
+}
+
+impl SourceArgs {
+  fn load(&self) -> Result<(Vec<PathBuf>, gt_swc_core::config::PluginConfig), String> {
+    let config = files::load_config(self.config.as_deref())?;
+    let files = files::collect_source_files(&self.paths)?;
+    Ok((files, config))
+  }
+}
+
+fn run_check(args: &SourceArgs) -> Result<ExitCode, String> {
+  let (files, config) = args.load()?;
+  let outcome = check::run(&files, &config);
+  for failure in &outcome.failures {
+    eprintln!("{failure}");
+  }
+  for violation in &outcome.violations {
+    println!("{violation}");
+  }
+  eprintln!(
+    "Checked {} files: {} violations, {} failed to parse",
+    files.len(),
+    outcome.violations.len(),
+    outcome.failures.len()
+  );
+  Ok(if outcome.has_errors() {
+    ExitCode::FAILURE
+  } else {
+    ExitCode::SUCCESS
+  })
+}
+
+fn run_extract(args: &SourceArgs, out: Option<&PathBuf>) -> Result<ExitCode, String> {
+  let (files, config) = args.load()?;
+  let outcome = extract::run(&files, &config);
+  for failure in &outcome.failures {
+    eprintln!("{failure}");
+  }
+  let json = outcome.to_json().map_err(|error| error.to_string())?;
+  match out {
+    Some(path) => std::fs::write(path, json)
+      .map_err(|error| format!("failed to write {}: {error}", path.display()))?,
+    None => writeln!(std::io::stdout(), "{json}").map_err(|error| error.to_string())?,
+  }
+  Ok(if outcome.failures.is_empty() {
+    ExitCode::SUCCESS
+  } else {
+    ExitCode::FAILURE
+  })
+}
+
+fn main() -> ExitCode {
+  let cli = Cli::parse();
+  let result = match &cli.command {
+    Command::Check(args) => run_check(args),
+    Command::Extract { source, out } => run_extract(source, out.as_ref()),
+  };
+  result.unwrap_or_else(|error| {
+    eprintln!("gt-swc: {error}");
+    ExitCode::from(2)
+  })
+}
@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+use swc_core::common::SyntaxContext;
+use swc_core::ecma::{ast::Id, atoms::Atom};
+
+/// A binding of a GT export or translator
+#[derive(Debug, Clone)]
+pub struct TrackedBinding {
+  /// The GT export or translator it refers to
+  pub original_name: Atom, // useGT, T, useGT_callback (never the alias)
+  /// The identifier for the binding
+  pub identifier: u32, // aggregator counter, dictionary namespace index, 0 for imports
+}
+
+/// Tracks GT bindings by identity: the symbol plus the `SyntaxContext` SWC's resolver assigns to
+/// each binding. Shadowing declarations get their own context, so lookups need no scope stack.
+#[derive(Debug, Default)]
+pub struct BindingTracker {
+  bindings: HashMap<Id, TrackedBinding>,
+}
+
+impl BindingTracker {
+  /// Track a binding of a GT export or translator
+  pub fn track_translation_variable(&mut self, id: Id, original_name: Atom, identifier: u32) {
+    self.bindings.insert(
+      id,
+      TrackedBinding {
+        original_name,
+        identifier,
+      },
+    );
+  }
+
+  /// Stop tracking a binding that was reassigned to something else: `t = format`
+  pub fn untrack(&mut self, id: &Id) {
+    self.bindings.remove(id);
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.bindings.is_empty()
+  }
+
+  /// Get the tracked binding for an identifier, if any
+  pub fn get_translation_variable(&self, id: &Id) -> Option<&TrackedBinding> {
+    self.bindings.get(id)
+  }
+
+  /// Find a binding of a GT export declared in the scope of `ctxt`: the local name `Var` is
+  /// imported as next to `T`. The alphabetically first alias wins.
+  pub fn find_in_scope(&self, original_name: &str, ctxt: SyntaxContext) -> Option<&Id> {
+    self
+      .bindings
+      .iter()
+      .filter(|(id, binding)| id.1 == ctxt && binding.original_name == original_name)
+      .map(|(id, _)| id)
+      .min_by(|a, b| a.0.cmp(&b.0))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use swc_core::common::{Mark, GLOBALS};
+
+  #[test]
+  fn keys_bindings_by_syntax_context() {
+    GLOBALS.set(&Default::default(), || {
+      let mut tracker = BindingTracker::default();
+      let outer = (
+        Atom::new("t"),
+        SyntaxContext::empty().apply_mark(Mark::new()),
+      );
+      let shadowed = (
+        Atom::new("t"),
+        SyntaxContext::empty().apply_mark(Mark::new()),
+      );
+
+      tracker.track_translation_variable(outer.clone(), Atom::new("useGT_callback"), 1);
+
+      assert_eq!(
+        tracker.get_translation_variable(&outer).unwrap().identifier,
+        1
+      );
+      assert!(tracker.get_translation_variable(&shadowed).is_none());
+    });
+  }
+
+  #[test]
+  fn untracks_reassigned_bindings() {
+    let mut tracker = BindingTracker::default();
+    let id = (Atom::new("t"), SyntaxContext::empty());
+
+    tracker.track_translation_variable(id.clone(), Atom::new("useGT_callback"), 1);
+    tracker.untrack(&id);
+
+    assert!(tracker.get_translation_variable(&id).is_none());
+  }
+
+  #[test]
+  fn finds_bindings_in_scope_by_export() {
+    GLOBALS.set(&Default::default(), || {
+      let mut tracker = BindingTracker::default();
+      let top_level = SyntaxContext::empty().apply_mark(Mark::new());
+      let nested = SyntaxContext::empty().apply_mark(Mark::new());
+      tracker.track_translation_variable((Atom::new("V"), top_level), Atom::new("Var"), 0);
+      tracker.track_translation_variable((Atom::new("Value"), top_level), Atom::new("Var"), 0);
+      tracker.track_translation_variable((Atom::new("Var"), nested), Atom::new("Var"), 0);
+
+      assert_eq!(
+        tracker.find_in_scope("Var", top_level),
+        Some(&(Atom::new("V"), top_level))
+      );
+      assert!(tracker.find_in_scope("Num", top_level).is_none());
+    });
+  }
+
+  mod binding_resolution {
+    use crate::test_utils::{config, transform_with_diagnostics};
+
+    fn codes(source: &str) -> Vec<Option<String>> {
+      let (_, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+      diagnostics
+        .into_iter()
+        .map(|diagnostic| diagnostic.code)
+        .collect()
+    }
+
+    #[test]
+    fn hoisted_vars_shadow_outer_translators() {
+      let source = "import { useGT } from 'gt-next';\nconst t = useGT();\nfunction f() {\n  if (ready) {\n    var t = format;\n  }\n  return t('Hi ' + name);\n}\n";
+
+      assert!(codes(source).is_empty());
+    }
+
+    #[test]
+    fn block_translators_do_not_leak() {
+      let source = "import { useGT } from 'gt-next';\nconst t = format;\n{\n  const t = useGT();\n  t('Hello');\n}\nt('Hi ' + name);\n";
+      let (output, _) = transform_with_diagnostics(source, &config(true, false));
+
+      assert!(codes(source).is_empty());
+      assert!(output.contains("t('Hello', {"));
+    }
+
+    #[test]
+    fn shadowed_custom_hooks_are_ignored() {
+      let source = "import { useGT } from 'gt-next';\nfunction useAppT() {\n  return useGT();\n}\nfunction Page(useAppT) {\n  const t = useAppT();\n  return t('Hi ' + name);\n}\n";
+
+      assert!(codes(source).is_empty());
+    }
+
+    #[test]
+    fn shadowed_components_are_not_translated() {
+      let source =
+        "import { T } from 'gt-next';\nfunction Page({ T }) {\n  return <T>Hello {name}</T>;\n}\n";
+      let (output, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+      assert!(diagnostics.is_empty());
+      assert!(!output.contains("_hash"));
+    }
+  }
+}
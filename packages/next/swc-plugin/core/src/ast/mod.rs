@@ -1,10 +1,10 @@
+pub mod bindings;
 pub mod constants;
-pub mod scope;
 pub mod string_collector;
 pub mod traversal;
 pub mod utilities;
 
-pub use scope::*;
+pub use bindings::*;
 pub use string_collector::*;
 pub use traversal::*;
 pub use utilities::*;
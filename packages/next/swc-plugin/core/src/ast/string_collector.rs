@@ -1,5 +1,7 @@
+use std::collections::{HashMap, VecDeque};
 use swc_core::common::Span;
 use swc_core::ecma::ast::*;
+use swc_core::ecma::visit::{VisitMut, VisitMutWith};
 
 /// Content extracted from a t() function call
 #[derive(Debug, Clone)]
@@ -14,6 +16,8 @@ pub struct TranslationContent {
   pub context: Option<String>,
   /// Optional max chars from options: t("text", {$maxChars: 10}) → Some(10)
   pub max_chars: Option<i32>,
+  /// Optional data format from options: t("text", {$format: "STRING"}) → Some("STRING")
+  pub format: Option<String>,
 }
 
 /// Content extracted from JSX translation components like <T>
@@ -41,15 +45,16 @@ pub struct TranslationData {
   pub hash: Option<TranslationHash>,
 }
 
-/// Simplified string collector for two-pass transformation
+/// Simplified string collector for the transform
 ///
-/// Pass 1 (Observation):
+/// During the traversal:
 /// - Mark useGT/getGT calls with unique IDs based on global counter
-/// - Collect t() strings, JSX components, and hashes and associate with those IDs
+/// - Collect t() strings and associate them with those IDs
+/// - Record the useGT/getGT calls that receive a content array, by their position among the
+///   call sites of the function or module that declares their translator
 ///
-/// Pass 2 (Action):
-/// - Look up collected content by regenerating same unique IDs
-/// - Inject content arrays into useGT/getGT calls or hash attributes into components
+/// When leaving that function or module, every t() call of the translator has been seen:
+/// - Patch the recorded calls in place with their content arrays
 #[derive(Debug, Default)]
 pub struct StringCollector {
   /// THE CORE DATA STRUCTURE
@@ -59,24 +64,52 @@ pub struct StringCollector {
   aggregators: Vec<TranslationData>,
 
   /// Global counter incremented for each useGT/getGT call encountered
-  /// Also serves as the next index for calls_needing_injection
   global_call_counter: u32,
+
+  /// Functions and the module being traversed, innermost last
+  scopes: Vec<InjectionScope>,
+
+  /// Call sites seen so far: variables initialized or assigned with a call
+  call_sites: u32,
+
+  /// Call site being tracked and the depth of the scope its translator is declared in
+  current_site: Option<(u32, usize)>,
+
+  /// Depth of the scope each variable is declared in
+  declarations: HashMap<Id, usize>,
+}
+
+/// A function or the module, which receives the content arrays of the translators it declares
+/// once it has been traversed
+#[derive(Debug, Default)]
+struct InjectionScope {
+  /// Call sites seen before the scope was entered
+  first_site: u32,
+  /// Content arrays to inject: (call site within the scope, counter ID), in source order
+  targets: VecDeque<(u32, u32)>,
 }
 
 impl StringCollector {
+  /// Identifier of translators without a content array of their own, like helper parameters
+  /// and custom hooks. Counter IDs start at 1.
+  pub const NO_AGGREGATOR: u32 = 0;
+
   /// Create a new empty string collector
   pub fn new() -> Self {
     Self {
       aggregators: Vec::new(),
       global_call_counter: 0,
+      scopes: Vec::new(),
+      call_sites: 0,
+      current_site: None,
+      declarations: HashMap::new(),
     }
   }
 
   /// Increment counter and return the current counter ID for a useGT/getGT call
   ///
   /// These IDs are:
-  /// - Deterministic: Same visitation order = same IDs  
-  /// - Stable: Don't change when AST is modified
+  /// - Deterministic: Same visitation order = same IDs
   /// - Unique: Global counter ensures no collisions ever
   /// - Simple: No scope tracking needed
   pub fn increment_counter(&mut self) -> u32 {
@@ -89,7 +122,7 @@ impl StringCollector {
     self.global_call_counter
   }
 
-  /// Pass 1: Initialize a useGT/getGT call for later content injection
+  /// Initialize a useGT/getGT call for later content injection
   ///
   /// This creates an empty TranslationCall that content will be added to
   pub fn initialize_aggregator(&mut self, counter_id: u32) {
@@ -99,7 +132,7 @@ impl StringCollector {
     }
   }
 
-  /// Pass 1: Add translation content from a t() call to a specific useGT/getGT
+  /// Add translation content from a t() call to a specific useGT/getGT
   /// Multiple content items can be added to the same call
   pub fn set_translation_content(&mut self, counter_id: u32, content: TranslationContent) {
     if let Some(call) = self.aggregators.get_mut(counter_id as usize) {
@@ -109,7 +142,7 @@ impl StringCollector {
     }
   }
 
-  /// Pass 1: Set JSX translation content for a specific useGT/getGT
+  /// Set JSX translation content for a specific useGT/getGT
   /// Only one JSX item can be set per call (overwrites if called multiple times)
   pub fn set_translation_jsx(&mut self, counter_id: u32, jsx: TranslationJsx) {
     if let Some(call) = self.aggregators.get_mut(counter_id as usize) {
@@ -119,7 +152,7 @@ impl StringCollector {
     }
   }
 
-  /// Pass 1: Set hash-only content for a specific useGT/getGT  
+  /// Set hash-only content for a specific useGT/getGT  
   /// Only one hash can be set per call (overwrites if called multiple times)
   pub fn set_translation_hash(&mut self, counter_id: u32, hash: TranslationHash) {
     if let Some(call) = self.aggregators.get_mut(counter_id as usize) {
@@ -129,7 +162,7 @@ impl StringCollector {
     }
   }
 
-  /// Pass 2: Get translation call data for injection into a specific useGT/getGT call
+  /// Get translation call data for injection into a specific useGT/getGT call
   ///
   /// Returns None if no call was initialized for this counter_id
   pub fn get_translation_data(&self, counter_id: u32) -> Option<&TranslationData> {
@@ -161,7 +194,7 @@ impl StringCollector {
       .and_then(|data| data.hash.as_ref())
   }
 
-  /// Pass 2: Check if a call has any content to inject
+  /// Check if a call has any content to inject
   pub fn has_content_for_injection(&self, counter_id: u32) -> bool {
     self
       .aggregators
@@ -170,6 +203,74 @@ impl StringCollector {
       .unwrap_or(false)
   }
 
+  /// Enter a function or the module
+  pub fn enter_scope(&mut self) {
+    self.scopes.push(InjectionScope {
+      first_site: self.call_sites,
+      targets: VecDeque::new(),
+    });
+  }
+
+  /// Leave a function or the module, patching the calls of the translators it declares in
+  /// place: useGT() -> useGT([{ message: "Hello", $_hash: "..." }]). The scope is only walked
+  /// up to its last such call.
+  pub fn exit_scope<N>(&mut self, node: &mut N)
+  where
+    N: for<'a> VisitMutWith<ContentArrayInjector<'a>>,
+  {
+    let Some(scope) = self.scopes.pop() else {
+      return;
+    };
+    if scope.targets.is_empty() {
+      return;
+    }
+    node.visit_mut_children_with(&mut ContentArrayInjector {
+      collector: self,
+      targets: scope.targets,
+      call_sites: 0,
+    });
+  }
+
+  /// Track a variable declaration, which may be a call site: `const t = useGT()`
+  pub fn record_declarator(&mut self, var_declarator: &mut VarDeclarator) {
+    let depth = self.scopes.len().saturating_sub(1);
+    if let Pat::Ident(BindingIdent { id, .. }) = &var_declarator.name {
+      self.declarations.insert(id.to_id(), depth);
+    }
+    self.current_site = None;
+    if declarator_call_site(var_declarator).is_some() {
+      self.current_site = Some((self.call_sites, depth));
+      self.call_sites += 1;
+    }
+  }
+
+  /// Track an assignment, which may be a call site: `t = useGT()`. The translator belongs to
+  /// the scope that declares the variable, or the module when that is not known.
+  pub fn record_assignment(&mut self, assign_expr: &mut AssignExpr) {
+    self.current_site = None;
+    if let Some((id, _)) = assignment_call_site(assign_expr) {
+      let depth = self
+        .declarations
+        .get(&id.to_id())
+        .map_or(0, |depth| (*depth).min(self.scopes.len().saturating_sub(1)));
+      self.current_site = Some((self.call_sites, depth));
+      self.call_sites += 1;
+    }
+  }
+
+  /// Record that the call of the call site being tracked receives the content array of
+  /// `counter_id` when the scope declaring its translator is left
+  pub fn record_injection_target(&mut self, counter_id: u32) {
+    let Some((site, depth)) = self.current_site.take() else {
+      return;
+    };
+    if let Some(scope) = self.scopes.get_mut(depth) {
+      scope
+        .targets
+        .push_back((site - scope.first_site, counter_id));
+    }
+  }
+
   /// Helper: Create a TranslationContent from t() call components
   pub fn create_translation_content(
     message: String,
@@ -177,6 +278,7 @@ impl StringCollector {
     id: Option<String>,
     context: Option<String>,
     max_chars: Option<i32>,
+    format: Option<String>,
   ) -> TranslationContent {
     TranslationContent {
       message,
@@ -184,6 +286,7 @@ impl StringCollector {
       id,
       context,
       max_chars,
+      format,
     }
   }
 
@@ -278,7 +381,16 @@ impl StringCollector {
 
     // Add optional max chars property
     if let Some(max_chars) = &content.max_chars {
-      props.push(Self::generate_key_value_pair_number("$maxChars", *max_chars, span));
+      props.push(Self::generate_key_value_pair_number(
+        "$maxChars",
+        *max_chars,
+        span,
+      ));
+    }
+
+    // Add optional format property, which the runtime hashes with the message
+    if let Some(format) = &content.format {
+      props.push(Self::generate_key_value_pair("$format", format, span));
     }
 
     ObjectLit { span, props }
@@ -317,6 +429,10 @@ impl StringCollector {
   pub fn clear(&mut self) {
     self.aggregators.clear();
     self.global_call_counter = 0;
+    self.scopes.clear();
+    self.call_sites = 0;
+    self.current_site = None;
+    self.declarations.clear();
   }
 
   /// Reset the counter to a specific value
@@ -325,6 +441,101 @@ impl StringCollector {
   }
 }
 
+/// Call a variable is initialized or assigned with: `useGT()`, `await getGT()`. These call
+/// sites are the only calls that receive a content array.
+fn call_site(expr: &mut Expr) -> Option<&mut CallExpr> {
+  match expr {
+    Expr::Call(call_expr) => Some(call_expr),
+    Expr::Await(AwaitExpr { arg, .. }) => match arg.as_mut() {
+      Expr::Call(call_expr) => Some(call_expr),
+      _ => None,
+    },
+    Expr::Paren(paren_expr) => call_site(&mut paren_expr.expr),
+    _ => None,
+  }
+}
+
+/// Call site of `const t = useGT()`
+fn declarator_call_site(var_declarator: &mut VarDeclarator) -> Option<&mut CallExpr> {
+  match (&var_declarator.name, var_declarator.init.as_deref_mut()) {
+    (Pat::Ident(_), Some(init)) => call_site(init),
+    _ => None,
+  }
+}
+
+/// Call site of `t = useGT()`, with the assigned variable
+fn assignment_call_site(assign_expr: &mut AssignExpr) -> Option<(&Ident, &mut CallExpr)> {
+  if assign_expr.op != AssignOp::Assign {
+    return None;
+  }
+  let AssignTarget::Simple(SimpleAssignTarget::Ident(BindingIdent { id, .. })) = &assign_expr.left
+  else {
+    return None;
+  };
+  Some((id, call_site(&mut assign_expr.right)?))
+}
+
+/// Patches the useGT/getGT calls of a scope with their content arrays, counting call sites
+/// the way the traversal did and stopping once all are found
+pub struct ContentArrayInjector<'a> {
+  collector: &'a StringCollector,
+  targets: VecDeque<(u32, u32)>,
+  call_sites: u32,
+}
+
+impl ContentArrayInjector<'_> {
+  fn inject(&mut self, call_expr: &mut CallExpr) {
+    let site = self.call_sites;
+    self.call_sites += 1;
+    let Some(&(target, counter_id)) = self.targets.front() else {
+      return;
+    };
+    if target != site {
+      return;
+    }
+    self.targets.pop_front();
+    let data = self.collector.get_translation_data(counter_id);
+    // Calls that already have arguments are left alone
+    if let Some(data) = data.filter(|_| call_expr.args.is_empty()) {
+      let content_array = self
+        .collector
+        .create_content_array(&data.content, call_expr.span);
+      call_expr.args.push(ExprOrSpread {
+        spread: None,
+        expr: Box::new(Expr::Array(content_array)),
+      });
+    }
+  }
+}
+
+impl VisitMut for ContentArrayInjector<'_> {
+  fn visit_mut_module_item(&mut self, item: &mut ModuleItem) {
+    if !self.targets.is_empty() {
+      item.visit_mut_children_with(self);
+    }
+  }
+
+  fn visit_mut_stmt(&mut self, stmt: &mut Stmt) {
+    if !self.targets.is_empty() {
+      stmt.visit_mut_children_with(self);
+    }
+  }
+
+  fn visit_mut_var_declarator(&mut self, var_declarator: &mut VarDeclarator) {
+    if let Some(call_expr) = declarator_call_site(var_declarator) {
+      self.inject(call_expr);
+    }
+    var_declarator.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_assign_expr(&mut self, assign_expr: &mut AssignExpr) {
+    if let Some((_, call_expr)) = assignment_call_site(assign_expr) {
+      self.inject(call_expr);
+    }
+    assign_expr.visit_mut_children_with(self);
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -372,6 +583,7 @@ mod tests {
       id: Some("greeting".to_string()),
       context: None,
       max_chars: None,
+      format: None,
     };
 
     collector.set_translation_content(counter_id, content);
@@ -404,6 +616,7 @@ mod tests {
         id: None,
         context: None,
         max_chars: None,
+        format: None,
       },
     );
 
@@ -415,6 +628,7 @@ mod tests {
         id: Some("second".to_string()),
         context: Some("test".to_string()),
         max_chars: None,
+        format: None,
       },
     );
 
@@ -451,6 +665,7 @@ mod tests {
         id: None,
         context: None,
         max_chars: None,
+        format: None,
       },
     );
 
@@ -462,6 +677,7 @@ mod tests {
         id: None,
         context: None,
         max_chars: None,
+        format: None,
       },
     );
 
@@ -489,6 +705,7 @@ mod tests {
         id: Some("greeting".to_string()),
         context: None,
         max_chars: None,
+        format: None,
       },
       TranslationContent {
         message: "World".to_string(),
@@ -496,6 +713,7 @@ mod tests {
         id: None,
         context: Some("global".to_string()),
         max_chars: None,
+        format: None,
       },
     ];
 
@@ -520,6 +738,7 @@ mod tests {
         id: None,
         context: None,
         max_chars: None,
+        format: None,
       },
     );
 
@@ -608,6 +827,7 @@ mod tests {
       Some("content-id".to_string()),
       None,
       None,
+      None,
     );
 
     let content2 = StringCollector::create_translation_content(
@@ -616,6 +836,7 @@ mod tests {
       None,
       Some("test-context".to_string()),
       None,
+      None,
     );
 
     let jsx = StringCollector::create_translation_jsx("jsx-hash".to_string());
@@ -663,6 +884,7 @@ mod tests {
         id: Some("greeting".to_string()),
         context: None,
         max_chars: None,
+        format: None,
       },
       TranslationContent {
         message: "World".to_string(),
@@ -670,6 +892,7 @@ mod tests {
         id: None,
         context: Some("global".to_string()),
         max_chars: None,
+        format: None,
       },
     ];
 
@@ -690,6 +913,7 @@ mod tests {
       Some("test-id".to_string()),
       Some("test-context".to_string()),
       None,
+      None,
     );
 
     assert_eq!(content.message, "Test message");
@@ -717,6 +941,7 @@ mod tests {
       Some("test-id".to_string()),
       None,
       Some(50),
+      None,
     );
 
     assert_eq!(content_with_max_chars.message, "Test with max chars");
@@ -732,12 +957,16 @@ mod tests {
       Some("complete-id".to_string()),
       Some("complete-context".to_string()),
       Some(100),
+      None,
     );
 
     assert_eq!(content_complete.message, "Complete test");
     assert_eq!(content_complete.hash, "complete-hash");
     assert_eq!(content_complete.id, Some("complete-id".to_string()));
-    assert_eq!(content_complete.context, Some("complete-context".to_string()));
+    assert_eq!(
+      content_complete.context,
+      Some("complete-context".to_string())
+    );
     assert_eq!(content_complete.max_chars, Some(100));
   }
 
@@ -755,6 +984,7 @@ mod tests {
       Some("limit-id".to_string()),
       None,
       Some(25),
+      None,
     );
 
     collector.set_translation_content(counter_id, content_with_max_chars);
@@ -772,6 +1002,7 @@ mod tests {
       None,
       Some("test-context".to_string()),
       None,
+      None,
     );
 
     collector.set_translation_content(counter_id, content_no_limit);
@@ -782,6 +1013,7 @@ mod tests {
       Some("high-id".to_string()),
       Some("high-context".to_string()),
       Some(200),
+      None,
     );
 
     collector.set_translation_content(counter_id, content_high_limit);
@@ -789,13 +1021,13 @@ mod tests {
     // Verify all contents are stored with correct max_chars
     let final_data = collector.get_translation_data(counter_id).unwrap();
     assert_eq!(final_data.content.len(), 3);
-    
+
     // Check first content (has max_chars)
     assert_eq!(final_data.content[0].max_chars, Some(25));
-    
+
     // Check second content (no max_chars)
     assert_eq!(final_data.content[1].max_chars, None);
-    
+
     // Check third content (has high max_chars)
     assert_eq!(final_data.content[2].max_chars, Some(200));
   }
@@ -803,7 +1035,7 @@ mod tests {
   #[test]
   fn test_content_object_generation_with_max_chars() {
     let collector = StringCollector::new();
-    
+
     // Test object creation with max_chars
     let content_with_max_chars = TranslationContent {
       message: "Test message".to_string(),
@@ -811,6 +1043,7 @@ mod tests {
       id: Some("test-id".to_string()),
       context: Some("test-context".to_string()),
       max_chars: Some(42),
+      format: None,
     };
 
     let span = swc_core::common::DUMMY_SP;
@@ -832,7 +1065,7 @@ mod tests {
     });
 
     assert!(max_chars_prop.is_some(), "Should have $maxChars property");
-    
+
     if let Some(PropOrSpread::Prop(prop)) = max_chars_prop {
       if let Prop::KeyValue(kv) = prop.as_ref() {
         if let Expr::Lit(Lit::Num(num)) = kv.value.as_ref() {
@@ -850,6 +1083,7 @@ mod tests {
       id: Some("test-id".to_string()),
       context: None,
       max_chars: None,
+      format: None,
     };
 
     let obj_no_max = collector.create_content_object(&content_without_max_chars, span);
@@ -869,7 +1103,43 @@ mod tests {
       false
     });
 
-    assert!(!has_max_chars, "Should not have $maxChars property when None");
+    assert!(
+      !has_max_chars,
+      "Should not have $maxChars property when None"
+    );
+  }
+
+  #[test]
+  fn test_content_object_generation_with_format() {
+    let collector = StringCollector::new();
+    let content = StringCollector::create_translation_content(
+      "Hello {{name}}".to_string(),
+      "format-hash".to_string(),
+      None,
+      None,
+      None,
+      Some("I18NEXT".to_string()),
+    );
+
+    let obj = collector.create_content_object(&content, swc_core::common::DUMMY_SP);
+
+    // message, $_hash, $format
+    assert_eq!(obj.props.len(), 3);
+    let format = obj.props.iter().find_map(|prop| {
+      let PropOrSpread::Prop(prop) = prop else {
+        return None;
+      };
+      let Prop::KeyValue(kv) = prop.as_ref() else {
+        return None;
+      };
+      match (&kv.key, kv.value.as_ref()) {
+        (PropName::Ident(ident), Expr::Lit(Lit::Str(value))) if ident.sym == "$format" => {
+          Some(value.value.to_string_lossy().into_owned())
+        }
+        _ => None,
+      }
+    });
+    assert_eq!(format.as_deref(), Some("I18NEXT"));
   }
 
   #[test]
@@ -949,6 +1219,7 @@ mod tests {
           None
         },
         None,
+        None,
       );
       collector.set_translation_content(counter_id, content);
     }
@@ -1012,6 +1283,7 @@ mod tests {
         Some("id1".to_string()),
         None,
         None,
+        None,
       ),
     );
 
@@ -1023,6 +1295,7 @@ mod tests {
         None,
         Some("context2".to_string()),
         None,
+        None,
       ),
     );
 
@@ -1075,6 +1348,7 @@ mod tests {
         Some("id1-1".to_string()),
         None,
         None,
+        None,
       ),
     );
     collector.set_translation_content(
@@ -1085,6 +1359,7 @@ mod tests {
         None,
         Some("ctx1-2".to_string()),
         None,
+        None,
       ),
     );
     collector.set_translation_jsx(
@@ -1105,6 +1380,7 @@ mod tests {
         None,
         None,
         None,
+        None,
       ),
     );
 
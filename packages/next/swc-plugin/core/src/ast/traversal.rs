@@ -4,10 +4,12 @@ use crate::ast::utilities::{
   get_variable_type, js_number_to_string,
 };
 use crate::hash::{
-  HtmlContentProps, SanitizedChild, SanitizedChildren, SanitizedElement, SanitizedGtProp,
-  SanitizedVariable, VariableType,
+  SanitizedChild, SanitizedChildren, SanitizedElement, SanitizedGtProp, SanitizedVariable,
+  VariableType,
+};
+use crate::visitor::jsx_utils::{
+  extract_attribute_from_jsx_attr, extract_max_chars_from_jsx_attr, jsx_attr_contains_derive_call,
 };
-use crate::visitor::jsx_utils::{extract_attribute_from_jsx_attr, extract_max_chars_from_jsx_attr, jsx_attr_contains_derive_call};
 use crate::TransformVisitor;
 use std::collections::BTreeMap;
 use swc_core::ecma::{ast::*, atoms::Atom};
@@ -59,7 +61,7 @@ impl<'a> JsxTraversal<'a> {
       let has_derive_in_context = jsx_attr_contains_derive_call(element, "context")
         || jsx_attr_contains_derive_call(element, "$context");
       let has_static = JsxHasher::contains_static(&sanitized_children) || has_derive_in_context;
-      
+
       // Create the full SanitizedData structure to match TypeScript compiler inputs
       use crate::hash::SanitizedData;
       let sanitized_data = SanitizedData {
@@ -69,6 +71,8 @@ impl<'a> JsxTraversal<'a> {
         data_format: Some("JSX".to_string()),
       };
       // Calculate hash using stable stringify (like TypeScript fast-json-stable-stringify)
+      // Sanitized data only has string keys, so serializing it cannot fail
+      #[allow(clippy::expect_used)]
       let json_string =
         JsxHasher::stable_stringify(&sanitized_data).expect("Failed to serialize sanitized data");
 
@@ -90,10 +94,10 @@ impl<'a> JsxTraversal<'a> {
 
       let empty_children =
         SanitizedChildren::Single(Box::new(SanitizedChild::Element(Box::new(empty_element))));
-      
+
       // Check if empty children contain static components - if so, return empty hash
       let has_static = JsxHasher::contains_static(&empty_children);
-      
+
       let sanitized_data = SanitizedData {
         source: Some(Box::new(empty_children)),
         context: None,
@@ -101,6 +105,8 @@ impl<'a> JsxTraversal<'a> {
         data_format: Some("JSX".to_string()),
       };
 
+      // Sanitized data only has string keys, so serializing it cannot fail
+      #[allow(clippy::expect_used)]
       let json_string =
         JsxHasher::stable_stringify(&sanitized_data).expect("Failed to serialize empty data");
 
@@ -125,8 +131,7 @@ impl<'a> JsxTraversal<'a> {
       return None;
     }
 
-    if filtered_children.len() == 1 {
-      let child = filtered_children.first().unwrap();
+    if let [child] = filtered_children.as_slice() {
       return self
         .build_sanitized_child(child, true, true)
         .map(|child| SanitizedChildren::Single(Box::new(child)));
@@ -282,7 +287,7 @@ impl<'a> JsxTraversal<'a> {
     let tag_name = get_tag_name(&element.opening.name)?;
 
     // Check if this is a GT component
-    let component_info = self.analyze_gt_component(&tag_name, &element.opening.attrs);
+    let component_info = self.analyze_gt_component(&element.opening.name, &element.opening.attrs);
 
     // Variable components should be handled as SanitizedVariable, not SanitizedElement
     if component_info.variable_type.is_some() {
@@ -307,7 +312,7 @@ impl<'a> JsxTraversal<'a> {
 
     // Handle different component types
     if component_info.is_gt_component {
-      if self.is_plural_component(&tag_name) {
+      if self.is_plural_component(&element.opening.name) {
         if !self.is_valid_plural_component(element, &component_info) {
           return None;
         }
@@ -315,7 +320,7 @@ impl<'a> JsxTraversal<'a> {
           sanitized_element.b = Some(branches);
         }
         sanitized_element.t = component_info.transformation;
-      } else if self.is_branch_component(&tag_name) {
+      } else if self.is_branch_component(&element.opening.name) {
         if !self.is_valid_branch_component(element, &component_info) {
           return None;
         }
@@ -343,8 +348,7 @@ impl<'a> JsxTraversal<'a> {
 
   /// Build a sanitized variable directly from JSX element
   fn build_sanitized_variable(&mut self, element: &JSXElement) -> Option<SanitizedVariable> {
-    let tag_name = get_tag_name(&element.opening.name)?;
-    let component_info = self.analyze_gt_component(&tag_name, &element.opening.attrs);
+    let component_info = self.analyze_gt_component(&element.opening.name, &element.opening.attrs);
 
     if let Some(var_type) = component_info.variable_type {
       // Extract variable name from children or attributes with proper prefix
@@ -388,118 +392,73 @@ impl<'a> JsxTraversal<'a> {
     }
   }
 
-  /// Check if this is a Branch component
-  pub fn is_branch_component(&self, tag_name: &str) -> bool {
-    // Named import
-    if let Some(translation_variable) = self
-      .visitor
-      .import_tracker
-      .scope_tracker
-      .get_translation_variable(&Atom::from(tag_name))
-    {
-      if translation_variable.original_name.as_str() == "Branch" {
-        return true;
-      }
-    }
-
-    // Namespace import
-    if tag_name.ends_with(".Branch") {
-      let namespace = tag_name.split('.').next().unwrap_or("");
-      if self
-        .visitor
-        .import_tracker
-        .namespace_imports
-        .contains(&Atom::from(namespace))
-      {
-        return true;
-      }
-    }
+  /// GT export a JSX element name resolves to, see [`TransformVisitor::resolve_component`]
+  fn resolve_component(&self, name: &JSXElementName) -> Option<Atom> {
+    self.visitor.resolve_component(name)
+  }
 
-    false
+  /// Check if this is a Branch component
+  pub fn is_branch_component(&self, name: &JSXElementName) -> bool {
+    self
+      .resolve_component(name)
+      .is_some_and(|component| component == "Branch")
   }
 
   /// Check if this is a Plural component
-  pub fn is_plural_component(&self, tag_name: &str) -> bool {
-    // Named import
-    if let Some(translation_variable) = self
-      .visitor
-      .import_tracker
-      .scope_tracker
-      .get_translation_variable(&Atom::from(tag_name))
-    {
-      if translation_variable.original_name.as_str() == "Plural" {
-        return true;
-      }
-    }
-
-    // Namespace import
-    if tag_name.ends_with(".Plural") {
-      let namespace = tag_name.split('.').next().unwrap_or("");
-      if self
-        .visitor
-        .import_tracker
-        .namespace_imports
-        .contains(&Atom::from(namespace))
-      {
-        return true;
-      }
-    }
-
-    false
+  pub fn is_plural_component(&self, name: &JSXElementName) -> bool {
+    self
+      .resolve_component(name)
+      .is_some_and(|component| component == "Plural")
   }
 
   /// Analyze if this is a GT component and extract relevant info
-  fn analyze_gt_component(&mut self, tag_name: &str, attrs: &[JSXAttrOrSpread]) -> ComponentInfo {
+  fn analyze_gt_component(
+    &mut self,
+    name: &JSXElementName,
+    attrs: &[JSXAttrOrSpread],
+  ) -> ComponentInfo {
     let mut info = ComponentInfo::default();
 
-    // Check if it's a known GT component
-    if self
-      .visitor
-      .should_track_component_as_translation(&Atom::from(tag_name))
-    {
-      info.is_gt_component = true;
-    } else if self
-      .visitor
-      .should_track_component_as_branch(&Atom::from(tag_name))
-    {
-      // Branch and Plural components
-      info.is_gt_component = true;
-
-      // Determine transformation type
-      if self.is_branch_component(tag_name) {
-        info.transformation = Some(String::from("b"));
-        info.branches = self.extract_branch_props(attrs);
-      } else if self.is_plural_component(tag_name) {
-        info.transformation = Some(String::from("p"));
-        info.branches = self.extract_plural_props(attrs);
-      }
-    } else if self
-      .visitor
-      .should_track_component_as_variable(&Atom::from(tag_name))
-    {
-      info.is_gt_component = true;
-      info.transformation = Some("v".to_string());
-      info.variable_type = Some(get_variable_type(tag_name));
-    }
-
-    // Handle namespace components (GT.T, GT.Var, etc.)
-    // TODO: rework a better way of checking, more modular
-    if tag_name.contains('.') {
-      let parts: Vec<&str> = tag_name.split('.').collect();
-      if parts.len() == 2 {
-        let (namespace, component) = (parts[0], parts[1]);
-        let namespace_atom = Atom::from(namespace);
-        let component_atom = Atom::from(component);
+    match name {
+      JSXElementName::Ident(ident) => {
+        let id = ident.to_id();
+        // Check if it's a known GT component
+        if self.visitor.should_track_component_as_translation(&id) {
+          info.is_gt_component = true;
+        } else if self.visitor.should_track_component_as_branch(&id) {
+          // Branch and Plural components
+          info.is_gt_component = true;
 
+          // Determine transformation type
+          if self.is_branch_component(name) {
+            info.transformation = Some(String::from("b"));
+            info.branches = self.extract_branch_props(attrs);
+          } else if self.is_plural_component(name) {
+            info.transformation = Some(String::from("p"));
+            info.branches = self.extract_plural_props(attrs);
+          }
+        } else if self.visitor.should_track_component_as_variable(&id) {
+          info.is_gt_component = true;
+          info.transformation = Some("v".to_string());
+          info.variable_type = Some(get_variable_type(&ident.sym));
+        }
+      }
+      // Handle namespace components (GT.T, GT.Var, etc.)
+      JSXElementName::JSXMemberExpr(JSXMemberExpr {
+        obj: JSXObject::Ident(namespace),
+        prop,
+        ..
+      }) => {
+        let component = &prop.sym;
         let (is_translation, is_variable, is_branch) = self
           .visitor
-          .should_track_namespace_component(&namespace_atom, &component_atom);
+          .should_track_namespace_component(&namespace.to_id(), component);
 
         if is_translation {
           info.is_gt_component = true;
         } else if is_branch {
           info.is_gt_component = true;
-          match component {
+          match component.as_str() {
             "Branch" => {
               info.transformation = Some("b".to_string());
               info.branches = self.extract_branch_props(attrs);
@@ -518,6 +477,7 @@ impl<'a> JsxTraversal<'a> {
           info.variable_type = Some(get_variable_type(component));
         }
       }
+      _ => {}
     }
 
     info
@@ -589,7 +549,7 @@ impl<'a> JsxTraversal<'a> {
   }
 
   /// Build sanitized children directly from JSX attribute value
-  fn build_sanitized_child_from_attr_value(
+  pub fn build_sanitized_child_from_attr_value(
     &mut self,
     value: &JSXAttrValue,
   ) -> Option<SanitizedChild> {
@@ -670,7 +630,9 @@ impl<'a> JsxTraversal<'a> {
               self.build_sanitized_child(&JSXElementChild::JSXElement(element.clone()), true, true)
             }
           }
-          Expr::Lit(Lit::Str(str_lit)) => Some(SanitizedChild::Text(str_lit.value.to_string_lossy().into_owned())),
+          Expr::Lit(Lit::Str(str_lit)) => Some(SanitizedChild::Text(
+            str_lit.value.to_string_lossy().into_owned(),
+          )),
           Expr::Lit(Lit::Num(num_lit)) => {
             Some(SanitizedChild::Text(js_number_to_string(num_lit.value)))
           }
@@ -731,39 +693,22 @@ use crate::visitor::expr_utils::is_allowed_dynamic_content;
 /// Recursively check if any JSX descendant contains a dynamic expression
 fn has_dynamic_content_recursive(children: &[JSXElementChild]) -> bool {
   children.iter().any(|child| match child {
-    JSXElementChild::JSXExprContainer(container) => {
-      !is_allowed_dynamic_content(&container.expr)
-    }
-    JSXElementChild::JSXElement(element) => {
-      has_dynamic_content_recursive(&element.children)
-    }
-    JSXElementChild::JSXFragment(fragment) => {
-      has_dynamic_content_recursive(&fragment.children)
-    }
+    JSXElementChild::JSXExprContainer(container) => !is_allowed_dynamic_content(&container.expr),
+    JSXElementChild::JSXElement(element) => has_dynamic_content_recursive(&element.children),
+    JSXElementChild::JSXFragment(fragment) => has_dynamic_content_recursive(&fragment.children),
     JSXElementChild::JSXSpreadChild(_) => true,
     _ => false,
   })
 }
 
-impl Default for HtmlContentProps {
-  fn default() -> Self {
-    Self {
-      pl: None,
-      ti: None,
-      alt: None,
-      arl: None,
-      arb: None,
-      ard: None,
-    }
-  }
-}
-
 #[cfg(test)]
 mod tests {
   use super::*;
   use crate::config::PluginSettings;
   use crate::logging::{LogLevel, Logger};
+  use crate::visitor::dataflow::TranslatorDataflow;
   use crate::visitor::state::{ImportTracker, Statistics, TraversalState};
+  use crate::visitor::suppression::SuppressionTracker;
   use swc_core::common::{SyntaxContext, DUMMY_SP};
   use swc_core::ecma::atoms::Atom;
 
@@ -776,6 +721,10 @@ mod tests {
       settings: PluginSettings::new(LogLevel::Silent, false, None, false, false, false),
       logger: Logger::new(LogLevel::Silent),
       string_collector: crate::ast::StringCollector::new(),
+      suppressions: SuppressionTracker::default(),
+      dataflow: TranslatorDataflow::default(),
+      manifest: None,
+      findings: Vec::new(),
     }
   }
 
@@ -794,6 +743,19 @@ mod tests {
   }
 
   // Helper to create empty JSX element
+  // Helper to create a JSX element name: `Branch` or `GT.Branch`
+  fn element_name(tag_name: &str) -> JSXElementName {
+    let ident = |name: &str| Ident::new(Atom::new(name), DUMMY_SP, SyntaxContext::empty());
+    match tag_name.split_once('.') {
+      Some((namespace, component)) => JSXElementName::JSXMemberExpr(JSXMemberExpr {
+        span: DUMMY_SP,
+        obj: JSXObject::Ident(ident(namespace)),
+        prop: IdentName::new(Atom::new(component), DUMMY_SP),
+      }),
+      None => JSXElementName::Ident(ident(tag_name)),
+    }
+  }
+
   fn create_jsx_element(tag_name: &str) -> JSXElement {
     JSXElement {
       span: DUMMY_SP,
@@ -947,8 +909,8 @@ mod tests {
       let traversal = JsxTraversal::new(&visitor);
 
       // Without any imports, should return false
-      assert!(!traversal.is_branch_component("Branch"));
-      assert!(!traversal.is_branch_component("CustomBranch"));
+      assert!(!traversal.is_branch_component(&element_name("Branch")));
+      assert!(!traversal.is_branch_component(&element_name("CustomBranch")));
     }
 
     #[test]
@@ -957,8 +919,8 @@ mod tests {
       let traversal = JsxTraversal::new(&visitor);
 
       // Without any imports, should return false
-      assert!(!traversal.is_plural_component("Plural"));
-      assert!(!traversal.is_plural_component("CustomPlural"));
+      assert!(!traversal.is_plural_component(&element_name("Plural")));
+      assert!(!traversal.is_plural_component(&element_name("CustomPlural")));
     }
 
     #[test]
@@ -967,8 +929,8 @@ mod tests {
       let traversal = JsxTraversal::new(&visitor);
 
       // Namespace components without imports should return false
-      assert!(!traversal.is_branch_component("GT.Branch"));
-      assert!(!traversal.is_plural_component("GT.Plural"));
+      assert!(!traversal.is_branch_component(&element_name("GT.Branch")));
+      assert!(!traversal.is_plural_component(&element_name("GT.Plural")));
     }
   }
 
@@ -1146,7 +1108,7 @@ mod tests {
       let visitor = create_test_visitor();
       let mut traversal = JsxTraversal::new(&visitor);
       let attrs = vec![];
-      let info = traversal.analyze_gt_component("div", &attrs);
+      let info = traversal.analyze_gt_component(&element_name("div"), &attrs);
 
       assert!(!info.is_gt_component);
       assert!(info.transformation.is_none());
@@ -1159,7 +1121,7 @@ mod tests {
       let visitor = create_test_visitor();
       let mut traversal = JsxTraversal::new(&visitor);
       let attrs = vec![];
-      let info = traversal.analyze_gt_component("GT.T", &attrs);
+      let info = traversal.analyze_gt_component(&element_name("GT.T"), &attrs);
 
       // Without proper imports set up, should not detect as GT component
       assert!(!info.is_gt_component);
@@ -1203,7 +1165,10 @@ mod tests {
     }
 
     // Helper to create JSX element with children
-    fn create_jsx_element_with_children(tag_name: &str, children: Vec<JSXElementChild>) -> JSXElement {
+    fn create_jsx_element_with_children(
+      tag_name: &str,
+      children: Vec<JSXElementChild>,
+    ) -> JSXElement {
       JSXElement {
         span: DUMMY_SP,
         opening: JSXOpeningElement {
@@ -1235,67 +1200,84 @@ mod tests {
     fn test_calculate_element_hash_with_text_only() {
       let visitor = create_test_visitor();
       let mut traversal = JsxTraversal::new(&visitor);
-      
-      let element = create_jsx_element_with_children("T", vec![
-        create_jsx_text_child("Hello world")
-      ]);
+
+      let element =
+        create_jsx_element_with_children("T", vec![create_jsx_text_child("Hello world")]);
 
       let (hash, json_string) = traversal.calculate_element_hash(&element);
-      
+
       // Should have a non-empty hash since no static components
-      assert!(!hash.is_empty(), "Hash should not be empty for text-only content");
+      assert!(
+        !hash.is_empty(),
+        "Hash should not be empty for text-only content"
+      );
       assert_eq!(hash.len(), 16, "Hash should be 16 characters long");
       assert!(!json_string.is_empty(), "JSON string should not be empty");
-      assert!(json_string.contains("Hello world"), "JSON should contain the text content");
+      assert!(
+        json_string.contains("Hello world"),
+        "JSON should contain the text content"
+      );
     }
 
     #[test]
     fn test_calculate_element_hash_integration_with_contains_static() {
-      use crate::hash::{JsxHasher, SanitizedChildren, SanitizedChild, SanitizedVariable, VariableType};
-      
+      use crate::hash::{
+        JsxHasher, SanitizedChild, SanitizedChildren, SanitizedVariable, VariableType,
+      };
+
       // Test the integration between calculate_element_hash and contains_static
       // by directly testing the contains_static function with known structures
-      
+
       // Create sanitized children with static variable
-      let static_children = SanitizedChildren::Single(Box::new(
-        SanitizedChild::Variable(SanitizedVariable {
+      let static_children =
+        SanitizedChildren::Single(Box::new(SanitizedChild::Variable(SanitizedVariable {
           k: Some("static_test".to_string()),
           v: Some(VariableType::Static),
           t: None,
-        })
-      ));
-      
-      assert!(JsxHasher::contains_static(&static_children), "Should detect static variable");
-      
+        })));
+
+      assert!(
+        JsxHasher::contains_static(&static_children),
+        "Should detect static variable"
+      );
+
       // Create sanitized children with regular variable
-      let regular_children = SanitizedChildren::Single(Box::new(
-        SanitizedChild::Variable(SanitizedVariable {
+      let regular_children =
+        SanitizedChildren::Single(Box::new(SanitizedChild::Variable(SanitizedVariable {
           k: Some("regular_test".to_string()),
           v: Some(VariableType::Variable),
           t: None,
-        })
-      ));
-      
-      assert!(!JsxHasher::contains_static(&regular_children), "Should not detect regular variable as static");
+        })));
+
+      assert!(
+        !JsxHasher::contains_static(&regular_children),
+        "Should not detect regular variable as static"
+      );
     }
 
     #[test]
     fn test_calculate_element_hash_with_mixed_variables() {
       let visitor = create_test_visitor();
       let mut traversal = JsxTraversal::new(&visitor);
-      
+
       // Create element with regular variable (should hash normally)
-      let element = create_jsx_element_with_children("T", vec![
-        create_jsx_text_child("Count: "),
-        // Note: Without proper import setup, this won't be detected as a variable
-        // but it will still be processed as a regular element
-        JSXElementChild::JSXElement(Box::new(create_jsx_element("Var"))),
-      ]);
+      let element = create_jsx_element_with_children(
+        "T",
+        vec![
+          create_jsx_text_child("Count: "),
+          // Note: Without proper import setup, this won't be detected as a variable
+          // but it will still be processed as a regular element
+          JSXElementChild::JSXElement(Box::new(create_jsx_element("Var"))),
+        ],
+      );
 
       let (hash, json_string) = traversal.calculate_element_hash(&element);
-      
+
       // Should have normal hash since no static components
-      assert!(!hash.is_empty(), "Hash should not be empty for regular variables");
+      assert!(
+        !hash.is_empty(),
+        "Hash should not be empty for regular variables"
+      );
       assert_eq!(hash.len(), 16, "Hash should be 16 characters long");
       assert!(!json_string.is_empty(), "JSON string should not be empty");
     }
@@ -1304,13 +1286,16 @@ mod tests {
     fn test_calculate_element_hash_empty_children() {
       let visitor = create_test_visitor();
       let mut traversal = JsxTraversal::new(&visitor);
-      
+
       let element = create_jsx_element_with_children("T", vec![]);
 
       let (hash, json_string) = traversal.calculate_element_hash(&element);
-      
+
       // Should use fallback path with empty element structure
-      assert!(!hash.is_empty(), "Hash should not be empty for empty children");
+      assert!(
+        !hash.is_empty(),
+        "Hash should not be empty for empty children"
+      );
       assert_eq!(hash.len(), 16, "Hash should be 16 characters long");
       assert!(!json_string.is_empty(), "JSON string should not be empty");
     }
@@ -1319,24 +1304,32 @@ mod tests {
     fn test_calculate_element_hash_with_nested_elements() {
       let visitor = create_test_visitor();
       let mut traversal = JsxTraversal::new(&visitor);
-      
+
       // Create nested structure with regular elements
-      let nested_element = create_jsx_element_with_children("div", vec![
-        create_jsx_text_child("Nested content"),
-      ]);
-      
-      let element = create_jsx_element_with_children("T", vec![
-        create_jsx_text_child("Outer "),
-        JSXElementChild::JSXElement(Box::new(nested_element)),
-        create_jsx_text_child(" content")
-      ]);
+      let nested_element =
+        create_jsx_element_with_children("div", vec![create_jsx_text_child("Nested content")]);
+
+      let element = create_jsx_element_with_children(
+        "T",
+        vec![
+          create_jsx_text_child("Outer "),
+          JSXElementChild::JSXElement(Box::new(nested_element)),
+          create_jsx_text_child(" content"),
+        ],
+      );
 
       let (hash, json_string) = traversal.calculate_element_hash(&element);
-      
+
       // Should have normal hash for nested regular elements
-      assert!(!hash.is_empty(), "Hash should not be empty for nested regular elements");
+      assert!(
+        !hash.is_empty(),
+        "Hash should not be empty for nested regular elements"
+      );
       assert_eq!(hash.len(), 16, "Hash should be 16 characters long");
-      assert!(!json_string.is_empty(), "JSON string should still be generated");
+      assert!(
+        !json_string.is_empty(),
+        "JSON string should still be generated"
+      );
     }
 
     #[test]
@@ -1344,19 +1337,20 @@ mod tests {
       let visitor = create_test_visitor();
       let mut traversal = JsxTraversal::new(&visitor);
 
-      let element1 = create_jsx_element_with_children("T", vec![
-        create_jsx_text_child("Hello world")
-      ]);
-      let element2 = create_jsx_element_with_children("T", vec![
-        create_jsx_text_child("Hello world")
-      ]);
+      let element1 =
+        create_jsx_element_with_children("T", vec![create_jsx_text_child("Hello world")]);
+      let element2 =
+        create_jsx_element_with_children("T", vec![create_jsx_text_child("Hello world")]);
 
       let (hash1, _) = traversal.calculate_element_hash(&element1);
       let (hash2, _) = traversal.calculate_element_hash(&element2);
 
       // Same content should produce same hash
       assert_eq!(hash1, hash2, "Same content should produce same hash");
-      assert!(!hash1.is_empty(), "Hash should not be empty for identical content");
+      assert!(
+        !hash1.is_empty(),
+        "Hash should not be empty for identical content"
+      );
     }
 
     #[test]
@@ -1456,7 +1450,10 @@ mod tests {
       ];
 
       let result = traversal.extract_branch_props(&attrs);
-      assert!(result.is_none(), "Should return None when no valid branch props remain");
+      assert!(
+        result.is_none(),
+        "Should return None when no valid branch props remain"
+      );
     }
 
     #[test]
@@ -1598,18 +1595,17 @@ mod tests {
     #[test]
     fn spread_child_nested_in_element_is_dynamic() {
       // <div>{...items}</div> nested inside parent
-      let children = vec![
-        create_element_child("div", vec![create_spread_child("items")]),
-      ];
+      let children = vec![create_element_child(
+        "div",
+        vec![create_spread_child("items")],
+      )];
       assert!(has_dynamic_content_recursive(&children));
     }
 
     #[test]
     fn spread_child_nested_in_fragment_is_dynamic() {
       // <>{...items}</> nested inside parent
-      let children = vec![
-        create_fragment_child(vec![create_spread_child("items")]),
-      ];
+      let children = vec![create_fragment_child(vec![create_spread_child("items")])];
       assert!(has_dynamic_content_recursive(&children));
     }
 
@@ -1634,10 +1630,17 @@ mod tests {
         settings: PluginSettings::new(LogLevel::Silent, false, None, false, true, false),
         logger: Logger::new(LogLevel::Silent),
         string_collector: crate::ast::StringCollector::new(),
+        suppressions: SuppressionTracker::default(),
+        dataflow: TranslatorDataflow::default(),
+        manifest: None,
+        findings: Vec::new(),
       }
     }
 
-    fn create_jsx_element_with_children(tag_name: &str, children: Vec<JSXElementChild>) -> JSXElement {
+    fn create_jsx_element_with_children(
+      tag_name: &str,
+      children: Vec<JSXElementChild>,
+    ) -> JSXElement {
       JSXElement {
         span: DUMMY_SP,
         opening: JSXOpeningElement {
@@ -1670,22 +1673,31 @@ mod tests {
       let visitor = create_autoderive_visitor();
       let mut traversal = JsxTraversal::new(&visitor);
 
-      let element = create_jsx_element_with_children("T", vec![
-        create_jsx_text_child("Hello "),
-        JSXElementChild::JSXSpreadChild(JSXSpreadChild {
-          span: DUMMY_SP,
-          expr: Box::new(Expr::Ident(Ident {
+      let element = create_jsx_element_with_children(
+        "T",
+        vec![
+          create_jsx_text_child("Hello "),
+          JSXElementChild::JSXSpreadChild(JSXSpreadChild {
             span: DUMMY_SP,
-            sym: Atom::new("items"),
-            optional: false,
-            ctxt: SyntaxContext::empty(),
-          })),
-        }),
-      ]);
+            expr: Box::new(Expr::Ident(Ident {
+              span: DUMMY_SP,
+              sym: Atom::new("items"),
+              optional: false,
+              ctxt: SyntaxContext::empty(),
+            })),
+          }),
+        ],
+      );
 
       let (hash, json_string) = traversal.calculate_element_hash(&element);
-      assert!(hash.is_empty(), "Spread child with autoderive should produce empty hash");
-      assert!(json_string.is_empty(), "Spread child with autoderive should produce empty json");
+      assert!(
+        hash.is_empty(),
+        "Spread child with autoderive should produce empty hash"
+      );
+      assert!(
+        json_string.is_empty(),
+        "Spread child with autoderive should produce empty json"
+      );
     }
 
     #[test]
@@ -1693,12 +1705,14 @@ mod tests {
       let visitor = create_autoderive_visitor();
       let mut traversal = JsxTraversal::new(&visitor);
 
-      let element = create_jsx_element_with_children("T", vec![
-        create_jsx_text_child("Hello world"),
-      ]);
+      let element =
+        create_jsx_element_with_children("T", vec![create_jsx_text_child("Hello world")]);
 
       let (hash, _) = traversal.calculate_element_hash(&element);
-      assert!(!hash.is_empty(), "Text-only content with autoderive should still produce a hash");
+      assert!(
+        !hash.is_empty(),
+        "Text-only content with autoderive should still produce a hash"
+      );
     }
 
     #[test]
@@ -1707,21 +1721,27 @@ mod tests {
       let visitor = create_test_visitor(); // autoderive=false
       let mut traversal = JsxTraversal::new(&visitor);
 
-      let element = create_jsx_element_with_children("T", vec![
-        create_jsx_text_child("Hello "),
-        JSXElementChild::JSXSpreadChild(JSXSpreadChild {
-          span: DUMMY_SP,
-          expr: Box::new(Expr::Ident(Ident {
+      let element = create_jsx_element_with_children(
+        "T",
+        vec![
+          create_jsx_text_child("Hello "),
+          JSXElementChild::JSXSpreadChild(JSXSpreadChild {
             span: DUMMY_SP,
-            sym: Atom::new("items"),
-            optional: false,
-            ctxt: SyntaxContext::empty(),
-          })),
-        }),
-      ]);
+            expr: Box::new(Expr::Ident(Ident {
+              span: DUMMY_SP,
+              sym: Atom::new("items"),
+              optional: false,
+              ctxt: SyntaxContext::empty(),
+            })),
+          }),
+        ],
+      );
 
       let (hash, _) = traversal.calculate_element_hash(&element);
-      assert!(!hash.is_empty(), "Without autoderive, spread child should not trigger empty hash");
+      assert!(
+        !hash.is_empty(),
+        "Without autoderive, spread child should not trigger empty hash"
+      );
     }
 
     #[test]
@@ -1730,8 +1750,9 @@ mod tests {
 
       // Expression container with dynamic ident
       let mut traversal1 = JsxTraversal::new(&visitor);
-      let expr_element = create_jsx_element_with_children("T", vec![
-        JSXElementChild::JSXExprContainer(JSXExprContainer {
+      let expr_element = create_jsx_element_with_children(
+        "T",
+        vec![JSXElementChild::JSXExprContainer(JSXExprContainer {
           span: DUMMY_SP,
           expr: JSXExpr::Expr(Box::new(Expr::Ident(Ident {
             span: DUMMY_SP,
@@ -1739,13 +1760,14 @@ mod tests {
             optional: false,
             ctxt: SyntaxContext::empty(),
           }))),
-        }),
-      ]);
+        })],
+      );
 
       // Spread child
       let mut traversal2 = JsxTraversal::new(&visitor);
-      let spread_element = create_jsx_element_with_children("T", vec![
-        JSXElementChild::JSXSpreadChild(JSXSpreadChild {
+      let spread_element = create_jsx_element_with_children(
+        "T",
+        vec![JSXElementChild::JSXSpreadChild(JSXSpreadChild {
           span: DUMMY_SP,
           expr: Box::new(Expr::Ident(Ident {
             span: DUMMY_SP,
@@ -1753,14 +1775,20 @@ mod tests {
             optional: false,
             ctxt: SyntaxContext::empty(),
           })),
-        }),
-      ]);
+        })],
+      );
 
       let (expr_hash, _) = traversal1.calculate_element_hash(&expr_element);
       let (spread_hash, _) = traversal2.calculate_element_hash(&spread_element);
 
-      assert!(expr_hash.is_empty(), "Dynamic expr container should produce empty hash");
-      assert!(spread_hash.is_empty(), "Spread child should produce empty hash");
+      assert!(
+        expr_hash.is_empty(),
+        "Dynamic expr container should produce empty hash"
+      );
+      assert!(
+        spread_hash.is_empty(),
+        "Spread child should produce empty hash"
+      );
     }
   }
 }
@@ -94,16 +94,14 @@ pub fn filter_jsx_children(children: &[JSXElementChild]) -> Vec<JSXElementChild>
   let mut remove_last_child = false;
   if children.len() >= 2 {
     // Check beginning
-    let first_child = children.first().unwrap();
-    if let JSXElementChild::JSXText(text) = first_child {
+    if let Some(JSXElementChild::JSXText(text)) = children.first() {
       if trim_normal_whitespace(&text.value).is_empty() && text.value.contains('\n') {
         remove_first_child = true;
       }
     }
 
     // Check end
-    let last_child = children.last().unwrap();
-    if let JSXElementChild::JSXText(text) = last_child {
+    if let Some(JSXElementChild::JSXText(text)) = children.last() {
       if trim_normal_whitespace(&text.value).is_empty() && text.value.contains('\n') {
         remove_last_child = true;
       }
@@ -168,9 +166,7 @@ pub fn build_sanitized_text_content(text: &JSXText) -> Option<SanitizedChild> {
     // Handle leading/trailing whitespace
     let trimmed_content = trim_normal_whitespace(&content);
     let parts: Vec<&str> = content.split(trimmed_content).collect();
-    let standardized_content = if parts.len() > 1 {
-      let first_part = parts.first().unwrap();
-      let last_part = parts.last().unwrap();
+    let standardized_content = if let [first_part, .., last_part] = parts.as_slice() {
       let mut leading_space = first_part.to_string();
       let mut trailing_space = last_part.to_string();
       // Collapse newlines to empty
@@ -349,7 +345,10 @@ mod tests {
 
     #[test]
     fn identifies_relative_time_variable() {
-      assert_eq!(get_variable_type("RelativeTime"), VariableType::RelativeTime);
+      assert_eq!(
+        get_variable_type("RelativeTime"),
+        VariableType::RelativeTime
+      );
     }
 
     #[test]
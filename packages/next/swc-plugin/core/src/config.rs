@@ -0,0 +1,393 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::dictionary::Dictionary;
+use crate::logging::LogLevel;
+use crate::visitor::errors::DiagnosticCode;
+use serde::{de::Error as _, Deserialize, Deserializer};
+
+/// Severity of a build check rule, configured ESLint-style: `{ "jsx-dynamic-content": "warn" }`
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleSeverity {
+  Off,
+  Warn,
+  Error,
+}
+
+/// A GT framework package. Each preset knows which modules the package exposes and whether it
+/// re-exports gt-i18n's module-level `t`
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ImportSourcePreset {
+  Next,
+  React,
+  ReactNative,
+  TanstackStart,
+  I18n,
+}
+
+impl ImportSourcePreset {
+  pub const ALL: [ImportSourcePreset; 5] = [
+    ImportSourcePreset::Next,
+    ImportSourcePreset::React,
+    ImportSourcePreset::ReactNative,
+    ImportSourcePreset::TanstackStart,
+    ImportSourcePreset::I18n,
+  ];
+
+  /// Modules the package exposes translation APIs from
+  pub fn modules(self) -> &'static [&'static str] {
+    match self {
+      ImportSourcePreset::Next => &["gt-next", "gt-next/server"],
+      ImportSourcePreset::React => &["gt-react"],
+      ImportSourcePreset::ReactNative => &["gt-react-native"],
+      ImportSourcePreset::TanstackStart => &["gt-tanstack-start", "gt-tanstack-start/server"],
+      ImportSourcePreset::I18n => &["gt-i18n"],
+    }
+  }
+
+  /// Whether `t` imported from the package is gt-i18n's module-level translation function
+  pub fn exports_module_translation_function(self) -> bool {
+    matches!(
+      self,
+      ImportSourcePreset::React | ImportSourcePreset::TanstackStart | ImportSourcePreset::I18n
+    )
+  }
+}
+
+/// An extra module to track imports from, configured as `"@acme/i18n"` (re-exports gt-next) or
+/// `{ "source": "@acme/i18n", "preset": "react" }`
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum ImportSourceConfig {
+  Module(String),
+  Wrapper {
+    source: String,
+    preset: ImportSourcePreset,
+  },
+}
+
+impl ImportSourceConfig {
+  fn resolve(&self) -> (String, ImportSourcePreset) {
+    match self {
+      ImportSourceConfig::Module(source) => (source.clone(), ImportSourcePreset::Next),
+      ImportSourceConfig::Wrapper { source, preset } => (source.clone(), *preset),
+    }
+  }
+}
+
+// For plugin configuration and settings
+#[derive(Debug, Clone)]
+pub struct PluginSettings {
+  /// Log level for the plugin
+  pub log_level: LogLevel,
+  /// Experimental feature: inject compile-time hash attributes
+  pub compile_time_hash: bool,
+  /// Optional filename for better error messages
+  pub filename: Option<String>,
+  /// Disable dynamic content check
+  pub disable_build_checks: bool,
+  /// When true, bare variables/calls in JSX expression containers are allowed
+  pub autoderive_jsx: bool,
+  /// When true, bare variables/calls in template literals and concatenations are allowed
+  pub autoderive_strings: bool,
+  /// When true, unwrapped dynamic content in `<T>` is wrapped in `<Var>` instead of reported
+  pub auto_wrap_var: bool,
+  /// When true, interpolated template literal messages are rewritten into ICU messages
+  pub auto_icu_templates: bool,
+  /// Per-rule severity overrides, keyed by rule name
+  pub rules: HashMap<String, RuleSeverity>,
+  /// Dictionary used to validate useTranslations()/getTranslations() keys
+  pub dictionary: Option<Arc<Dictionary>>,
+  /// Modules whose imports are tracked, mapped to the package preset they behave like
+  pub import_sources: HashMap<String, ImportSourcePreset>,
+  /// Locales `<Plural>` branches are checked against, the source locale first
+  pub locales: Vec<String>,
+}
+
+impl PluginSettings {
+  pub fn new(
+    log_level: LogLevel,
+    compile_time_hash: bool,
+    filename: Option<String>,
+    disable_build_checks: bool,
+    autoderive_jsx: bool,
+    autoderive_strings: bool,
+  ) -> Self {
+    Self {
+      log_level,
+      compile_time_hash,
+      filename,
+      disable_build_checks,
+      autoderive_jsx,
+      autoderive_strings,
+      auto_wrap_var: false,
+      auto_icu_templates: false,
+      rules: HashMap::new(),
+      dictionary: None,
+      import_sources: ImportSourcePreset::ALL
+        .iter()
+        .flat_map(|preset| {
+          preset
+            .modules()
+            .iter()
+            .map(|module| (module.to_string(), *preset))
+        })
+        .collect(),
+      locales: Vec::new(),
+    }
+  }
+
+  /// Build settings from the deserialized plugin options
+  pub fn from_config(config: &PluginConfig, filename: Option<String>) -> Self {
+    let mut settings = Self {
+      auto_wrap_var: config.auto_wrap_var,
+      auto_icu_templates: config.auto_icu_templates,
+      rules: config.rules.clone(),
+      dictionary: config.dictionary.clone(),
+      locales: config.locales.clone(),
+      ..Self::new(
+        config.log_level.clone(),
+        config.compile_time_hash,
+        filename,
+        config.disable_build_checks,
+        config.autoderive_jsx,
+        config.autoderive_strings,
+      )
+    };
+    settings.import_sources.extend(
+      config
+        .import_sources
+        .iter()
+        .map(ImportSourceConfig::resolve),
+    );
+    settings
+  }
+
+  /// The preset a module's imports should be tracked with, if it is a GT import source
+  pub fn import_source_preset(&self, module: &str) -> Option<ImportSourcePreset> {
+    self.import_sources.get(module).copied()
+  }
+
+  /// Resolve the severity a diagnostic should be reported at, falling back to the rule's default
+  pub fn severity(&self, code: DiagnosticCode) -> RuleSeverity {
+    if self.disable_build_checks {
+      return RuleSeverity::Off;
+    }
+    self
+      .rules
+      .get(code.rule_name())
+      .copied()
+      .unwrap_or_else(|| code.default_severity())
+  }
+}
+
+/// Plugin configuration options
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PluginConfig {
+  #[serde(default)]
+  pub log_level: LogLevel,
+  #[serde(default)]
+  pub compile_time_hash: bool,
+  #[serde(default)]
+  pub filename: Option<String>,
+  #[serde(default)]
+  pub disable_build_checks: bool,
+  #[serde(default)]
+  pub autoderive_jsx: bool,
+  #[serde(default)]
+  pub autoderive_strings: bool,
+  /// Wrap dynamic content in `<T>` in `<Var>` at build time, when hashing is enabled
+  #[serde(default)]
+  pub auto_wrap_var: bool,
+  /// Rewrite interpolated template literal messages into ICU messages, when hashing is enabled
+  #[serde(default)]
+  pub auto_icu_templates: bool,
+  #[serde(default)]
+  pub rules: HashMap<String, RuleSeverity>,
+  /// Dictionary for key validation, inlined as an object. `withGTConfig` reads a path for you.
+  /// It is flattened once here rather than for every file.
+  #[serde(default, deserialize_with = "deserialize_dictionary")]
+  pub dictionary: Option<Arc<Dictionary>>,
+  /// Extra modules to track GT imports from, on top of the built-in framework packages
+  #[serde(default)]
+  pub import_sources: Vec<ImportSourceConfig>,
+  /// Directory to write a JSON extraction manifest per file to, when hashing is enabled
+  #[serde(default)]
+  pub manifest_dir: Option<String>,
+  /// Locales of the app, the source locale first, for locale-aware `<Plural>` checks
+  #[serde(default)]
+  pub locales: Vec<String>,
+}
+
+impl PluginConfig {
+  /// Parse the plugin options. A typo in any option, rule name or rule severity is an error
+  /// rather than a silent reset of every option to its default.
+  pub fn from_json(json: &str) -> Result<Self, String> {
+    let config: Self =
+      serde_json::from_str(json).map_err(|error| format!("invalid plugin options: {error}"))?;
+    let mut unknown_rules: Vec<&str> = config
+      .rules
+      .keys()
+      .map(String::as_str)
+      .filter(|rule| {
+        !DiagnosticCode::ALL
+          .iter()
+          .any(|code| code.rule_name() == *rule)
+      })
+      .collect();
+    if !unknown_rules.is_empty() {
+      unknown_rules.sort_unstable();
+      return Err(format!(
+        "invalid plugin options: unknown rule `{}`",
+        unknown_rules.join("`, `")
+      ));
+    }
+    Ok(config)
+  }
+}
+
+fn deserialize_dictionary<'de, D: Deserializer<'de>>(
+  deserializer: D,
+) -> Result<Option<Arc<Dictionary>>, D::Error> {
+  match serde_json::Value::deserialize(deserializer)? {
+    serde_json::Value::Null => Ok(None),
+    value => Dictionary::from_json(&value)
+      .map(|dictionary| Some(Arc::new(dictionary)))
+      .map_err(D::Error::custom),
+  }
+}
+
+impl Default for PluginConfig {
+  fn default() -> Self {
+    Self {
+      log_level: LogLevel::Warn,
+      compile_time_hash: false,
+      filename: None,
+      disable_build_checks: false,
+      autoderive_jsx: false,
+      autoderive_strings: false,
+      auto_wrap_var: false,
+      auto_icu_templates: false,
+      rules: HashMap::new(),
+      dictionary: None,
+      import_sources: Vec::new(),
+      manifest_dir: None,
+      locales: Vec::new(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::config::RuleSeverity;
+  use crate::test_utils::transform_with_diagnostics;
+  use crate::visitor::errors::DiagnosticCode;
+
+  fn config_with_rules(rules: &str) -> PluginConfig {
+    let mut config: PluginConfig =
+      serde_json::from_str(&format!(r#"{{"compileTimeHash": true, "rules": {rules}}}"#)).unwrap();
+    config.log_level = crate::logging::LogLevel::Silent;
+    config
+  }
+
+  #[test]
+  fn rejects_options_with_a_typo() {
+    let severity = PluginConfig::from_json(r#"{"rules": {"derive-shape": "warning"}}"#);
+    let preset = PluginConfig::from_json(
+      r#"{"compileTimeHash": true, "importSources": [{"source": "x", "preset": "react-dom"}]}"#,
+    );
+
+    assert!(severity
+      .unwrap_err()
+      .starts_with("invalid plugin options: "));
+    assert!(preset.is_err());
+    assert!(
+      PluginConfig::from_json(r#"{"compileTimeHash": true}"#)
+        .unwrap()
+        .compile_time_hash
+    );
+  }
+
+  #[test]
+  fn rejects_unknown_rules_and_options() {
+    let rule = PluginConfig::from_json(r#"{"rules": {"derive-shapes": "warn"}}"#);
+    let option = PluginConfig::from_json(r#"{"compileTimeHashes": true}"#);
+
+    assert_eq!(
+      rule.unwrap_err(),
+      "invalid plugin options: unknown rule `derive-shapes`"
+    );
+    assert!(option
+      .unwrap_err()
+      .contains("unknown field `compileTimeHashes`"));
+    for code in DiagnosticCode::ALL {
+      let json = format!(r#"{{"rules": {{"{}": "off"}}}}"#, code.rule_name());
+      assert!(
+        PluginConfig::from_json(&json).is_ok(),
+        "{}",
+        code.rule_name()
+      );
+    }
+  }
+
+  #[test]
+  fn deserializes_rules_map() {
+    let config = config_with_rules(
+      r#"{"jsx-dynamic-content": "warn", "t-dynamic-argument": "off", "derive-shape": "error"}"#,
+    );
+
+    assert_eq!(config.rules["jsx-dynamic-content"], RuleSeverity::Warn);
+    assert_eq!(config.rules["t-dynamic-argument"], RuleSeverity::Off);
+    assert_eq!(config.rules["derive-shape"], RuleSeverity::Error);
+  }
+
+  #[test]
+  fn warn_rules_report_warnings() {
+    let source = "import { T } from 'gt-next';\nconst el = <T>Hello {user.name}</T>;\n";
+    let (_, diagnostics) = transform_with_diagnostics(
+      source,
+      &config_with_rules(r#"{"jsx-dynamic-content": "warn"}"#),
+    );
+
+    assert_eq!(diagnostics.len(), 1);
+    assert!(!diagnostics[0].is_error());
+    assert_eq!(diagnostics[0].code.as_deref(), Some("GT001"));
+  }
+
+  #[test]
+  fn off_rules_are_silent() {
+    let source = "import { useGT } from 'gt-next';\nconst t = useGT();\nt('Hi ' + name);\n";
+    let (_, diagnostics) = transform_with_diagnostics(
+      source,
+      &config_with_rules(r#"{"t-dynamic-argument": "off"}"#),
+    );
+
+    assert!(diagnostics.is_empty());
+  }
+
+  #[test]
+  fn derive_shape_is_reported_separately() {
+    let source = "import { useGT, derive } from 'gt-next';\nconst t = useGT();\nt(derive(name));\n";
+    let (_, diagnostics) = transform_with_diagnostics(
+      source,
+      &config_with_rules(r#"{"t-dynamic-argument": "off"}"#),
+    );
+
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].is_error());
+    assert_eq!(diagnostics[0].code.as_deref(), Some("GT003"));
+  }
+
+  #[test]
+  fn unconfigured_rules_default_to_error() {
+    let source = "import { T } from 'gt-next';\nconst el = <T>Hello {user.name}</T>;\n";
+    let (_, diagnostics) = transform_with_diagnostics(source, &config_with_rules("{}"));
+
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].is_error());
+  }
+}
@@ -0,0 +1,316 @@
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+/// What a dictionary key resolves to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DictionaryEntry {
+  /// A translatable entry: `"Home"` or `["Home", { "context": "nav" }]`
+  Leaf,
+  /// A nested object of entries
+  Namespace,
+}
+
+/// A gt-next dictionary flattened to dot-separated keys
+#[derive(Debug, Clone, Default)]
+pub struct Dictionary {
+  entries: BTreeMap<String, DictionaryEntry>,
+}
+
+impl Dictionary {
+  /// Build a dictionary from its JSON representation. A path to a dictionary file is read by
+  /// the caller, once per build (`withGTConfig`, the CLI's `load_config`), not once per file.
+  pub fn from_json(value: &Value) -> Result<Self, String> {
+    let root = match value {
+      Value::Object(root) => root,
+      Value::String(path) => {
+        return Err(format!(
+          "dictionary {path} must be inlined as a JSON object"
+        ));
+      }
+      _ => return Err("dictionary must be a JSON object".to_string()),
+    };
+    let mut dictionary = Self::default();
+    for (key, value) in root {
+      dictionary.insert(key.clone(), value);
+    }
+    Ok(dictionary)
+  }
+
+  fn insert(&mut self, key: String, value: &Value) {
+    match value {
+      Value::Object(children) => {
+        for (child_key, child) in children {
+          self.insert(format!("{key}.{child_key}"), child);
+        }
+        self.entries.insert(key, DictionaryEntry::Namespace);
+      }
+      _ => {
+        self.entries.insert(key, DictionaryEntry::Leaf);
+      }
+    }
+  }
+
+  /// Look up a fully qualified key
+  pub fn get(&self, key: &str) -> Option<DictionaryEntry> {
+    self.entries.get(key).copied()
+  }
+
+  /// Leaf entries that are not in `used`, in key order
+  pub fn unused_entries<'a>(&'a self, used: &[&str]) -> Vec<&'a str> {
+    self
+      .entries
+      .iter()
+      .filter(|(key, entry)| **entry == DictionaryEntry::Leaf && !used.contains(&key.as_str()))
+      .map(|(key, _)| key.as_str())
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  fn dictionary() -> Dictionary {
+    Dictionary::from_json(&json!({
+      "nav": {
+        "home": "Home",
+        "about": ["About", { "context": "navigation" }]
+      },
+      "title": "Welcome"
+    }))
+    .unwrap()
+  }
+
+  #[test]
+  fn flattens_nested_entries() {
+    let dictionary = dictionary();
+
+    assert_eq!(dictionary.get("nav.home"), Some(DictionaryEntry::Leaf));
+    assert_eq!(dictionary.get("nav.about"), Some(DictionaryEntry::Leaf));
+    assert_eq!(dictionary.get("title"), Some(DictionaryEntry::Leaf));
+    assert_eq!(dictionary.get("nav"), Some(DictionaryEntry::Namespace));
+    assert_eq!(dictionary.get("nav.contact"), None);
+  }
+
+  #[test]
+  fn lists_unused_leaves() {
+    let dictionary = dictionary();
+
+    assert_eq!(
+      dictionary.unused_entries(&["nav.home"]),
+      vec!["nav.about", "title"]
+    );
+  }
+
+  #[test]
+  fn rejects_non_object_dictionaries() {
+    assert!(Dictionary::from_json(&json!(["Home"])).is_err());
+  }
+
+  #[test]
+  fn asks_for_dictionary_paths_to_be_inlined() {
+    let error = Dictionary::from_json(&json!("dictionary.json")).unwrap_err();
+
+    assert!(error.contains("must be inlined"));
+  }
+
+  mod dictionary_translators {
+    use crate::config::PluginConfig;
+    use crate::dictionary::Dictionary;
+    use crate::run_visitor;
+    use crate::test_utils::{
+      capture_diagnostics, config, parse, parse_with_comments, source_file,
+      transform_with_diagnostics, ReportedDiagnostic,
+    };
+    use crate::visitor::state::Statistics;
+    use std::sync::Arc;
+    use swc_core::common::comments::Comments;
+    use swc_core::ecma::codegen::to_code;
+
+    fn observe(source: &str) -> (Statistics, Vec<ReportedDiagnostic>) {
+      observe_with_config(source, &config(true, false))
+    }
+
+    fn observe_with_config(
+      source: &str,
+      config: &PluginConfig,
+    ) -> (Statistics, Vec<ReportedDiagnostic>) {
+      let (cm, comments, program) = parse_with_comments(source);
+      let ((_, visitor, _), diagnostics) = capture_diagnostics(&cm, || {
+        let comments = Some(Box::new(comments) as Box<dyn Comments>);
+        run_visitor(
+          program,
+          config,
+          None,
+          comments,
+          Some(source_file(&cm)),
+          None,
+        )
+      });
+      (visitor.statistics, diagnostics)
+    }
+
+    fn keys(statistics: &Statistics) -> Vec<Option<&str>> {
+      statistics
+        .dictionary_keys
+        .iter()
+        .map(|usage| usage.key.as_deref())
+        .collect()
+    }
+
+    #[test]
+    fn records_keys_with_namespaces() {
+      let source = r#"
+        import { useTranslations, getTranslations } from 'gt-next';
+        export function Nav() {
+          const t = useTranslations();
+          return [t('nav.home'), t(`nav.about`)];
+        }
+        export async function Footer() {
+          const t = await getTranslations('footer');
+          return t('copyright');
+        }
+      "#;
+      let (statistics, diagnostics) = observe(source);
+
+      assert!(diagnostics.is_empty());
+      assert_eq!(
+        keys(&statistics),
+        vec![
+          Some("nav.home"),
+          Some("nav.about"),
+          Some("footer.copyright")
+        ]
+      );
+    }
+
+    #[test]
+    fn warns_on_dynamic_keys() {
+      let source = "import { useTranslations } from 'gt-next';\nconst t = useTranslations();\nt(`items.${id}`);\n";
+      let (statistics, diagnostics) = observe(source);
+
+      assert_eq!(diagnostics.len(), 1);
+      assert_eq!(diagnostics[0].code.as_deref(), Some("GT005"));
+      assert!(!diagnostics[0].is_error());
+      assert_eq!((diagnostics[0].line, diagnostics[0].col), (3, 3));
+      assert_eq!(keys(&statistics), vec![None]);
+    }
+
+    #[test]
+    fn dynamic_namespaces_leave_keys_unresolved() {
+      let source = r#"
+        import { useTranslations } from 'gt-next';
+        const t = useTranslations(section);
+        t('title');
+      "#;
+      let (statistics, diagnostics) = observe(source);
+
+      assert!(diagnostics.is_empty());
+      assert_eq!(keys(&statistics), vec![None]);
+    }
+
+    #[test]
+    fn tracks_message_decoders_without_key_checks() {
+      let source = r#"
+        import { useMessages, msg } from 'gt-next';
+        const greeting = msg('Hello');
+        const m = useMessages();
+        m(greeting);
+      "#;
+      let (statistics, diagnostics) = observe(source);
+
+      assert!(diagnostics.is_empty());
+      assert_eq!(statistics.message_decode_count, 1);
+      assert!(statistics.dictionary_keys.is_empty());
+    }
+
+    fn config_with_dictionary() -> PluginConfig {
+      PluginConfig {
+        dictionary: Dictionary::from_json(&serde_json::json!({
+          "nav": { "home": "Home", "about": ["About", { "context": "navigation" }] },
+          "footer": { "copyright": "All rights reserved" }
+        }))
+        .ok()
+        .map(Arc::new),
+        ..config(true, false)
+      }
+    }
+
+    #[test]
+    fn flattens_the_dictionary_when_options_are_parsed() {
+      let config = PluginConfig::from_json(r#"{"dictionary": {"nav": {"home": "Home"}}}"#).unwrap();
+      let path = PluginConfig::from_json(r#"{"dictionary": "dictionary.json"}"#);
+
+      let dictionary = config.dictionary.unwrap();
+      assert_eq!(
+        dictionary.get("nav.home"),
+        Some(crate::dictionary::DictionaryEntry::Leaf)
+      );
+      assert!(path
+        .unwrap_err()
+        .contains("dictionary dictionary.json must be inlined"));
+    }
+
+    #[test]
+    fn accepts_keys_in_the_dictionary() {
+      let source = r#"
+        import { useTranslations } from 'gt-next';
+        const t = useTranslations();
+        const nav = useTranslations('nav');
+        t('footer.copyright');
+        nav('about');
+      "#;
+      let (_, diagnostics) = observe_with_config(source, &config_with_dictionary());
+
+      assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn reports_missing_keys() {
+      let source = "import { useTranslations } from 'gt-next';\nconst t = useTranslations('nav');\nt('hom');\n";
+      let (_, diagnostics) = observe_with_config(source, &config_with_dictionary());
+
+      assert_eq!(diagnostics.len(), 1);
+      let diagnostic = &diagnostics[0];
+      assert_eq!(diagnostic.code.as_deref(), Some("GT006"));
+      assert!(diagnostic.is_error());
+      assert!(diagnostic.message.contains("\"nav.hom\""));
+      assert_eq!((diagnostic.line, diagnostic.col), (3, 3));
+    }
+
+    #[test]
+    fn reports_namespace_keys() {
+      let source = "import { getTranslations } from 'gt-next';\nconst t = await getTranslations();\nt('nav');\n";
+      let (_, diagnostics) = observe_with_config(source, &config_with_dictionary());
+
+      assert_eq!(diagnostics.len(), 1);
+      assert_eq!(diagnostics[0].code.as_deref(), Some("GT007"));
+    }
+
+    #[test]
+    fn skips_unresolved_keys() {
+      let source = r#"
+        import { useTranslations } from 'gt-next';
+        const t = useTranslations(section);
+        t('missing');
+      "#;
+      let (_, diagnostics) = observe_with_config(source, &config_with_dictionary());
+
+      assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn leaves_dictionary_calls_untouched() {
+      let source = r#"
+        import { useTranslations } from 'gt-next';
+        const t = useTranslations();
+        t('nav.home');
+      "#;
+      let (output, _) = transform_with_diagnostics(source, &config(true, false));
+
+      assert_eq!(output, to_code(&parse(source)));
+    }
+  }
+}
@@ -21,7 +21,7 @@ pub enum VariableType {
 }
 
 /// Map of data-_gt properties to their corresponding React props
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 pub struct HtmlContentProps {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub pl: Option<String>, // placeholder
@@ -130,9 +130,7 @@ impl JsxHasher {
   fn handle_children(children: &SanitizedChildren) -> bool {
     match children {
       SanitizedChildren::Single(child) => Self::handle_child(child.as_ref()),
-      SanitizedChildren::Multiple(children_vec) => {
-        children_vec.iter().any(|child| Self::handle_child(child))
-      }
+      SanitizedChildren::Multiple(children_vec) => children_vec.iter().any(Self::handle_child),
       SanitizedChildren::Wrapped { c } => Self::handle_children(c.as_ref()),
     }
   }
@@ -145,7 +143,9 @@ impl JsxHasher {
       SanitizedChild::Element(element) => Self::handle_element(element.as_ref()),
       SanitizedChild::Boolean(_) => false,
       SanitizedChild::Null(_) => false,
-      SanitizedChild::Fragment(fragment_children) => Self::handle_children(fragment_children.as_ref()),
+      SanitizedChild::Fragment(fragment_children) => {
+        Self::handle_children(fragment_children.as_ref())
+      }
     }
   }
 
@@ -161,7 +161,10 @@ impl JsxHasher {
   fn handle_element(element: &SanitizedElement) -> bool {
     // Check branches first (for Branch/Plural components)
     if let Some(branches) = &element.b {
-      if branches.values().any(|branch| Self::handle_child(branch.as_ref())) {
+      if branches
+        .values()
+        .any(|branch| Self::handle_child(branch.as_ref()))
+      {
         return true;
       }
     }
@@ -176,7 +179,10 @@ impl JsxHasher {
     // Check GT data branches
     if let Some(gt_data) = &element.d {
       if let Some(gt_branches) = &gt_data.b {
-        if gt_branches.values().any(|branch| Self::handle_child(branch.as_ref())) {
+        if gt_branches
+          .values()
+          .any(|branch| Self::handle_child(branch.as_ref()))
+        {
           return true;
         }
       }
@@ -534,28 +540,40 @@ mod tests {
 
     #[test]
     fn test_contains_static_with_text_only() {
-      let children = SanitizedChildren::Single(Box::new(SanitizedChild::Text("Hello world".to_string())));
-      assert!(!JsxHasher::contains_static(&children), "Text-only content should not contain static");
+      let children =
+        SanitizedChildren::Single(Box::new(SanitizedChild::Text("Hello world".to_string())));
+      assert!(
+        !JsxHasher::contains_static(&children),
+        "Text-only content should not contain static"
+      );
     }
 
     #[test]
     fn test_contains_static_with_regular_variable() {
-      let children = SanitizedChildren::Single(Box::new(SanitizedChild::Variable(SanitizedVariable {
-        k: Some("name".to_string()),
-        v: Some(VariableType::Variable),
-        t: None,
-      })));
-      assert!(!JsxHasher::contains_static(&children), "Regular variable should not be static");
+      let children =
+        SanitizedChildren::Single(Box::new(SanitizedChild::Variable(SanitizedVariable {
+          k: Some("name".to_string()),
+          v: Some(VariableType::Variable),
+          t: None,
+        })));
+      assert!(
+        !JsxHasher::contains_static(&children),
+        "Regular variable should not be static"
+      );
     }
 
     #[test]
     fn test_contains_static_with_static_variable() {
-      let children = SanitizedChildren::Single(Box::new(SanitizedChild::Variable(SanitizedVariable {
-        k: Some("static_content".to_string()),
-        v: Some(VariableType::Static),
-        t: None,
-      })));
-      assert!(JsxHasher::contains_static(&children), "Static variable should be detected");
+      let children =
+        SanitizedChildren::Single(Box::new(SanitizedChild::Variable(SanitizedVariable {
+          k: Some("static_content".to_string()),
+          v: Some(VariableType::Static),
+          t: None,
+        })));
+      assert!(
+        JsxHasher::contains_static(&children),
+        "Static variable should be detected"
+      );
     }
 
     #[test]
@@ -574,33 +592,45 @@ mod tests {
         }),
         SanitizedChild::Text("!".to_string()),
       ]);
-      assert!(JsxHasher::contains_static(&children), "Should detect static variable in multiple children");
+      assert!(
+        JsxHasher::contains_static(&children),
+        "Should detect static variable in multiple children"
+      );
     }
 
     #[test]
     fn test_contains_static_in_element_children() {
       let element = SanitizedElement {
         b: None,
-        c: Some(Box::new(SanitizedChildren::Single(Box::new(SanitizedChild::Variable(SanitizedVariable {
-          k: Some("static_content".to_string()),
-          v: Some(VariableType::Static),
-          t: None,
-        }))))),
+        c: Some(Box::new(SanitizedChildren::Single(Box::new(
+          SanitizedChild::Variable(SanitizedVariable {
+            k: Some("static_content".to_string()),
+            v: Some(VariableType::Static),
+            t: None,
+          }),
+        )))),
         t: Some("div".to_string()),
         d: None,
       };
-      let children = SanitizedChildren::Single(Box::new(SanitizedChild::Element(Box::new(element))));
-      assert!(JsxHasher::contains_static(&children), "Should detect static variable in nested element");
+      let children =
+        SanitizedChildren::Single(Box::new(SanitizedChild::Element(Box::new(element))));
+      assert!(
+        JsxHasher::contains_static(&children),
+        "Should detect static variable in nested element"
+      );
     }
 
     #[test]
     fn test_contains_static_in_element_branches() {
       let mut branches = BTreeMap::new();
-      branches.insert("case1".to_string(), Box::new(SanitizedChild::Variable(SanitizedVariable {
-        k: Some("static_content".to_string()),
-        v: Some(VariableType::Static),
-        t: None,
-      })));
+      branches.insert(
+        "case1".to_string(),
+        Box::new(SanitizedChild::Variable(SanitizedVariable {
+          k: Some("static_content".to_string()),
+          v: Some(VariableType::Static),
+          t: None,
+        })),
+      );
 
       let element = SanitizedElement {
         b: Some(branches),
@@ -608,18 +638,25 @@ mod tests {
         t: Some("b".to_string()),
         d: None,
       };
-      let children = SanitizedChildren::Single(Box::new(SanitizedChild::Element(Box::new(element))));
-      assert!(JsxHasher::contains_static(&children), "Should detect static variable in element branches");
+      let children =
+        SanitizedChildren::Single(Box::new(SanitizedChild::Element(Box::new(element))));
+      assert!(
+        JsxHasher::contains_static(&children),
+        "Should detect static variable in element branches"
+      );
     }
 
     #[test]
     fn test_contains_static_in_gt_data_branches() {
       let mut gt_branches = BTreeMap::new();
-      gt_branches.insert("option1".to_string(), Box::new(SanitizedChild::Variable(SanitizedVariable {
-        k: Some("static_content".to_string()),
-        v: Some(VariableType::Static),
-        t: None,
-      })));
+      gt_branches.insert(
+        "option1".to_string(),
+        Box::new(SanitizedChild::Variable(SanitizedVariable {
+          k: Some("static_content".to_string()),
+          v: Some(VariableType::Static),
+          t: None,
+        })),
+      );
 
       let gt_prop = SanitizedGtProp {
         b: Some(gt_branches),
@@ -633,54 +670,72 @@ mod tests {
         t: Some("T".to_string()),
         d: Some(gt_prop),
       };
-      let children = SanitizedChildren::Single(Box::new(SanitizedChild::Element(Box::new(element))));
-      assert!(JsxHasher::contains_static(&children), "Should detect static variable in GT data branches");
+      let children =
+        SanitizedChildren::Single(Box::new(SanitizedChild::Element(Box::new(element))));
+      assert!(
+        JsxHasher::contains_static(&children),
+        "Should detect static variable in GT data branches"
+      );
     }
 
     #[test]
     fn test_contains_static_in_fragment() {
-      let fragment_children = SanitizedChildren::Single(Box::new(SanitizedChild::Variable(SanitizedVariable {
-        k: Some("static_content".to_string()),
-        v: Some(VariableType::Static),
-        t: None,
-      })));
-      let children = SanitizedChildren::Single(Box::new(SanitizedChild::Fragment(Box::new(fragment_children))));
-      assert!(JsxHasher::contains_static(&children), "Should detect static variable in fragment");
+      let fragment_children =
+        SanitizedChildren::Single(Box::new(SanitizedChild::Variable(SanitizedVariable {
+          k: Some("static_content".to_string()),
+          v: Some(VariableType::Static),
+          t: None,
+        })));
+      let children = SanitizedChildren::Single(Box::new(SanitizedChild::Fragment(Box::new(
+        fragment_children,
+      ))));
+      assert!(
+        JsxHasher::contains_static(&children),
+        "Should detect static variable in fragment"
+      );
     }
 
     #[test]
     fn test_contains_static_in_wrapped_children() {
       let wrapped_children = SanitizedChildren::Wrapped {
-        c: Box::new(SanitizedChildren::Single(Box::new(SanitizedChild::Variable(SanitizedVariable {
-          k: Some("static_content".to_string()),
-          v: Some(VariableType::Static),
-          t: None,
-        })))),
+        c: Box::new(SanitizedChildren::Single(Box::new(
+          SanitizedChild::Variable(SanitizedVariable {
+            k: Some("static_content".to_string()),
+            v: Some(VariableType::Static),
+            t: None,
+          }),
+        ))),
       };
-      assert!(JsxHasher::contains_static(&wrapped_children), "Should detect static variable in wrapped children");
+      assert!(
+        JsxHasher::contains_static(&wrapped_children),
+        "Should detect static variable in wrapped children"
+      );
     }
 
     #[test]
     fn test_contains_static_complex_nested_structure() {
       let mut branches = BTreeMap::new();
-      branches.insert("nested".to_string(), Box::new(SanitizedChild::Element(Box::new(SanitizedElement {
-        b: None,
-        c: Some(Box::new(SanitizedChildren::Multiple(vec![
-          SanitizedChild::Text("Some text ".to_string()),
-          SanitizedChild::Variable(SanitizedVariable {
-            k: Some("regular_var".to_string()),
-            v: Some(VariableType::Number),
-            t: None,
-          }),
-          SanitizedChild::Variable(SanitizedVariable {
-            k: Some("deep_static".to_string()),
-            v: Some(VariableType::Static),
-            t: None,
-          }),
-        ]))),
-        t: Some("span".to_string()),
-        d: None,
-      }))));
+      branches.insert(
+        "nested".to_string(),
+        Box::new(SanitizedChild::Element(Box::new(SanitizedElement {
+          b: None,
+          c: Some(Box::new(SanitizedChildren::Multiple(vec![
+            SanitizedChild::Text("Some text ".to_string()),
+            SanitizedChild::Variable(SanitizedVariable {
+              k: Some("regular_var".to_string()),
+              v: Some(VariableType::Number),
+              t: None,
+            }),
+            SanitizedChild::Variable(SanitizedVariable {
+              k: Some("deep_static".to_string()),
+              v: Some(VariableType::Static),
+              t: None,
+            }),
+          ]))),
+          t: Some("span".to_string()),
+          d: None,
+        }))),
+      );
 
       let element = SanitizedElement {
         b: Some(branches),
@@ -688,8 +743,12 @@ mod tests {
         t: Some("b".to_string()),
         d: None,
       };
-      let children = SanitizedChildren::Single(Box::new(SanitizedChild::Element(Box::new(element))));
-      assert!(JsxHasher::contains_static(&children), "Should detect static variable in deeply nested structure");
+      let children =
+        SanitizedChildren::Single(Box::new(SanitizedChild::Element(Box::new(element))));
+      assert!(
+        JsxHasher::contains_static(&children),
+        "Should detect static variable in deeply nested structure"
+      );
     }
 
     #[test]
@@ -703,17 +762,22 @@ mod tests {
         }),
         SanitizedChild::Element(Box::new(SanitizedElement {
           b: None,
-          c: Some(Box::new(SanitizedChildren::Single(Box::new(SanitizedChild::Variable(SanitizedVariable {
-            k: Some("count".to_string()),
-            v: Some(VariableType::Number),
-            t: None,
-          }))))),
+          c: Some(Box::new(SanitizedChildren::Single(Box::new(
+            SanitizedChild::Variable(SanitizedVariable {
+              k: Some("count".to_string()),
+              v: Some(VariableType::Number),
+              t: None,
+            }),
+          )))),
           t: Some("div".to_string()),
           d: None,
         })),
         SanitizedChild::Text("!".to_string()),
       ]);
-      assert!(!JsxHasher::contains_static(&children), "Complex structure without static should return false");
+      assert!(
+        !JsxHasher::contains_static(&children),
+        "Complex structure without static should return false"
+      );
     }
   }
 }
@@ -0,0 +1,611 @@
+use std::fmt;
+
+/// Argument types that take an optional style: `{price, number, ::currency/USD}`. FormatJS
+/// rejects ICU4J's `spellout`, `ordinal` and `duration`.
+const FORMATTED_TYPES: &[&str] = &["number", "date", "time"];
+
+/// CLDR plural categories, the keywords `plural` and `selectordinal` options can use
+pub const PLURAL_CATEGORIES: &[&str] = &["zero", "one", "two", "few", "many", "other"];
+
+/// A parsed ICU MessageFormat message
+pub type Message = Vec<Element>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Element {
+  /// Text, with quoting resolved
+  Literal(String),
+  /// `{name}`, `{name, number}` or `{name, plural, ...}`. `offset` is the byte offset of the
+  /// name in the message.
+  Argument {
+    name: String,
+    offset: usize,
+    kind: ArgumentKind,
+  },
+  /// `#` inside a plural option
+  Pound,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArgumentKind {
+  /// `{name}`
+  Simple,
+  /// `{name, number}`, `{name, date, short}`
+  Formatted {
+    format: String,
+    style: Option<String>,
+  },
+  /// `{count, plural, offset:1 one {...} other {...}}`, `{place, selectordinal, ...}`
+  Plural {
+    ordinal: bool,
+    offset: Option<i32>,
+    options: Vec<SelectOption>,
+  },
+  /// `{gender, select, female {...} other {...}}`
+  Select { options: Vec<SelectOption> },
+}
+
+/// A `selector {message}` option of a plural or select argument
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectOption {
+  pub selector: String,
+  pub message: Message,
+}
+
+/// A syntax error at a byte offset in the message
+#[derive(Debug, Clone, PartialEq)]
+pub struct IcuError {
+  pub message: String,
+  pub offset: usize,
+}
+
+impl fmt::Display for IcuError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&self.message)
+  }
+}
+
+/// Parse an ICU MessageFormat message the way FormatJS, which formats it at runtime, does:
+/// `''` is a literal apostrophe and `'` only starts quoted text before `{`, `}`, `<`, `>` or a
+/// `#` in a plural option. Quoted text runs to the next single apostrophe or the end of the
+/// message, and a `}` outside any argument is text.
+pub fn parse(message: &str) -> Result<Message, IcuError> {
+  let mut parser = Parser {
+    source: message,
+    pos: 0,
+  };
+  parser.parse_message(false, None)
+}
+
+/// Where the parser is in the message
+struct Parser<'a> {
+  source: &'a str,
+  pos: usize,
+}
+
+impl Parser<'_> {
+  fn peek(&self) -> Option<char> {
+    self.source[self.pos..].chars().next()
+  }
+
+  fn bump(&mut self) -> Option<char> {
+    let ch = self.peek()?;
+    self.pos += ch.len_utf8();
+    Some(ch)
+  }
+
+  fn skip_whitespace(&mut self) {
+    while self.peek().is_some_and(char::is_whitespace) {
+      self.bump();
+    }
+  }
+
+  fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &str {
+    let start = self.pos;
+    while self.peek().is_some_and(&predicate) {
+      self.bump();
+    }
+    &self.source[start..self.pos]
+  }
+
+  fn error<T>(&self, message: impl Into<String>, offset: usize) -> Result<T, IcuError> {
+    Err(IcuError {
+      message: message.into(),
+      offset,
+    })
+  }
+
+  /// Parse text and arguments up to the end of the message, or up to the `}` closing the
+  /// option that opened at `open` when nested
+  fn parse_message(&mut self, in_plural: bool, open: Option<usize>) -> Result<Message, IcuError> {
+    let mut elements = Vec::new();
+    let mut text = String::new();
+    loop {
+      match self.peek() {
+        None => {
+          if let Some(open) = open {
+            return self.error("unclosed '{'", open);
+          }
+          break;
+        }
+        Some('}') if open.is_some() => break,
+        Some('{') => {
+          flush_literal(&mut elements, &mut text);
+          elements.push(self.parse_argument()?);
+        }
+        Some('#') if in_plural => {
+          self.bump();
+          flush_literal(&mut elements, &mut text);
+          elements.push(Element::Pound);
+        }
+        Some('\'') => self.parse_apostrophe(in_plural, &mut text),
+        Some(ch) => {
+          self.bump();
+          text.push(ch);
+        }
+      }
+    }
+    flush_literal(&mut elements, &mut text);
+    Ok(elements)
+  }
+
+  /// `''` is an apostrophe, `'{'` quotes syntax characters and any other `'` is literal
+  fn parse_apostrophe(&mut self, in_plural: bool, text: &mut String) {
+    self.bump();
+    match self.peek() {
+      Some('\'') => {
+        self.bump();
+        text.push('\'');
+      }
+      Some('{' | '}' | '<' | '>') => self.parse_quoted(text),
+      Some('#') if in_plural => self.parse_quoted(text),
+      _ => text.push('\''),
+    }
+  }
+
+  /// Quoted text, up to the next single apostrophe or the end of the message
+  fn parse_quoted(&mut self, text: &mut String) {
+    while let Some(ch) = self.bump() {
+      match ch {
+        '\'' if self.peek() == Some('\'') => {
+          self.bump();
+          text.push('\'');
+        }
+        '\'' => return,
+        _ => text.push(ch),
+      }
+    }
+  }
+
+  fn parse_argument(&mut self) -> Result<Element, IcuError> {
+    let open = self.pos;
+    self.bump();
+    self.skip_whitespace();
+    let offset = self.pos;
+    let name = self.take_while(is_name_char).to_string();
+    if name.is_empty() {
+      return match self.peek() {
+        None => self.error("unclosed '{'", open),
+        Some('}') => self.error("empty argument: expected a name", open),
+        Some(ch) => self.error(
+          format!("invalid character '{ch}' in argument name"),
+          self.pos,
+        ),
+      };
+    }
+    self.skip_whitespace();
+    let kind = match self.bump() {
+      None => return self.error("unclosed '{'", open),
+      Some('}') => ArgumentKind::Simple,
+      Some(',') => self.parse_argument_kind(open)?,
+      Some(ch) => {
+        let message = format!("invalid character '{ch}' in argument name");
+        return self.error(message, self.pos - ch.len_utf8());
+      }
+    };
+    Ok(Element::Argument { name, offset, kind })
+  }
+
+  /// The `type[, style]` or `plural, options` part of an argument, through its closing `}`
+  fn parse_argument_kind(&mut self, open: usize) -> Result<ArgumentKind, IcuError> {
+    self.skip_whitespace();
+    let type_offset = self.pos;
+    let format = self.take_while(|ch| ch.is_ascii_alphabetic()).to_string();
+    if format.is_empty() {
+      return match self.peek() {
+        None => self.error("unclosed '{'", open),
+        _ => self.error("expected an argument type", self.pos),
+      };
+    }
+    self.skip_whitespace();
+    match format.as_str() {
+      "plural" | "selectordinal" => {
+        self.expect_comma(&format, open)?;
+        self.skip_whitespace();
+        let offset = self.parse_plural_offset()?;
+        let options = self.parse_options(true, open)?;
+        Ok(ArgumentKind::Plural {
+          ordinal: format == "selectordinal",
+          offset,
+          options,
+        })
+      }
+      "select" => {
+        self.expect_comma(&format, open)?;
+        let options = self.parse_options(false, open)?;
+        Ok(ArgumentKind::Select { options })
+      }
+      _ if FORMATTED_TYPES.contains(&format.as_str()) => {
+        let style = match self.bump() {
+          Some('}') => None,
+          Some(',') => Some(self.parse_style(&format, open)?),
+          Some(ch) => {
+            let message = format!("expected ',' or '}}' after '{format}', found '{ch}'");
+            return self.error(message, self.pos - ch.len_utf8());
+          }
+          None => return self.error("unclosed '{'", open),
+        };
+        Ok(ArgumentKind::Formatted { format, style })
+      }
+      _ => self.error(format!("unknown argument type '{format}'"), type_offset),
+    }
+  }
+
+  fn expect_comma(&mut self, format: &str, open: usize) -> Result<(), IcuError> {
+    match self.peek() {
+      Some(',') => {
+        self.bump();
+        Ok(())
+      }
+      None => self.error("unclosed '{'", open),
+      Some(_) => self.error(
+        format!("expected ',' and options after '{format}'"),
+        self.pos,
+      ),
+    }
+  }
+
+  /// A `number`/`date`/`time` style or skeleton, through the closing `}`
+  fn parse_style(&mut self, format: &str, open: usize) -> Result<String, IcuError> {
+    self.skip_whitespace();
+    let start = self.pos;
+    let style = self
+      .take_while(|ch| ch != '}' && ch != '{')
+      .trim_end()
+      .to_string();
+    match self.bump() {
+      Some('}') if style.is_empty() => self.error(format!("expected a {format} style"), start),
+      Some('}') => Ok(style),
+      Some(_) => self.error(format!("unexpected '{{' in {format} style"), self.pos - 1),
+      None => self.error("unclosed '{'", open),
+    }
+  }
+
+  /// `offset:1` before the options of a plural argument
+  fn parse_plural_offset(&mut self) -> Result<Option<i32>, IcuError> {
+    if !self.source[self.pos..].starts_with("offset:") {
+      return Ok(None);
+    }
+    self.pos += "offset:".len();
+    self.skip_whitespace();
+    let start = self.pos;
+    if self.peek() == Some('-') {
+      self.bump();
+    }
+    self.take_while(|ch| ch.is_ascii_digit());
+    match self.source[start..self.pos].parse() {
+      Ok(offset) => Ok(Some(offset)),
+      Err(_) => self.error("expected a number after 'offset:'", start),
+    }
+  }
+
+  /// `selector {message}` options through the argument's closing `}`
+  fn parse_options(&mut self, plural: bool, open: usize) -> Result<Vec<SelectOption>, IcuError> {
+    let mut options: Vec<SelectOption> = Vec::new();
+    loop {
+      self.skip_whitespace();
+      let selector_offset = self.pos;
+      match self.peek() {
+        None => return self.error("unclosed '{'", open),
+        Some('}') => {
+          self.bump();
+          break;
+        }
+        _ => {}
+      }
+      let selector = self.parse_selector(plural)?;
+      if options.iter().any(|option| option.selector == selector) {
+        return self.error(format!("duplicate option '{selector}'"), selector_offset);
+      }
+      self.skip_whitespace();
+      let option_open = self.pos;
+      if self.peek() != Some('{') {
+        return self.error(format!("expected '{{' after option '{selector}'"), self.pos);
+      }
+      self.bump();
+      let message = self.parse_message(plural, Some(option_open))?;
+      self.bump();
+      options.push(SelectOption { selector, message });
+    }
+    if !options.iter().any(|option| option.selector == "other") {
+      return self.error("missing 'other' option", open);
+    }
+    Ok(options)
+  }
+
+  /// `=0` or a plural category for plural arguments, any key for select arguments
+  fn parse_selector(&mut self, plural: bool) -> Result<String, IcuError> {
+    let start = self.pos;
+    if plural && self.peek() == Some('=') {
+      self.bump();
+      if self.peek() == Some('-') {
+        self.bump();
+      }
+      let digits = self.take_while(|ch| ch.is_ascii_digit() || ch == '.');
+      if digits.is_empty() {
+        return self.error("expected a number after '='", self.pos);
+      }
+      return Ok(self.source[start..self.pos].to_string());
+    }
+    let selector = self
+      .take_while(|ch| is_name_char(ch) || ch == '-')
+      .to_string();
+    if selector.is_empty() {
+      let found = self
+        .peek()
+        .map(|ch| format!(", found '{ch}'"))
+        .unwrap_or_default();
+      return self.error(format!("expected an option selector{found}"), start);
+    }
+    if plural && !PLURAL_CATEGORIES.contains(&selector.as_str()) {
+      return self.error(
+        format!(
+          "'{selector}' is not a plural category: use =N or one of {}",
+          PLURAL_CATEGORIES.join(", ")
+        ),
+        start,
+      );
+    }
+    Ok(selector)
+  }
+}
+
+/// Names of the arguments a message references, with their byte offsets, in order
+pub fn argument_names(message: &Message) -> Vec<(&str, usize)> {
+  let mut names = Vec::new();
+  collect_argument_names(message, &mut names);
+  names
+}
+
+fn collect_argument_names<'a>(message: &'a Message, names: &mut Vec<(&'a str, usize)>) {
+  for element in message {
+    let Element::Argument { name, offset, kind } = element else {
+      continue;
+    };
+    names.push((name.as_str(), *offset));
+    if let ArgumentKind::Plural { options, .. } | ArgumentKind::Select { options } = kind {
+      for option in options {
+        collect_argument_names(&option.message, names);
+      }
+    }
+  }
+}
+
+fn flush_literal(elements: &mut Message, text: &mut String) {
+  if !text.is_empty() {
+    elements.push(Element::Literal(std::mem::take(text)));
+  }
+}
+
+/// Argument names can't contain whitespace or ICU pattern syntax (ASCII punctuation but `_`)
+fn is_name_char(ch: char) -> bool {
+  ch.is_alphanumeric() || ch == '_' || !(ch.is_ascii() || ch.is_whitespace())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn error(message: &str) -> IcuError {
+    parse(message).unwrap_err()
+  }
+
+  #[test]
+  fn parses_text_and_arguments() {
+    let message = parse("Hello {name}, you owe {amount, number, ::currency/USD}").unwrap();
+
+    assert_eq!(message.len(), 4);
+    assert_eq!(message[0], Element::Literal("Hello ".to_string()));
+    assert_eq!(
+      message[1],
+      Element::Argument {
+        name: "name".to_string(),
+        offset: 7,
+        kind: ArgumentKind::Simple,
+      }
+    );
+    assert_eq!(
+      message[3],
+      Element::Argument {
+        name: "amount".to_string(),
+        offset: 23,
+        kind: ArgumentKind::Formatted {
+          format: "number".to_string(),
+          style: Some("::currency/USD".to_string()),
+        },
+      }
+    );
+  }
+
+  #[test]
+  fn parses_nested_plural_and_select() {
+    let message = parse(
+      "{count, plural, offset:1 =0 {No one} one {# guest} other {{gender, select, female {her} other {their}} # guests}}",
+    )
+    .unwrap();
+
+    let Element::Argument {
+      kind: ArgumentKind::Plural {
+        ordinal,
+        offset,
+        options,
+      },
+      ..
+    } = &message[0]
+    else {
+      panic!("expected a plural argument");
+    };
+    assert!(!ordinal);
+    assert_eq!(*offset, Some(1));
+    assert_eq!(options.len(), 3);
+    assert_eq!(options[1].message[0], Element::Pound);
+  }
+
+  #[test]
+  fn resolves_apostrophe_quoting() {
+    let message = parse("It''s '{literal}' and don't").unwrap();
+
+    assert_eq!(
+      message,
+      vec![Element::Literal("It's {literal} and don't".to_string())]
+    );
+  }
+
+  #[test]
+  fn treats_pound_as_text_outside_plurals() {
+    assert_eq!(
+      parse("Item #1").unwrap(),
+      vec![Element::Literal("Item #1".to_string())]
+    );
+  }
+
+  #[test]
+  fn reports_unbalanced_braces() {
+    assert_eq!(error("Hello {name").offset, 6);
+    assert_eq!(error("{count, plural, other {# items}").offset, 0);
+  }
+
+  #[test]
+  fn reports_invalid_arguments() {
+    assert_eq!(error("Hello {}").message, "empty argument: expected a name");
+    assert_eq!(error("Hello {user.name}").offset, 11);
+    assert_eq!(error("{n, numbr}").message, "unknown argument type 'numbr'");
+    assert_eq!(error("{n, number, }").message, "expected a number style");
+  }
+
+  #[test]
+  fn reports_bad_plural_and_select_syntax() {
+    assert_eq!(
+      error("{n, plural, one {#}}").message,
+      "missing 'other' option"
+    );
+    assert_eq!(error("{n, plural, ones {#} other {#}}").offset, 12);
+    assert_eq!(
+      error("{n, select, a {A} a {B} other {C}}").message,
+      "duplicate option 'a'"
+    );
+    assert_eq!(error("{n, select, a A other {C}}").offset, 14);
+    assert_eq!(error("{n, plural other {#}}").offset, 11);
+  }
+
+  #[test]
+  fn lists_argument_names_in_nested_options() {
+    let message = parse("{count, plural, one {{name} has #} other {{name} and {others}}}").unwrap();
+
+    let names: Vec<&str> = argument_names(&message)
+      .into_iter()
+      .map(|(name, _)| name)
+      .collect();
+    assert_eq!(names, vec!["count", "name", "name", "others"]);
+  }
+
+  #[test]
+  fn quotes_the_rest_of_the_message_when_unterminated() {
+    let message = parse("Use '{braces} for text").unwrap();
+
+    assert_eq!(
+      message,
+      vec![Element::Literal("Use {braces} for text".to_string())]
+    );
+  }
+
+  #[test]
+  fn quotes_like_formatjs() {
+    assert_eq!(parse("A '| {name}").unwrap().len(), 2);
+    assert_eq!(
+      parse("I '<'3 cats").unwrap(),
+      vec![Element::Literal("I <3 cats".to_string())]
+    );
+    assert_eq!(
+      parse("Hello name}").unwrap(),
+      vec![Element::Literal("Hello name}".to_string())]
+    );
+  }
+
+  #[test]
+  fn rejects_icu4j_only_argument_types() {
+    for format in ["spellout", "ordinal", "duration"] {
+      let message = format!("{{n, {format}}}");
+      assert_eq!(
+        error(&message).message,
+        format!("unknown argument type '{format}'")
+      );
+    }
+  }
+
+  // Adapted from the FormatJS parser integration corpus, see packages/icu. Fixtures with a
+  // plural or select missing its `other` option are left out, since that is always reported.
+  #[test]
+  fn accepts_formatjs_fixtures() {
+    let fixtures = [
+      "a''b",
+      "I '<'3 cats. '<a>foo</a>' '<b>bar</b>'",
+      "{numPhotos, plural, =0{no photos} =1{one photo} other{'#' photos}}",
+      "{0, date, ::yyyy.MM.dd G 'at' HH:mm:ss vvvv}",
+      "{0, number, percent}",
+      "{c, plural, offset:-2 =-1 { {text} project} other { {text} projects}}",
+      "'#'",
+      "'aa''b'",
+      "I don't know",
+      "\n    {itemCount, plural,\n        one {item'}'}\n        other {items'}'}\n    }",
+      "You have {itemCount, plural, offset: 2\n        =0 {no items}\n        one {1 item}\n        other {{itemCount} items}\n    }.",
+      "You {count, plural, one {worked for '#' hour} other {worked for '#' hours}} today.",
+      "'{a''b}'",
+      "'}a''b{'",
+      "aaa'{'",
+      "aaa'}'",
+      "This '{isn''t}' obvious",
+      "'<a>",
+      "{floor, selectordinal, =0{ground} one{#st} two{#nd} few{#rd} other{#th}} floor",
+      "My name is { name }",
+      "Your meeting is scheduled for the {dateVal, date} at {timeVal, time}",
+      "a '{a{ {}{}{} ''bb",
+      "You have '{count'",
+      "You have '{count",
+      "You have '{count}",
+      "You have '{count} {thing}",
+      "}",
+    ];
+    for fixture in fixtures {
+      assert!(parse(fixture).is_ok(), "{fixture}");
+    }
+  }
+
+  #[test]
+  fn rejects_formatjs_error_fixtures() {
+    let fixtures = [
+      "You have {count, plural, one {# hot dog} one {# hamburger} other {# snacks}} in your bag.",
+      "My name is { }",
+      "My name is {0, }",
+      "{0, number, }",
+      "My name is {0, foo}",
+      "My name is {0!}",
+      "My name is { 0",
+      "{0, number",
+      "{0, number, percent",
+      "a 'a {}{}",
+    ];
+    for fixture in fixtures {
+      assert!(parse(fixture).is_err(), "{fixture}");
+    }
+  }
+}
@@ -0,0 +1,452 @@
+// Tests use decimals like 3.14 as plain numbers and keep a few redundant assertions
+#![cfg_attr(
+  test,
+  allow(
+    clippy::approx_constant,
+    clippy::assertions_on_constants,
+    clippy::module_inception,
+    clippy::useless_conversion
+  )
+)]
+
+use crate::visitor::TransformVisitor;
+use crate::{
+  config::{PluginConfig, PluginSettings, RuleSeverity},
+  hash::{JsxHasher, SanitizedData},
+  manifest::Manifest,
+  visitor::{
+    analysis::{
+      is_dictionary_translator_callback, is_message_function_name, is_messages_translator_callback,
+      is_module_translation_function_name, is_translation_function_callback,
+    },
+    dataflow::collect_translator_dataflow,
+    errors::{create_dynamic_content_warning, Finding},
+    expr_utils::{create_named_import, is_allowed_dynamic_content},
+    suppression::SuppressionTracker,
+    transform::AUTO_WRAP_VAR_NAME,
+  },
+};
+use swc_core::{
+  common::{comments::Comments, sync::Lrc, SourceFile, Spanned, DUMMY_SP},
+  ecma::{
+    ast::*,
+    visit::{VisitMut, VisitMutWith},
+  },
+};
+
+impl VisitMut for TransformVisitor {
+  /// ES modules can receive the `Var` import auto-wrapped content needs, added after any
+  /// directives so `"use client"` stays first
+  fn visit_mut_module(&mut self, module: &mut Module) {
+    self.traversal_state.in_module = true;
+    self.string_collector.enter_scope();
+    module.visit_mut_children_with(self);
+    self.string_collector.exit_scope(module);
+    if let Some(source) = self.import_tracker.auto_var_import.take() {
+      let local = Ident::new_no_ctxt(AUTO_WRAP_VAR_NAME.into(), DUMMY_SP);
+      let directives = module
+        .body
+        .iter()
+        .take_while(|item| {
+          matches!(item, ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) if expr.is_lit())
+        })
+        .count();
+      module
+        .body
+        .insert(directives, create_named_import("Var", local, &source));
+    }
+  }
+
+  /// Scripts receive the content arrays of their top-level translators once traversed
+  fn visit_mut_script(&mut self, script: &mut Script) {
+    self.string_collector.enter_scope();
+    script.visit_mut_children_with(self);
+    self.string_collector.exit_scope(script);
+  }
+
+  /// JSX children following `{/* gt-ignore */}`
+  fn visit_mut_jsx_element_childs(&mut self, children: &mut Vec<JSXElementChild>) {
+    for (index, child) in children.iter().enumerate() {
+      let JSXElementChild::JSXExprContainer(container) = child else {
+        continue;
+      };
+      if !matches!(container.expr, JSXExpr::JSXEmptyExpr(_)) {
+        continue;
+      }
+      // Skip whitespace-only text between the comment and the suppressed child
+      let next = children[index + 1..].iter().find(|next| match next {
+        JSXElementChild::JSXText(text) => !text.value.trim().is_empty(),
+        _ => true,
+      });
+      if let Some(next) = next {
+        self
+          .suppressions
+          .register_jsx_container(container.span, next.span());
+      }
+    }
+    children.visit_mut_children_with(self);
+  }
+
+  /// Process import declarations to track gt-next imports
+  fn visit_mut_import_decl(&mut self, import_decl: &mut ImportDecl) {
+    self.process_gt_import_declaration(import_decl);
+    import_decl.visit_mut_children_with(self);
+  }
+
+  /// Process variable declarations to track assignments like: const t = useGT()
+  fn visit_mut_var_declarator(&mut self, var_declarator: &mut VarDeclarator) {
+    self.string_collector.record_declarator(var_declarator);
+    self.track_variable_assignment(var_declarator);
+    var_declarator.visit_mut_children_with(self);
+  }
+
+  /// Process variable reassignments like: t = useGT()
+  fn visit_mut_assign_expr(&mut self, assign_expr: &mut AssignExpr) {
+    self.string_collector.record_assignment(assign_expr);
+    self.track_assignment(assign_expr);
+    assign_expr.visit_mut_children_with(self);
+  }
+
+  /// Named functions may be helpers that receive a translator
+  fn visit_mut_fn_decl(&mut self, fn_decl: &mut FnDecl) {
+    self.traversal_state.pending_function = Some(fn_decl.ident.to_id());
+    fn_decl.visit_mut_children_with(self);
+  }
+
+  /// Process function declarations to ensure their bodies are traversed. Translators they
+  /// declare receive their content arrays on the way out.
+  fn visit_mut_function(&mut self, function: &mut Function) {
+    let name = self.traversal_state.pending_function.take();
+    self.track_translator_parameters(
+      name.as_ref(),
+      function.params.iter().map(|param| &param.pat),
+    );
+    self.string_collector.enter_scope();
+    function.visit_mut_children_with(self);
+    self.string_collector.exit_scope(function);
+  }
+
+  /// Process arrow functions to ensure their bodies are traversed
+  fn visit_mut_arrow_expr(&mut self, arrow: &mut ArrowExpr) {
+    let name = self.traversal_state.pending_function.take();
+    self.track_translator_parameters(name.as_ref(), arrow.params.iter());
+    self.string_collector.enter_scope();
+    arrow.visit_mut_children_with(self);
+    self.string_collector.exit_scope(arrow);
+  }
+
+  /// Call expressions: t()
+  fn visit_mut_call_expr(&mut self, call_expr: &mut CallExpr) {
+    if let Some(callee) = self.resolve_call(call_expr) {
+      // Register the useGT/getGT as aggregators on the string collector
+      let function_name = callee.function_name;
+      let original_name = callee.original_name;
+      let identifier = callee.identifier;
+
+      // Rewrite t(`Hello ${name}`) into t("Hello {name}", { name }) before it is checked
+      if self.settings.compile_time_hash
+        && self.settings.auto_icu_templates
+        && (is_translation_function_callback(&original_name)
+          || is_message_function_name(&original_name)
+          || is_module_translation_function_name(&original_name))
+      {
+        self.rewrite_template_message(call_expr);
+      }
+
+      // Detect t() calls
+      if is_translation_function_callback(&original_name) {
+        if let Some(string) = call_expr.args.first() {
+          // Check for violations
+          self.check_call_expr_for_violations(string, &function_name);
+          self.check_message_format(string, call_expr.args.get(1), &function_name);
+
+          // Track the t() function call and inject its hash
+          if self.settings.compile_time_hash {
+            if let Some(hash) = self.track_translation_callback(call_expr, string, identifier) {
+              TransformVisitor::inject_hash_attribute_on_call_expr(call_expr, &hash);
+            }
+          }
+        }
+      }
+      // Detect msg() and gt-i18n t() calls
+      else if is_message_function_name(&original_name)
+        || is_module_translation_function_name(&original_name)
+      {
+        let message_array = call_expr
+          .args
+          .first()
+          .filter(|_| is_message_function_name(&original_name))
+          .and_then(|arg| arg.expr.as_array());
+        // msg(['Hello, Alice!', 'Hello, Bob!']) is hashed entry by entry at runtime
+        if let Some(array) = message_array {
+          self.track_message_array(call_expr, array, &function_name);
+        } else if let Some(string) = call_expr.args.first() {
+          self.check_call_expr_for_violations(string, &function_name);
+          self.check_message_format(string, call_expr.args.get(1), &function_name);
+
+          if self.settings.compile_time_hash {
+            if let Some(hash) = self.track_standalone_call(call_expr, string) {
+              TransformVisitor::inject_hash_attribute_on_call_expr(call_expr, &hash);
+            }
+          }
+        }
+      }
+      // Detect dictionary lookups: t('nav.home')
+      else if is_dictionary_translator_callback(&original_name) {
+        if let Some(key) = call_expr.args.first() {
+          self.track_dictionary_key(key, identifier, &function_name);
+        }
+      }
+      // Detect message decoding: m(encodedMessage)
+      else if is_messages_translator_callback(&original_name) {
+        self.statistics.message_decode_count += 1;
+      }
+    }
+    call_expr.visit_mut_children_with(self);
+  }
+
+  /// Tagged templates from gt-i18n are checked and extracted but not rewritten: the runtime
+  /// looks up the interpolated text before the positional message, which a call would skip
+  fn visit_mut_tagged_tpl(&mut self, tagged_tpl: &mut TaggedTpl) {
+    if let Some(callee) = self.resolve_callee(&tagged_tpl.tag) {
+      if is_module_translation_function_name(&callee.original_name) {
+        self.check_tagged_template_for_violations(&tagged_tpl.tpl, &callee.function_name);
+
+        if self.settings.compile_time_hash {
+          self.track_tagged_template(tagged_tpl);
+        }
+      }
+    }
+    tagged_tpl.visit_mut_children_with(self);
+  }
+
+  /// Process JSX expression containers to detect unwrapped dynamic content
+  fn visit_mut_jsx_expr_container(&mut self, expr_container: &mut JSXExprContainer) {
+    // {/* gt-ignore */ expression}
+    if !matches!(expr_container.expr, JSXExpr::JSXEmptyExpr(_)) {
+      self
+        .suppressions
+        .register_jsx_container(expr_container.span, expr_container.span);
+    }
+    // Only check for violations if we're in a translation component and NOT in a JSX attribute
+    if self.traversal_state.in_translation_component && !self.traversal_state.in_jsx_attribute {
+      // Check if the expression is allowed dynamic content
+      if !self.settings.disable_build_checks
+        && !self.settings.autoderive_jsx
+        && !is_allowed_dynamic_content(&expr_container.expr)
+      {
+        let diagnostic = create_dynamic_content_warning("T", expr_container.expr.span());
+        self.report_diagnostic(diagnostic);
+      }
+    }
+    expr_container.visit_mut_children_with(self);
+  }
+
+  /// Process JSX attributes to track context and avoid flagging attribute expressions
+  fn visit_mut_jsx_attr(&mut self, attr: &mut JSXAttr) {
+    let was_in_jsx_attribute = self.traversal_state.in_jsx_attribute;
+    self.traversal_state.in_jsx_attribute = true;
+    attr.visit_mut_children_with(self);
+    self.traversal_state.in_jsx_attribute = was_in_jsx_attribute;
+  }
+
+  /// Process JSX elements to track component context and inject experimental features
+  fn visit_mut_jsx_element(&mut self, element: &mut JSXElement) {
+    self.statistics.jsx_element_count += 1;
+
+    // Save previous state
+    let was_in_translation = self.traversal_state.in_translation_component;
+    let was_in_variable = self.traversal_state.in_variable_component;
+
+    // Update component tracking state
+    let (is_translation_component, is_variable_component, _) =
+      self.determine_component_type(element);
+    self.traversal_state.in_translation_component = is_translation_component;
+    self.traversal_state.in_variable_component = is_variable_component;
+
+    match self.resolve_component(&element.opening.name).as_deref() {
+      Some("Plural") => self.check_plural_component(element),
+      Some("Branch") => self.check_branch_component(element),
+      _ => {}
+    }
+
+    // Inject hash attributes on translation components, over the auto-wrapped content
+    if self.settings.compile_time_hash
+      && self.traversal_state.in_translation_component
+      && !was_in_translation
+    {
+      if self.settings.auto_wrap_var {
+        self.wrap_dynamic_content(element);
+      }
+      self.inject_hash_attributes(element);
+    }
+
+    // Process children
+    element.visit_mut_children_with(self);
+
+    // Restore previous state
+    self.traversal_state.in_translation_component = was_in_translation;
+    self.traversal_state.in_variable_component = was_in_variable;
+  }
+}
+
+/// Outcome of a transform: the diagnostics it reported and, when collected, the extraction
+/// manifest of the entries it hashed
+#[derive(Debug, Default)]
+pub struct Report {
+  /// Diagnostics after suppressions and rule severities were applied
+  pub findings: Vec<Finding>,
+  /// Hashed entries, collected when `manifestDir` is set or by [`extract`]. Locations are left
+  /// for the caller to resolve against its source map.
+  pub manifest: Option<Manifest>,
+  /// Fully qualified keys passed to dictionary translators, None when the key or namespace is
+  /// not a string literal. Unused dictionary entries are found from the keys of every file.
+  pub dictionary_keys: Vec<Option<String>>,
+  /// Walks over the program, see [`transform_program`]
+  pub traversals: usize,
+}
+
+impl Report {
+  /// Whether any error-level diagnostic was reported, which fails the build
+  pub fn has_errors(&self) -> bool {
+    self
+      .findings
+      .iter()
+      .any(|finding| finding.severity == RuleSeverity::Error)
+  }
+}
+
+/// Run the transform, walking the program in several passes:
+/// 1. The translator dataflow is collected in one read-only walk, which records what local
+///    functions return and receive. Translators passed through nested helpers are followed
+///    over those facts, without walking the program again.
+/// 2. The main pass validates the program and, when compile-time hashing is enabled, injects
+///    hashes in place. useGT()/getGT() calls are patched with their content arrays when the
+///    function or module declaring their translator is left, once its t() calls were seen.
+///    Validation-only mode leaves the program as it was.
+///
+/// The program must have been through SWC's resolver, as it has when SWC runs a plugin: GT
+/// bindings are tracked by the SyntaxContext it gives every binding. Files that are neither
+/// hashed nor checked are returned without any walk. Diagnostics are also emitted through
+/// SWC's error handler when one is set.
+pub fn transform_program(
+  program: Program,
+  config: &PluginConfig,
+  filename: Option<String>,
+) -> (Program, Report) {
+  transform_program_with_comments(program, config, filename, None, None)
+}
+
+/// Run the transform with the program's comments and source file, used to find gt-ignore
+/// suppressions and resolve the lines `gt-ignore-next-line` covers. Both are needed to honor
+/// suppressions.
+pub fn transform_program_with_comments(
+  program: Program,
+  config: &PluginConfig,
+  filename: Option<String>,
+  comments: Option<Box<dyn Comments>>,
+  source_file: Option<Lrc<SourceFile>>,
+) -> (Program, Report) {
+  let collect_manifest = config.manifest_dir.is_some();
+  run_transform(
+    program,
+    config,
+    filename,
+    comments,
+    source_file,
+    collect_manifest,
+  )
+}
+
+pub(crate) fn run_transform(
+  program: Program,
+  config: &PluginConfig,
+  filename: Option<String>,
+  comments: Option<Box<dyn Comments>>,
+  source_file: Option<Lrc<SourceFile>>,
+  collect_manifest: bool,
+) -> (Program, Report) {
+  let manifest = collect_manifest.then(|| Manifest::new(filename.clone()));
+  // Nothing to inject and nothing to check
+  if !config.compile_time_hash && config.disable_build_checks {
+    let report = Report {
+      manifest,
+      ..Default::default()
+    };
+    return (program, report);
+  }
+
+  let (program, visitor, traversals) =
+    run_visitor(program, config, filename, comments, source_file, manifest);
+  let report = Report {
+    findings: visitor.findings,
+    manifest: visitor.manifest,
+    dictionary_keys: visitor
+      .statistics
+      .dictionary_keys
+      .into_iter()
+      .map(|usage| usage.key)
+      .collect(),
+    traversals,
+  };
+  (program, report)
+}
+
+/// Run the visitor over the program, returning the visitor with everything it collected and
+/// the number of walks over the program
+pub(crate) fn run_visitor(
+  program: Program,
+  config: &PluginConfig,
+  filename: Option<String>,
+  comments: Option<Box<dyn Comments>>,
+  source_file: Option<Lrc<SourceFile>>,
+  manifest: Option<Manifest>,
+) -> (Program, TransformVisitor, usize) {
+  // Create StringCollector for the content arrays
+  let string_collector = crate::ast::StringCollector::new();
+
+  let mut program = program;
+  let settings = PluginSettings::from_config(config, filename);
+  let dataflow = collect_translator_dataflow(&program, &settings);
+  // The dataflow walk and the main pass
+  let traversals = 2;
+  let mut visitor = TransformVisitor::with_settings(settings, string_collector);
+  visitor.dataflow = dataflow;
+  visitor.manifest = manifest;
+  if !config.disable_build_checks {
+    visitor.suppressions = SuppressionTracker::new(comments, source_file);
+  }
+  // Violations are collected and emitted as span-accurate diagnostics through SWC's error
+  // handler, which fails the build without aborting the transform
+  program.visit_mut_with(&mut visitor);
+  visitor.report_unused_suppressions();
+  (program, visitor, traversals)
+}
+
+/// Hash sanitized content the way `<T>` and t() hashes are computed: the SHA-256 of its
+/// stable JSON, truncated to 16 hex digits
+pub fn hash_jsx(data: &SanitizedData) -> Option<String> {
+  let json_string = JsxHasher::stable_stringify(data).ok()?;
+  Some(JsxHasher::hash_string(&json_string))
+}
+
+pub mod ast;
+pub mod config;
+pub mod dictionary;
+pub mod hash;
+pub mod icu;
+pub mod logging;
+pub mod manifest;
+pub mod message_format;
+pub mod plurals;
+#[cfg(feature = "parser")]
+pub mod source;
+pub mod visitor;
+pub mod whitespace;
+
+#[cfg(test)]
+mod test_utils;
+
+#[cfg(test)]
+#[path = "lib_tests.rs"]
+mod tests;
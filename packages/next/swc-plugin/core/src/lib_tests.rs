@@ -0,0 +1,295 @@
+use super::*;
+use crate::test_utils::{
+  capture_diagnostics, config, parse, parse_with_source_map, transform_with_diagnostics,
+  ReportedDiagnostic,
+};
+use swc_core::common::GLOBALS;
+use swc_core::ecma::codegen::to_code;
+
+mod validation_only_mode {
+  use super::*;
+
+  #[test]
+  fn leaves_program_untouched_when_hashing_disabled() {
+    let source = r#"
+      import { T, useGT } from 'gt-next';
+      function Page() {
+        const t = useGT();
+        return <T title={t("Greeting")}>Hello world</T>;
+      }
+    "#;
+    let expected = to_code(&parse(source));
+
+    let (output, diagnostics) = transform_with_diagnostics(source, &config(false, false));
+
+    assert_eq!(output, expected);
+    assert!(diagnostics.is_empty());
+  }
+
+  #[test]
+  fn keeps_syntax_contexts_when_hashing_disabled() {
+    let source = r#"
+      import { useGT } from 'gt-next';
+      function Page() {
+        const t = useGT();
+        return t("Greeting");
+      }
+    "#;
+    let (cm, program) = parse_with_source_map(source);
+    let (output, _) = capture_diagnostics(&cm, || {
+      transform_program(program.clone(), &config(false, false), None).0
+    });
+
+    assert!(output == program);
+  }
+
+  #[test]
+  fn reports_jsx_violations_when_hashing_disabled() {
+    let source = r#"
+      import { T } from 'gt-next';
+      const el = <T>Hello {name}</T>;
+    "#;
+    let (_, diagnostics) = transform_with_diagnostics(source, &config(false, false));
+
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].is_error());
+  }
+
+  #[test]
+  fn reports_call_violations_when_hashing_disabled() {
+    let source = r#"
+      import { useGT } from 'gt-next';
+      function Page() {
+        const t = useGT();
+        return t(`Hello ${name}`);
+      }
+    "#;
+    let (_, diagnostics) = transform_with_diagnostics(source, &config(false, false));
+
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].is_error());
+  }
+
+  #[test]
+  fn skips_everything_when_checks_and_hashing_disabled() {
+    let source = r#"
+      import { T } from 'gt-next';
+      const el = <T>Hello {name}</T>;
+    "#;
+    let expected = to_code(&parse(source));
+
+    let (output, diagnostics) = transform_with_diagnostics(source, &config(false, true));
+
+    assert_eq!(output, expected);
+    assert!(diagnostics.is_empty());
+  }
+
+  #[test]
+  fn injects_hashes_when_hashing_enabled() {
+    let source = r#"
+      import { T } from 'gt-next';
+      const el = <T>Hello world</T>;
+    "#;
+
+    let (output, _) = transform_with_diagnostics(source, &config(true, false));
+
+    assert!(output.contains("_hash="));
+  }
+}
+
+mod in_place_injection {
+  use super::*;
+
+  #[test]
+  fn injects_each_translator_its_own_content() {
+    let source = "import { useGT } from 'gt-next';\nfunction A() {\n  const t = useGT();\n  return t('First');\n}\nfunction B() {\n  const t = useGT();\n  return t('Second');\n}\n";
+    let (output, _) = transform_with_diagnostics(source, &config(true, false));
+    let (a, b) = output.split_once("function B").unwrap();
+
+    assert!(a.contains("useGT([") && a.contains("message: \"First\""));
+    assert!(b.contains("useGT([") && b.contains("message: \"Second\""));
+    assert!(!a.contains("Second") && !b.contains("First"));
+  }
+
+  #[test]
+  fn injects_calls_without_real_spans() {
+    // Code synthesized by other transforms shares DUMMY_SP
+    struct DropSpans;
+    impl VisitMut for DropSpans {
+      fn visit_mut_span(&mut self, span: &mut swc_core::common::Span) {
+        *span = DUMMY_SP;
+      }
+    }
+    let source = "import { useGT } from 'gt-next';\nfunction A() {\n  const t = useGT();\n  return t('First');\n}\nfunction B() {\n  const t = useGT();\n  return t('Second');\n}\n";
+    let (cm, mut program) = parse_with_source_map(source);
+    program.visit_mut_with(&mut DropSpans);
+    let (output, _) = capture_diagnostics(&cm, || {
+      transform_program(program, &config(true, false), None).0
+    });
+    let output = to_code(&output);
+    let (a, b) = output.split_once("function B").unwrap();
+
+    assert!(a.contains("useGT([") && a.contains("message: \"First\""));
+    assert!(b.contains("useGT([") && b.contains("message: \"Second\""));
+    assert!(!a.contains("Second") && !b.contains("First"));
+  }
+
+  #[test]
+  fn injects_once_the_declaring_scope_is_left() {
+    let source = "import { useGT } from 'gt-next';\nlet t;\nfunction init() {\n  t = useGT();\n}\nfunction Page() {\n  const t = useGT();\n  return t('Page');\n}\ninit();\nt('Hello');\n";
+    let (output, _) = transform_with_diagnostics(source, &config(true, false));
+    let (init, page) = output.split_once("function Page").unwrap();
+
+    assert!(init.contains("t = useGT([") && init.contains("message: \"Hello\""));
+    assert!(page.contains("useGT([") && page.contains("message: \"Page\""));
+    assert!(!init.contains("message: \"Page\""));
+  }
+
+  #[test]
+  fn spreads_options_with_the_hash_key() {
+    let source = "import { useGT } from 'gt-next';\nconst t = useGT();\nt('Hello', opts);\n";
+    let (output, _) = transform_with_diagnostics(source, &config(true, false));
+
+    assert!(output.contains("...opts,\n    $_hash: \""));
+    assert!(!output.contains("$hash"));
+  }
+
+  #[test]
+  fn leaves_custom_hook_calls_alone() {
+    let source = "import { useGT } from 'gt-next';\nfunction useAppT() {\n  return useGT();\n}\nfunction Page() {\n  const t = useAppT();\n  return t('Hello');\n}\n";
+    let (output, _) = transform_with_diagnostics(source, &config(true, false));
+
+    assert!(output.contains("return useGT();"));
+    assert!(output.contains("t('Hello', {"));
+  }
+}
+
+mod diagnostics {
+  use super::*;
+
+  #[test]
+  fn jsx_violation_points_at_offending_expression() {
+    let source = "import { T } from 'gt-next';\nconst el = <T>Hello {user.name}</T>;\n";
+    let (_, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+    assert_eq!(diagnostics.len(), 1);
+    let diagnostic = &diagnostics[0];
+    assert_eq!(diagnostic.code.as_deref(), Some("GT001"));
+    assert!(diagnostic.message.contains("unwrapped dynamic content"));
+    assert_eq!((diagnostic.line, diagnostic.col), (2, 22));
+  }
+
+  #[test]
+  fn call_violation_points_at_offending_expression() {
+    let source = "import { useGT } from 'gt-next';\nconst t = useGT();\nt('Hi ' + name);\n";
+    let (_, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+    assert_eq!(diagnostics.len(), 1);
+    let diagnostic = &diagnostics[0];
+    assert_eq!(diagnostic.code.as_deref(), Some("GT002"));
+    assert!(diagnostic.message.contains("Found: name"));
+    assert_eq!((diagnostic.line, diagnostic.col), (3, 11));
+  }
+
+  #[test]
+  fn reports_every_violation_without_panicking() {
+    let source = r#"
+      import { T, useGT } from 'gt-next';
+      function Page() {
+        const t = useGT();
+        t(`Hello ${first}`);
+        return <T>{a} and {b}</T>;
+      }
+    "#;
+    let (output, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+    assert_eq!(diagnostics.len(), 3);
+    assert!(diagnostics.iter().all(ReportedDiagnostic::is_error));
+    assert!(output.contains("function Page"));
+  }
+}
+
+mod traversal_count {
+  use super::*;
+
+  fn traversals(source: &str, config: &PluginConfig) -> usize {
+    let (cm, program) = parse_with_source_map(source);
+    let (report, _) = capture_diagnostics(&cm, || transform_program(program, config, None).1);
+    report.traversals
+  }
+
+  #[test]
+  fn skips_files_that_are_neither_hashed_nor_checked() {
+    let source = "import { useGT } from 'gt-next';\nconst t = useGT();\nt('Hello');\n";
+
+    assert_eq!(traversals(source, &config(false, true)), 0);
+  }
+
+  #[test]
+  fn walks_files_without_gt_imports_twice() {
+    // One dataflow walk and the main pass
+    let source = "const greeting = format('Hello');\n";
+
+    assert_eq!(traversals(source, &config(true, false)), 2);
+  }
+
+  #[test]
+  fn injects_content_arrays_in_the_main_pass() {
+    let source = "import { useGT } from 'gt-next';\nconst t = useGT();\nt('Hello');\n";
+
+    assert_eq!(traversals(source, &config(true, false)), 2);
+    assert_eq!(traversals(source, &config(false, false)), 2);
+  }
+
+  #[test]
+  fn follows_nested_helpers_in_one_dataflow_walk() {
+    // One dataflow walk finds label() and describe(), then the main pass
+    let source = "import { useGT } from 'gt-next';\nfunction label(t) {\n  return describe(t);\n}\nconst describe = (translate) => translate('Hi');\nfunction Page() {\n  const t = useGT();\n  return label(t);\n}\n";
+
+    assert_eq!(traversals(source, &config(true, false)), 2);
+  }
+}
+
+mod public_api {
+  use super::*;
+  use crate::hash::{SanitizedChild, SanitizedChildren};
+  use crate::visitor::errors::DiagnosticCode;
+
+  #[test]
+  fn reports_findings_with_their_severity() {
+    let source = "import { T } from 'gt-next';\nconst el = <T>Hello {name}</T>;\n";
+    let program = parse(source);
+    let (_, report) = GLOBALS.set(&Default::default(), || {
+      transform_program(program, &config(false, false), None)
+    });
+
+    let findings: Vec<_> = report
+      .findings
+      .iter()
+      .map(|finding| (finding.diagnostic.code, finding.severity))
+      .collect();
+    assert_eq!(
+      findings,
+      [(DiagnosticCode::DynamicJsxContent, RuleSeverity::Error)]
+    );
+    assert!(report.has_errors());
+    assert!(report.manifest.is_none());
+  }
+
+  #[test]
+  fn hashes_jsx_like_the_transform() {
+    let source = "import { T } from 'gt-next';\nconst el = <T>Hello</T>;\n";
+    let (output, _) = transform_with_diagnostics(source, &config(true, false));
+    let data = SanitizedData {
+      source: Some(Box::new(SanitizedChildren::Single(Box::new(
+        SanitizedChild::Text("Hello".to_string()),
+      )))),
+      context: None,
+      max_chars: None,
+      data_format: Some("JSX".to_string()),
+    };
+
+    let hash = hash_jsx(&data).unwrap();
+    assert!(output.contains(&format!("_hash=\"{hash}\"")));
+  }
+}
@@ -0,0 +1,293 @@
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use serde_json::Value;
+use swc_core::common::{BytePos, Span};
+
+use crate::hash::JsxHasher;
+
+/// Line and column of an entry, both 1-based
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceLocation {
+  pub line: usize,
+  pub column: usize,
+}
+
+/// A translatable entry hashed at compile time: a `<T>` element or a t()/msg() message
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestEntry {
+  /// Hash injected into the output, or the one the runtime computes for `msg([...])` entries
+  pub hash: String,
+  /// Sanitized data the hash was computed over
+  pub source: Value,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub id: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub context: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub max_chars: Option<i64>,
+  /// Data format: JSX, ICU, STRING, ...
+  pub format: String,
+  /// Location of the entry, once resolved against the source map
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub location: Option<SourceLocation>,
+  #[serde(skip)]
+  pub span: Span,
+}
+
+impl ManifestEntry {
+  /// Build an entry from the sanitized JSON its hash was computed over. None when the JSON
+  /// can't be parsed.
+  pub fn new(hash: String, json_string: &str, id: Option<String>, span: Span) -> Option<Self> {
+    let source: Value = serde_json::from_str(json_string).ok()?;
+    let context = source
+      .get("context")
+      .and_then(Value::as_str)
+      .map(str::to_string);
+    let max_chars = source.get("maxChars").and_then(Value::as_i64);
+    let format = source
+      .get("dataFormat")
+      .and_then(Value::as_str)
+      .unwrap_or("ICU")
+      .to_string();
+    Some(Self {
+      hash,
+      source,
+      id,
+      context,
+      max_chars,
+      format,
+      location: None,
+      span,
+    })
+  }
+}
+
+/// Translatable entries of a file, written as JSON for the CLI to consume instead of
+/// re-parsing the code
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct Manifest {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub filename: Option<String>,
+  pub entries: Vec<ManifestEntry>,
+}
+
+impl Manifest {
+  pub fn new(filename: Option<String>) -> Self {
+    Self {
+      filename,
+      entries: Vec::new(),
+    }
+  }
+
+  pub fn record(&mut self, entry: ManifestEntry) {
+    self.entries.push(entry);
+  }
+
+  /// Resolve entry locations with a source map lookup returning 1-based (line, column)
+  pub fn resolve_locations(&mut self, lookup: impl Fn(BytePos) -> (usize, usize)) {
+    for entry in &mut self.entries {
+      if entry.span.is_dummy() {
+        continue;
+      }
+      let (line, column) = lookup(entry.span.lo);
+      entry.location = Some(SourceLocation { line, column });
+    }
+  }
+
+  pub fn to_json(&self) -> serde_json::Result<String> {
+    serde_json::to_string_pretty(self)
+  }
+
+  /// Path of the manifest in `dir`, named after a hash of the filename so every file maps to
+  /// a stable path
+  pub fn path_in(&self, dir: &Path) -> PathBuf {
+    let name = JsxHasher::hash_string(self.filename.as_deref().unwrap_or_default());
+    dir.join(format!("{name}.json"))
+  }
+
+  /// Write the manifest to `dir`. Files without entries are written too, replacing a stale
+  /// manifest.
+  pub fn write(&self, dir: &Path) -> std::io::Result<PathBuf> {
+    let path = self.path_in(dir);
+    std::fs::create_dir_all(dir)?;
+    std::fs::write(&path, self.to_json()?)?;
+    Ok(path)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use swc_core::common::DUMMY_SP;
+
+  #[test]
+  fn reads_options_from_the_sanitized_data() {
+    let json = r#"{"context":"nav","dataFormat":"JSX","maxChars":12,"source":"Home"}"#;
+    let entry =
+      ManifestEntry::new("abc".to_string(), json, Some("home".to_string()), DUMMY_SP).unwrap();
+
+    assert_eq!(entry.context.as_deref(), Some("nav"));
+    assert_eq!(entry.max_chars, Some(12));
+    assert_eq!(entry.format, "JSX");
+    assert_eq!(entry.source["source"], "Home");
+  }
+
+  #[test]
+  fn writes_a_file_per_source_file() {
+    let scratch = tempfile::tempdir().unwrap();
+    let dir = scratch.path().join("manifests");
+    let mut manifest = Manifest::new(Some("app/page.tsx".to_string()));
+    let json = r#"{"dataFormat":"ICU","source":"Hello"}"#;
+    manifest.record(ManifestEntry::new("abc".to_string(), json, None, DUMMY_SP).unwrap());
+
+    let path = manifest.write(&dir).unwrap();
+    let written: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+
+    assert_eq!(path, manifest.path_in(&dir));
+    assert_eq!(written["filename"], "app/page.tsx");
+    assert_eq!(written["entries"][0]["hash"], "abc");
+    assert!(written["entries"][0].get("location").is_none());
+  }
+
+  mod extraction_manifest {
+    use super::*;
+    use crate::config::PluginConfig;
+    use crate::manifest::SourceLocation;
+    use crate::test_utils::{
+      capture_diagnostics, config, hash_of, parse, parse_with_comments, source_file,
+    };
+    use crate::transform_program_with_comments;
+    use swc_core::ecma::codegen::to_code;
+
+    fn extract(source: &str, manifest_dir: Option<&str>) -> (String, Option<Manifest>) {
+      let config = PluginConfig {
+        manifest_dir: manifest_dir.map(str::to_string),
+        ..config(true, false)
+      };
+      let (cm, comments, program) = parse_with_comments(source);
+      let ((output, report), _) = capture_diagnostics(&cm, || {
+        transform_program_with_comments(
+          program,
+          &config,
+          Some("page.tsx".to_string()),
+          Some(Box::new(comments)),
+          Some(source_file(&cm)),
+        )
+      });
+      let manifest = report.manifest.map(|mut manifest| {
+        manifest.resolve_locations(|pos| {
+          let loc = cm.lookup_char_pos(pos);
+          (loc.line, loc.col_display + 1)
+        });
+        manifest
+      });
+      (to_code(&output), manifest)
+    }
+
+    #[test]
+    fn lists_each_entry_of_a_message_array() {
+      let source =
+        "import { msg } from 'gt-next';\nmsg(['Hi', 'Bye'], { $id: 'items', $context: 'nav' });\n";
+      let (_, manifest) = extract(source, Some(".gt"));
+
+      let entries: Vec<_> = manifest
+        .unwrap()
+        .entries
+        .into_iter()
+        .map(|entry| (entry.hash, entry.id))
+        .collect();
+      let hash = |message: &str| {
+        let source =
+          format!("import {{ msg }} from 'gt-next';\nmsg('{message}', {{ $context: 'nav' }});");
+        hash_of(&source)
+      };
+      assert_eq!(
+        entries,
+        [
+          (hash("Hi"), Some("items.0".to_string())),
+          (hash("Bye"), Some("items.1".to_string()))
+        ]
+      );
+    }
+
+    #[test]
+    fn lists_tagged_templates_as_the_runtime_icu_source() {
+      let source = "import { t } from 'gt-i18n';\nt`Hello ${name}`;\n";
+      let (output, manifest) = extract(source, Some(".gt"));
+
+      let entries = manifest.unwrap().entries;
+      assert_eq!(entries.len(), 1);
+      assert_eq!(
+        entries[0].hash,
+        hash_of("import { t } from 'gt-i18n';\nt('Hello {0}');\n")
+      );
+      assert_eq!(output, to_code(&parse(source)));
+    }
+
+    #[test]
+    fn lists_every_hashed_entry() {
+      let source = "import { T, useGT, msg } from 'gt-next';\nconst greeting = msg('Hi', { $context: 'nav' });\nfunction Page() {\n  const t = useGT();\n  return <T id=\"welcome\">Welcome {t('Home', { $id: 'home', $maxChars: 10 })}</T>;\n}\n";
+      let (output, manifest) = extract(source, Some(".gt"));
+      let manifest = manifest.unwrap();
+
+      assert_eq!(manifest.filename.as_deref(), Some("page.tsx"));
+      let entries: Vec<_> = manifest
+        .entries
+        .iter()
+        .map(|entry| (entry.format.as_str(), entry.id.as_deref(), entry.location))
+        .collect();
+      assert_eq!(
+        entries,
+        [
+          (
+            "ICU",
+            None,
+            Some(SourceLocation {
+              line: 2,
+              column: 18
+            })
+          ),
+          (
+            "JSX",
+            Some("welcome"),
+            Some(SourceLocation {
+              line: 5,
+              column: 10
+            })
+          ),
+          (
+            "ICU",
+            Some("home"),
+            Some(SourceLocation {
+              line: 5,
+              column: 35
+            })
+          ),
+        ]
+      );
+      assert_eq!(manifest.entries[0].context.as_deref(), Some("nav"));
+      assert_eq!(manifest.entries[2].max_chars, Some(10));
+      for entry in &manifest.entries {
+        assert!(output.contains(&entry.hash));
+      }
+    }
+
+    #[test]
+    fn skips_entries_left_to_the_cli() {
+      let source = "import { useGT, derive } from 'gt-next';\nconst t = useGT();\nt('Hi', { $context: derive(getContext()) });\n";
+      let (_, manifest) = extract(source, Some(".gt"));
+
+      assert!(manifest.unwrap().entries.is_empty());
+    }
+
+    #[test]
+    fn is_only_collected_when_configured() {
+      let source = "import { T } from 'gt-next';\nconst el = <T>Hello</T>;\n";
+      let (_, manifest) = extract(source, None);
+
+      assert!(manifest.is_none());
+    }
+  }
+}
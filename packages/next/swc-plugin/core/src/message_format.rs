@@ -0,0 +1,345 @@
+use crate::icu;
+
+/// A message format the runtime can interpolate, as named in `$format`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageFormat {
+  Icu,
+  I18next,
+  String,
+}
+
+/// A syntax error at a byte offset in the message
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyntaxError {
+  pub message: String,
+  pub offset: usize,
+}
+
+impl MessageFormat {
+  pub const ALL: [MessageFormat; 3] = [
+    MessageFormat::Icu,
+    MessageFormat::I18next,
+    MessageFormat::String,
+  ];
+
+  /// The format named by a `$format` value. Names are case-sensitive, like the runtime's.
+  pub fn from_name(name: &str) -> Option<Self> {
+    Self::ALL.into_iter().find(|format| format.name() == name)
+  }
+
+  pub fn name(&self) -> &'static str {
+    match self {
+      MessageFormat::Icu => "ICU",
+      MessageFormat::I18next => "I18NEXT",
+      MessageFormat::String => "STRING",
+    }
+  }
+
+  /// How diagnostics refer to the format's syntax
+  pub fn syntax_name(&self) -> &'static str {
+    match self {
+      MessageFormat::Icu => "ICU MessageFormat",
+      MessageFormat::I18next => "i18next interpolation",
+      MessageFormat::String => "plain text",
+    }
+  }
+
+  /// Whether every value a message uses must be one of its placeholders. i18next reads
+  /// options like `count` and `context` without a placeholder.
+  pub fn uses_every_value(&self) -> bool {
+    matches!(self, MessageFormat::Icu)
+  }
+
+  /// Check a message's syntax, returning the names of the values it interpolates with their
+  /// byte offsets. Plain strings interpolate nothing.
+  pub fn placeholders(&self, message: &str) -> Result<Vec<(String, usize)>, SyntaxError> {
+    match self {
+      MessageFormat::Icu => {
+        let parsed = icu::parse(message).map_err(|error| SyntaxError {
+          message: error.message,
+          offset: error.offset,
+        })?;
+        Ok(
+          icu::argument_names(&parsed)
+            .into_iter()
+            .map(|(name, offset)| (name.to_string(), offset))
+            .collect(),
+        )
+      }
+      MessageFormat::I18next => i18next_placeholders(message),
+      MessageFormat::String => Ok(Vec::new()),
+    }
+  }
+}
+
+/// `{{name}}`, `{{- html}}` and `{{price, currency}}` interpolations of an i18next message,
+/// named after the option they read: `{{user.name}}` reads `user`. `$t()` nesting is left to
+/// the runtime.
+fn i18next_placeholders(message: &str) -> Result<Vec<(String, usize)>, SyntaxError> {
+  let mut placeholders = Vec::new();
+  let mut rest = 0;
+  while let Some(found) = message[rest..].find("{{") {
+    let open = rest + found;
+    let start = open + "{{".len();
+    let unclosed = || SyntaxError {
+      message: "unclosed '{{'".to_string(),
+      offset: open,
+    };
+    let end = start + message[start..].find("}}").ok_or_else(unclosed)?;
+    let inner = &message[start..end];
+    if inner.contains("{{") {
+      return Err(unclosed());
+    }
+    let path = inner.split(',').next().unwrap_or_default();
+    let path = path.trim_start().trim_start_matches('-').trim();
+    if path.is_empty() {
+      return Err(SyntaxError {
+        message: "empty interpolation: expected a name".to_string(),
+        offset: open,
+      });
+    }
+    let offset = start + inner.find(path).unwrap_or_default();
+    let name = path.split('.').next().unwrap_or(path);
+    placeholders.push((name.to_string(), offset));
+    rest = end + "}}".len();
+  }
+  Ok(placeholders)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn reads_runtime_format_names() {
+    assert_eq!(
+      MessageFormat::from_name("I18NEXT"),
+      Some(MessageFormat::I18next)
+    );
+    assert_eq!(MessageFormat::from_name("icu"), None);
+    assert_eq!(MessageFormat::from_name("MARKDOWN"), None);
+  }
+
+  #[test]
+  fn lists_i18next_interpolations() {
+    let placeholders = MessageFormat::I18next
+      .placeholders("Hi {{user.name}}, {{- bio}} costs {{ price, currency }}")
+      .unwrap();
+
+    assert_eq!(
+      placeholders,
+      vec![
+        ("user".to_string(), 5),
+        ("bio".to_string(), 22),
+        ("price".to_string(), 37)
+      ]
+    );
+  }
+
+  #[test]
+  fn reports_i18next_syntax_errors() {
+    let unclosed = MessageFormat::I18next
+      .placeholders("Hi {{name} and {{other}}")
+      .unwrap_err();
+    let empty = MessageFormat::I18next.placeholders("Hi {{ }}").unwrap_err();
+
+    assert_eq!(
+      (unclosed.message.as_str(), unclosed.offset),
+      ("unclosed '{{'", 3)
+    );
+    assert_eq!(empty.message, "empty interpolation: expected a name");
+    assert_eq!(empty.offset, 3);
+  }
+
+  #[test]
+  fn leaves_plain_strings_alone() {
+    assert_eq!(
+      MessageFormat::String.placeholders("Hi {name"),
+      Ok(Vec::new())
+    );
+  }
+
+  mod message_syntax {
+    use crate::config::PluginConfig;
+    use crate::test_utils::{config, transform_with_diagnostics, ReportedDiagnostic};
+
+    fn syntax_diagnostics(source: &str, config: &PluginConfig) -> Vec<ReportedDiagnostic> {
+      diagnostics_with_code(source, config, "GT009")
+    }
+
+    fn diagnostics_with_code(
+      source: &str,
+      config: &PluginConfig,
+      code: &str,
+    ) -> Vec<ReportedDiagnostic> {
+      let (_, diagnostics) = transform_with_diagnostics(source, config);
+      diagnostics
+        .into_iter()
+        .filter(|diagnostic| diagnostic.code.as_deref() == Some(code))
+        .collect()
+    }
+
+    #[test]
+    fn points_at_the_offending_character() {
+      let source = "import { useGT } from 'gt-next';\nconst t = useGT();\nt('Hello {name');\n";
+      let diagnostics = syntax_diagnostics(source, &config(false, false));
+
+      assert_eq!(diagnostics.len(), 1);
+      assert!(diagnostics[0].is_error());
+      assert!(diagnostics[0].message.contains("unclosed '{'"));
+      assert_eq!((diagnostics[0].line, diagnostics[0].col), (3, 10));
+    }
+
+    #[test]
+    fn maps_offsets_through_escape_sequences() {
+      let source = "import { useGT } from 'gt-next';\nconst t = useGT();\n\
+        t(\"Caf\\u00e9 {count, plural, one {#}}\");\n";
+      let diagnostics = syntax_diagnostics(source, &config(false, false));
+
+      assert_eq!(diagnostics.len(), 1);
+      assert!(diagnostics[0].message.contains("missing 'other' option"));
+      assert_eq!((diagnostics[0].line, diagnostics[0].col), (3, 14));
+    }
+
+    #[test]
+    fn checks_msg_template_literals() {
+      let source = "import { msg } from 'gt-next';\nmsg(`{n, plural, ones {#} other {#}}`);\n";
+      let diagnostics = syntax_diagnostics(source, &config(true, false));
+
+      assert_eq!(diagnostics.len(), 1);
+      assert!(diagnostics[0]
+        .message
+        .contains("'ones' is not a plural category"));
+      assert_eq!((diagnostics[0].line, diagnostics[0].col), (2, 18));
+    }
+
+    #[test]
+    fn accepts_valid_messages() {
+      let source = r#"
+        import { useGT } from 'gt-next';
+        const t = useGT();
+        t("It''s {count, plural, =0 {empty} one {# item} other {# items}}, don't worry");
+        t("Total: {amount, number, ::currency/USD}", { amount });
+      "#;
+
+      assert!(syntax_diagnostics(source, &config(true, false)).is_empty());
+    }
+
+    #[test]
+    fn warns_on_placeholders_without_values() {
+      let source = "import { useGT } from 'gt-next';\nconst t = useGT();\n\
+        t('Hello {name}, you owe {amount, number} ({name})', { name });\n";
+      let diagnostics = diagnostics_with_code(source, &config(false, false), "GT010");
+
+      assert_eq!(diagnostics.len(), 1);
+      assert!(!diagnostics[0].is_error());
+      assert!(diagnostics[0].message.contains("no \"amount\" value"));
+      assert_eq!((diagnostics[0].line, diagnostics[0].col), (3, 27));
+    }
+
+    #[test]
+    fn warns_on_values_the_message_never_uses() {
+      let source = "import { msg } from 'gt-next';\n\
+        msg('Hello {name}', { name, $context: 'greeting', count: 3 });\n";
+      let diagnostics = diagnostics_with_code(source, &config(true, false), "GT011");
+
+      assert_eq!(diagnostics.len(), 1);
+      assert!(diagnostics[0].message.contains("passes \"count\""));
+      assert_eq!((diagnostics[0].line, diagnostics[0].col), (2, 51));
+    }
+
+    #[test]
+    fn matches_placeholders_in_plural_options() {
+      let source = r#"
+        import { useGT } from 'gt-next';
+        const t = useGT();
+        t("{count, plural, one {{user} has # item} other {{user} has # items}}", { count, user });
+      "#;
+      let (_, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+      assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn skips_placeholder_checks_when_options_are_spread() {
+      let source = r#"
+        import { useGT } from 'gt-next';
+        const t = useGT();
+        t("Hello {name}", { ...values, extra });
+        t("Hello {name}", values);
+      "#;
+      let (_, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+      assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn skips_messages_in_other_formats() {
+      let source = r#"
+        import { useGT } from 'gt-next';
+        const t = useGT();
+        t("Hello {{name}", { $format: "STRING" });
+      "#;
+
+      assert!(syntax_diagnostics(source, &config(true, false)).is_empty());
+    }
+
+    #[test]
+    fn injects_the_format_into_the_content_array() {
+      let source = r#"
+        import { useGT } from 'gt-next';
+        function Page() {
+          const t = useGT();
+          return t("Hello {{name}}", { $format: "I18NEXT", name });
+        }
+      "#;
+      let (output, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+      assert!(diagnostics.is_empty());
+      let content = &output[output.find("useGT([").unwrap()..];
+      assert!(content.contains("$format: \"I18NEXT\""), "{output}");
+    }
+
+    #[test]
+    fn reports_unsupported_formats() {
+      let source = "import { msg } from 'gt-next';\n\
+        msg('Hello', { $format: 'MARKDOWN' });\nmsg('Hello', { $format: format });\n";
+      let diagnostics = diagnostics_with_code(source, &config(false, false), "GT012");
+
+      assert_eq!(diagnostics.len(), 2);
+      assert!(diagnostics[0].is_error());
+      assert!(diagnostics[0]
+        .message
+        .contains("\"MARKDOWN\", which is not supported"));
+      assert_eq!((diagnostics[0].line, diagnostics[0].col), (2, 25));
+      assert!(diagnostics[1].message.contains("must be a string literal"));
+    }
+
+    #[test]
+    fn checks_i18next_syntax() {
+      let source = "import { useGT } from 'gt-next';\nconst t = useGT();\n\
+        t('Hi {{name}', { $format: 'I18NEXT', name });\n";
+      let diagnostics = syntax_diagnostics(source, &config(false, false));
+
+      assert_eq!(diagnostics.len(), 1);
+      assert!(diagnostics[0]
+        .message
+        .contains("not valid i18next interpolation: unclosed '{{'"));
+      assert_eq!((diagnostics[0].line, diagnostics[0].col), (3, 7));
+    }
+
+    #[test]
+    fn checks_i18next_placeholders_without_flagging_extra_options() {
+      let source = r#"
+        import { useGT } from 'gt-next';
+        const t = useGT();
+        t("{{user.name}} has {{total}} items", { $format: "I18NEXT", user, count });
+      "#;
+      let (_, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+      assert_eq!(diagnostics.len(), 1);
+      assert_eq!(diagnostics[0].code.as_deref(), Some("GT010"));
+      assert!(diagnostics[0].message.contains("\"total\""));
+    }
+  }
+}
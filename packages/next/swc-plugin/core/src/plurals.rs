@@ -0,0 +1,302 @@
+//! CLDR cardinal plural categories, bundled so `<Plural>` branches can be checked against the
+//! source locale at build time, and the runtime's branch selection they are checked with
+
+use crate::icu::PLURAL_CATEGORIES;
+
+/// Cardinal categories by language, from CLDR 48
+const CARDINAL_CATEGORIES: &[(&[&str], &[&str])] = &[
+  (
+    &["other"],
+    &[
+      "bm", "bo", "dz", "hnj", "id", "ig", "ii", "ja", "jbo", "jv", "kde", "kea", "km", "ko",
+      "lkt", "lo", "ms", "my", "nqo", "osa", "sah", "ses", "sg", "su", "th", "to", "tpi", "vi",
+      "wo", "yo", "yue", "zh",
+    ],
+  ),
+  (
+    &["one", "other"],
+    &[
+      "af", "ak", "am", "an", "as", "ast", "az", "bal", "bem", "bez", "bg", "bho", "bn", "brx",
+      "ce", "ceb", "cgg", "chr", "ckb", "csw", "da", "de", "doi", "dv", "ee", "el", "en", "eo",
+      "et", "eu", "fa", "ff", "fi", "fil", "fo", "fur", "fy", "gl", "gsw", "gu", "guw", "ha",
+      "haw", "hi", "hu", "hy", "ia", "ie", "io", "is", "jgo", "jmc", "ka", "kab", "kaj", "kcg",
+      "kk", "kkj", "kl", "kn", "kok", "ks", "ksb", "ku", "ky", "lb", "lg", "lij", "ln", "mas",
+      "mg", "mgo", "mk", "ml", "mn", "mr", "nah", "nb", "nd", "ne", "nl", "nn", "nnh", "no", "nr",
+      "nso", "ny", "nyn", "om", "or", "os", "pa", "pap", "pcm", "ps", "rm", "rof", "rwk", "saq",
+      "sc", "sd", "sdh", "seh", "si", "sn", "so", "sq", "ss", "ssy", "st", "sv", "sw", "syr", "ta",
+      "te", "teo", "ti", "tig", "tk", "tn", "tr", "ts", "tzm", "ug", "ur", "uz", "ve", "vo", "vun",
+      "wa", "wae", "xh", "xog", "yi", "zu",
+    ],
+  ),
+  (&["one", "few", "other"], &["bs", "hr", "ro", "shi", "sr"]),
+  (
+    &["one", "many", "other"],
+    &["ca", "es", "fr", "it", "lld", "pt", "scn", "vec"],
+  ),
+  (
+    &["zero", "one", "other"],
+    &["blo", "cv", "ksh", "lag", "lv", "prg"],
+  ),
+  (
+    &["one", "two", "other"],
+    &["he", "iu", "sat", "se", "sma", "smi", "smj", "smn", "sms"],
+  ),
+  (
+    &["one", "few", "many", "other"],
+    &["be", "cs", "lt", "pl", "ru", "sk", "uk"],
+  ),
+  (&["one", "two", "few", "other"], &["dsb", "gd", "hsb", "sl"]),
+  (
+    &["one", "two", "few", "many", "other"],
+    &["br", "ga", "gv", "mt", "sgs"],
+  ),
+  (
+    &["zero", "one", "two", "few", "many", "other"],
+    &["ar", "cy", "kw"],
+  ),
+];
+
+/// Deprecated language codes `Intl` still accepts, mapped to their replacement
+const LANGUAGE_ALIASES: &[(&str, &str)] = &[
+  ("in", "id"),
+  ("iw", "he"),
+  ("ji", "yi"),
+  ("jw", "jv"),
+  ("mo", "ro"),
+  ("sh", "sr"),
+  ("tl", "fil"),
+];
+
+/// Cardinal plural categories of a locale's language, in CLDR order. None when the language has
+/// no bundled data.
+pub fn cardinal_categories(locale: &str) -> Option<&'static [&'static str]> {
+  let language = locale.split(['-', '_']).next()?.to_ascii_lowercase();
+  let language = LANGUAGE_ALIASES
+    .iter()
+    .find(|(alias, _)| *alias == language)
+    .map_or(language.as_str(), |(_, replacement)| replacement);
+  CARDINAL_CATEGORIES
+    .iter()
+    .find(|(_, languages)| languages.contains(&language))
+    .map(|(categories, _)| *categories)
+}
+
+/// The branch `<Plural>` renders, mirroring the runtime's `getPluralForm`: `zero`,
+/// `singular`/`one` and `dual`/`two` win for counts of exactly 0, 1 and 2, `singular` and `dual`
+/// stand in for `one` and `two`, and `plural` then `other` catch the rest. `count` is None for
+/// any count but 0, 1 and 2.
+fn select_branch<'a>(count: Option<u32>, category: &str, branches: &[&'a str]) -> Option<&'a str> {
+  let find = |name: &str| branches.iter().copied().find(|branch| *branch == name);
+  let exact = match count {
+    Some(0) => find("zero"),
+    Some(1) => find("singular").or_else(|| find("one")),
+    Some(2) => find("dual").or_else(|| find("two")),
+    _ => None,
+  };
+  exact
+    .or_else(|| match category {
+      "one" => find("singular"),
+      "two" => find("dual"),
+      _ => None,
+    })
+    .or_else(|| find(category))
+    .or_else(|| find("plural"))
+    .or_else(|| find("other"))
+}
+
+/// Branches no count selects in a locale with the given categories. A count may fall in any of
+/// the categories, so only branches that can never be selected are returned.
+pub fn unreachable_branches<'a>(branches: &[&'a str], categories: &[&str]) -> Vec<&'a str> {
+  let mut reachable = Vec::new();
+  for count in [Some(0), Some(1), Some(2), None] {
+    for category in categories {
+      reachable.extend(select_branch(count, category, branches));
+    }
+  }
+  branches
+    .iter()
+    .copied()
+    .filter(|branch| !reachable.contains(branch))
+    .collect()
+}
+
+/// Categories of a locale, other than `other`, that have no branch of their own and fall back
+/// to the catch-all. `singular` and `dual` cover `one` and `two`.
+pub fn missing_categories(branches: &[&str], categories: &[&'static str]) -> Vec<&'static str> {
+  categories
+    .iter()
+    .copied()
+    .filter(|category| *category != "other")
+    .filter(|category| {
+      let alias = match *category {
+        "one" => "singular",
+        "two" => "dual",
+        _ => *category,
+      };
+      !branches.contains(category) && !branches.contains(&alias)
+    })
+    .collect()
+}
+
+/// Whether a branch name is a CLDR category rather than one of the `singular`, `dual` and
+/// `plural` aliases
+pub fn is_cldr_category(branch: &str) -> bool {
+  PLURAL_CATEGORIES.contains(&branch)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn looks_up_categories_by_language() {
+    assert_eq!(cardinal_categories("en-US"), Some(&["one", "other"][..]));
+    assert_eq!(
+      cardinal_categories("pt_BR"),
+      Some(&["one", "many", "other"][..])
+    );
+    assert_eq!(cardinal_categories("iw"), cardinal_categories("he"));
+    assert_eq!(cardinal_categories("zh-Hant").map(<[_]>::len), Some(1));
+    assert_eq!(cardinal_categories("xx"), None);
+  }
+
+  #[test]
+  fn finds_categories_without_a_branch() {
+    let polish = cardinal_categories("pl").unwrap();
+
+    assert_eq!(
+      missing_categories(&["one", "other"], polish),
+      vec!["few", "many"]
+    );
+    assert!(missing_categories(&["singular", "few", "many", "plural"], polish).is_empty());
+  }
+
+  #[test]
+  fn finds_branches_no_count_selects() {
+    let english = cardinal_categories("en").unwrap();
+
+    assert_eq!(
+      unreachable_branches(&["one", "few", "other"], english),
+      vec!["few"]
+    );
+    assert_eq!(
+      unreachable_branches(&["singular", "one", "other"], english),
+      vec!["one"]
+    );
+    assert_eq!(
+      unreachable_branches(&["one", "other", "plural"], english),
+      vec!["plural"]
+    );
+    // Exact counts always select these, whatever the locale
+    assert!(unreachable_branches(&["zero", "dual", "other"], english).is_empty());
+  }
+
+  #[test]
+  fn keeps_the_catch_all_for_uncovered_categories() {
+    let russian = cardinal_categories("ru").unwrap();
+
+    assert!(unreachable_branches(&["one", "few", "plural"], russian).is_empty());
+    assert_eq!(
+      unreachable_branches(&["two", "dual", "other"], PLURAL_CATEGORIES),
+      vec!["two"]
+    );
+  }
+
+  mod plural_components {
+    use crate::config::PluginConfig;
+    use crate::test_utils::{config, transform_with_diagnostics, ReportedDiagnostic};
+
+    fn plural_diagnostics(source: &str, locales: &[&str]) -> Vec<ReportedDiagnostic> {
+      let config = PluginConfig {
+        locales: locales.iter().map(|locale| locale.to_string()).collect(),
+        ..config(false, false)
+      };
+      let (_, diagnostics) = transform_with_diagnostics(source, &config);
+      diagnostics
+        .into_iter()
+        .filter(|diagnostic| {
+          matches!(
+            diagnostic.code.as_deref(),
+            Some("GT013" | "GT014" | "GT015")
+          )
+        })
+        .collect()
+    }
+
+    fn codes(diagnostics: &[ReportedDiagnostic]) -> Vec<&str> {
+      diagnostics
+        .iter()
+        .filter_map(|diagnostic| diagnostic.code.as_deref())
+        .collect()
+    }
+
+    #[test]
+    fn accepts_complete_plurals() {
+      let source = "import { Plural } from 'gt-next';\n\
+        export const a = <Plural n={count} one=\"item\" other=\"items\" />;\n\
+        export const b = <Plural n={count} singular=\"item\">items</Plural>;\n";
+
+      assert!(plural_diagnostics(source, &["en-US", "fr"]).is_empty());
+    }
+
+    #[test]
+    fn reports_missing_count() {
+      let source = "import { Plural } from 'gt-next';\n\
+        export const el = <Plural one=\"item\" other=\"items\" />;\n";
+      let diagnostics = plural_diagnostics(source, &[]);
+
+      assert_eq!(codes(&diagnostics), vec!["GT013"]);
+      assert!(!diagnostics[0].is_error());
+      assert_eq!((diagnostics[0].line, diagnostics[0].col), (2, 20));
+    }
+
+    #[test]
+    fn reports_missing_catch_all() {
+      let source = "import { Plural } from 'gt-next';\n\
+        export const el = <Plural n={count} one=\"item\" />;\n";
+      let diagnostics = plural_diagnostics(source, &[]);
+
+      assert_eq!(codes(&diagnostics), vec!["GT014"]);
+      assert!(diagnostics[0].message.contains("no \"other\" branch"));
+    }
+
+    #[test]
+    fn reports_categories_the_source_locale_needs() {
+      let source = "import { Plural as P } from 'gt-next';\n\
+        export const el = <P n={count} one=\"plik\" other=\"pliki\" />;\n";
+      let diagnostics = plural_diagnostics(source, &["pl", "en"]);
+
+      assert_eq!(codes(&diagnostics), vec!["GT014", "GT014"]);
+      assert!(diagnostics[0].message.contains("no \"few\" branch"));
+      assert!(diagnostics[1].message.contains("no \"many\" branch"));
+      // Only the source locale's categories are required
+      assert!(plural_diagnostics(source, &["en", "pl"]).is_empty());
+    }
+
+    #[test]
+    fn reports_unreachable_branches() {
+      let source = "import * as GT from 'gt-next';\n\
+        export const el = <GT.Plural n={count} singular=\"item\" one=\"item\" few=\"items\"\n\
+        other=\"items\" />;\n";
+      let diagnostics = plural_diagnostics(source, &["en"]);
+
+      assert_eq!(codes(&diagnostics), vec!["GT015", "GT015"]);
+      let shadowed = "\"one\" is never rendered: \"singular\" takes precedence";
+      assert!(diagnostics[0].message.contains(shadowed));
+      assert!(diagnostics[1]
+        .message
+        .contains("en has no \"few\" plural category"));
+      assert_eq!((diagnostics[1].line, diagnostics[1].col), (2, 67));
+      // Without locales a count may fall in any category
+      assert_eq!(codes(&plural_diagnostics(source, &[])), vec!["GT015"]);
+    }
+
+    #[test]
+    fn skips_plurals_with_spread_props() {
+      let source = "import { Plural } from 'gt-next';\n\
+        export const el = <Plural {...props} one=\"item\" />;\n";
+
+      assert!(plural_diagnostics(source, &["en"]).is_empty());
+    }
+  }
+}
@@ -0,0 +1,135 @@
+use swc_core::common::{
+  comments::SingleThreadedComments, sync::Lrc, FileName, Globals, Mark, SourceFile, SourceMap,
+  GLOBALS,
+};
+use swc_core::ecma::{
+  ast::Program,
+  parser::{lexer::Lexer, Parser, StringInput, Syntax, TsSyntax},
+  transforms::base::resolver,
+  visit::VisitMutWith,
+};
+
+use crate::config::PluginConfig;
+use crate::logging::LogLevel;
+use crate::manifest::Manifest;
+use crate::Report;
+
+/// A parsed source file with the source map and comments the transform needs
+pub struct ParsedSource {
+  pub source_map: Lrc<SourceMap>,
+  pub source_file: Lrc<SourceFile>,
+  pub comments: SingleThreadedComments,
+  pub program: Program,
+}
+
+/// Parse a JS/TS source file, with JSX enabled. `.ts` files are parsed without JSX so type
+/// assertions like `<T>value` keep working.
+pub fn parse_source(source: &str, filename: &str) -> Result<ParsedSource, String> {
+  let source_map: Lrc<SourceMap> = Default::default();
+  let comments = SingleThreadedComments::default();
+  let file = source_map.new_source_file(
+    Lrc::new(FileName::Real(filename.into())),
+    source.to_string(),
+  );
+  let lexer = Lexer::new(
+    Syntax::Typescript(TsSyntax {
+      tsx: !filename.ends_with(".ts"),
+      ..Default::default()
+    }),
+    Default::default(),
+    StringInput::from(&*file),
+    Some(&comments),
+  );
+  let program = Parser::new_from(lexer)
+    .parse_program()
+    .map_err(|error| format!("failed to parse {filename}: {}", error.kind().msg()))?;
+  Ok(ParsedSource {
+    source_map,
+    source_file: file,
+    comments,
+    program,
+  })
+}
+
+/// Parse and transform a source file with `GLOBALS` set, returning the report and the source
+/// map its spans point into
+pub fn transform_source(
+  source: &str,
+  filename: &str,
+  config: &PluginConfig,
+) -> Result<(Report, Lrc<SourceMap>), String> {
+  let parsed = parse_source(source, filename)?;
+  let (_, report) = GLOBALS.set(&Globals::new(), || {
+    // SWC resolves a program before running plugins, the transform relies on it
+    let mut program = parsed.program;
+    program.visit_mut_with(&mut resolver(Mark::new(), Mark::new(), true));
+    crate::run_transform(
+      program,
+      config,
+      Some(filename.to_string()),
+      Some(Box::new(parsed.comments)),
+      Some(parsed.source_file),
+      true,
+    )
+  });
+  Ok((report, parsed.source_map))
+}
+
+/// Extract the translatable entries of a source file without going through a build, with
+/// locations resolved
+pub fn extract(source: &str, filename: &str) -> Result<Manifest, String> {
+  extract_with_config(source, filename, &PluginConfig::default())
+}
+
+/// [`extract`] with plugin options, e.g. extra import sources. Hashing is always enabled and
+/// build checks are skipped.
+pub fn extract_with_config(
+  source: &str,
+  filename: &str,
+  config: &PluginConfig,
+) -> Result<Manifest, String> {
+  let config = PluginConfig {
+    log_level: LogLevel::Silent,
+    compile_time_hash: true,
+    disable_build_checks: true,
+    ..config.clone()
+  };
+  let (report, source_map) = transform_source(source, filename, &config)?;
+  let mut manifest = report.manifest.unwrap_or_default();
+  manifest.resolve_locations(|pos| {
+    let loc = source_map.lookup_char_pos(pos);
+    (loc.line, loc.col_display + 1)
+  });
+  Ok(manifest)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn extracts_entries_with_locations() {
+    let source = "import { T } from 'gt-next';\nexport const el = <T>Hello</T>;\n";
+    let manifest = extract(source, "page.tsx").unwrap();
+
+    assert_eq!(manifest.filename.as_deref(), Some("page.tsx"));
+    assert_eq!(manifest.entries.len(), 1);
+    assert_eq!(manifest.entries[0].format, "JSX");
+    assert_eq!(
+      manifest.entries[0].location.map(|location| location.line),
+      Some(2)
+    );
+  }
+
+  #[test]
+  fn reports_parse_errors() {
+    let error = extract("const = ;", "broken.tsx").err().unwrap();
+
+    assert!(error.starts_with("failed to parse broken.tsx"));
+  }
+
+  #[test]
+  fn parses_type_assertions_in_ts_files() {
+    assert!(parse_source("const value = <string>input;", "cast.ts").is_ok());
+  }
+}
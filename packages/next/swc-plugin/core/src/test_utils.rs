@@ -0,0 +1,151 @@
+use std::sync::{Arc, Mutex};
+
+use swc_core::common::{
+  comments::{Comments, SingleThreadedComments},
+  errors::{DiagnosticBuilder, DiagnosticId, Emitter, Handler, Level, HANDLER},
+  sync::Lrc,
+  FileName, Globals, Mark, SourceFile, SourceMap, GLOBALS,
+};
+use swc_core::ecma::ast::Program;
+use swc_core::ecma::parser::{lexer::Lexer, Parser, StringInput, Syntax, TsSyntax};
+use swc_core::ecma::{codegen::to_code, transforms::base::resolver, visit::VisitMutWith};
+
+use crate::config::PluginConfig;
+use crate::logging::LogLevel;
+use crate::transform_program_with_comments;
+
+/// A diagnostic captured from SWC's error handler, resolved to a source position
+#[derive(Debug, Clone)]
+pub struct ReportedDiagnostic {
+  pub level: Level,
+  pub code: Option<String>,
+  pub message: String,
+  pub line: usize,
+  pub col: usize,
+}
+
+impl ReportedDiagnostic {
+  pub fn is_error(&self) -> bool {
+    matches!(self.level, Level::Error)
+  }
+}
+
+/// Parse a TSX source string into a program
+pub fn parse(source: &str) -> Program {
+  parse_with_source_map(source).1
+}
+
+/// Parse a TSX source string, returning the source map used for position lookups
+pub fn parse_with_source_map(source: &str) -> (Lrc<SourceMap>, Program) {
+  let (cm, _, program) = parse_with_comments(source);
+  (cm, program)
+}
+
+/// Parse a TSX source string, also collecting its comments. The program is resolved, as SWC
+/// does before running plugins.
+pub fn parse_with_comments(source: &str) -> (Lrc<SourceMap>, SingleThreadedComments, Program) {
+  let cm: Lrc<SourceMap> = Default::default();
+  let comments = SingleThreadedComments::default();
+  let fm = cm.new_source_file(Lrc::new(FileName::Anon), source.to_string());
+  let lexer = Lexer::new(
+    Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    Default::default(),
+    StringInput::from(&*fm),
+    Some(&comments),
+  );
+  let mut program = Parser::new_from(lexer)
+    .parse_program()
+    .expect("failed to parse test source");
+  GLOBALS.set(&Globals::new(), || {
+    program.visit_mut_with(&mut resolver(Mark::new(), Mark::new(), true));
+  });
+  (cm, comments, program)
+}
+
+/// The parsed file, which suppression comments are found in
+pub fn source_file(cm: &Lrc<SourceMap>) -> Lrc<SourceFile> {
+  cm.files()[0].clone()
+}
+
+struct CaptureEmitter(Arc<Mutex<Vec<swc_core::common::errors::Diagnostic>>>);
+
+impl Emitter for CaptureEmitter {
+  fn emit(&mut self, db: &mut DiagnosticBuilder<'_>) {
+    self.0.lock().unwrap().push((**db).clone());
+  }
+}
+
+/// Run an operation with SWC's error handler set, returning everything it reported
+pub fn capture_diagnostics<T>(
+  cm: &Lrc<SourceMap>,
+  operation: impl FnOnce() -> T,
+) -> (T, Vec<ReportedDiagnostic>) {
+  let captured = Arc::new(Mutex::new(Vec::new()));
+  let handler = Handler::with_emitter(true, false, Box::new(CaptureEmitter(captured.clone())));
+  let result = GLOBALS.set(&Globals::new(), || HANDLER.set(&handler, operation));
+
+  let diagnostics = captured
+    .lock()
+    .unwrap()
+    .iter()
+    .map(|diagnostic| {
+      let loc = diagnostic
+        .span
+        .primary_span()
+        .map(|span| cm.lookup_char_pos(span.lo));
+      ReportedDiagnostic {
+        level: diagnostic.level,
+        code: diagnostic.code.as_ref().map(|code| match code {
+          DiagnosticId::Error(code) | DiagnosticId::Lint(code) => code.clone(),
+        }),
+        message: diagnostic.message(),
+        line: loc.as_ref().map(|loc| loc.line).unwrap_or(0),
+        col: loc.as_ref().map(|loc| loc.col_display + 1).unwrap_or(0),
+      }
+    })
+    .collect();
+  (result, diagnostics)
+}
+
+/// Plugin options with logging silenced
+pub fn config(compile_time_hash: bool, disable_build_checks: bool) -> PluginConfig {
+  PluginConfig {
+    log_level: LogLevel::Silent,
+    compile_time_hash,
+    disable_build_checks,
+    ..Default::default()
+  }
+}
+
+/// Run the transform with SWC's error handler set, returning the output code and diagnostics
+pub fn transform_with_diagnostics(
+  source: &str,
+  config: &PluginConfig,
+) -> (String, Vec<ReportedDiagnostic>) {
+  let (cm, comments, program) = parse_with_comments(source);
+  let file = source_file(&cm);
+  let (output, diagnostics) = capture_diagnostics(&cm, || {
+    let comments = Some(Box::new(comments) as Box<dyn Comments>);
+    transform_program_with_comments(program, config, None, comments, Some(file)).0
+  });
+  (to_code(&output), diagnostics)
+}
+
+/// The first hash injected into the output, from call options or a `_hash` JSX prop
+pub fn injected_hash(output: &str) -> &str {
+  let start = ["$_hash: \"", "_hash=\""]
+    .iter()
+    .find_map(|prefix| output.find(prefix).map(|index| index + prefix.len()))
+    .expect("missing hash");
+  let end = start + output[start..].find('"').expect("unterminated hash");
+  &output[start..end]
+}
+
+/// Transform a source and return the first hash it injects
+pub fn hash_of(source: &str) -> String {
+  let (output, _) = transform_with_diagnostics(source, &config(true, false));
+  injected_hash(&output).to_string()
+}
@@ -7,7 +7,10 @@ pub fn is_translation_component_name(name: &Atom) -> bool {
 
 /// Check if a component name matches known gt-next variable components
 pub fn is_variable_component_name(name: &Atom) -> bool {
-  matches!(name.as_ref(), "Var" | "Num" | "Currency" | "DateTime" | "RelativeTime" | "Derive")
+  matches!(
+    name.as_ref(),
+    "Var" | "Num" | "Currency" | "DateTime" | "RelativeTime" | "Derive"
+  )
 }
 
 /// Check if a name is a GT branch
@@ -25,11 +28,52 @@ pub fn is_translation_function_callback(name: &Atom) -> bool {
   matches!(name.as_ref(), "useGT_callback" | "getGT_callback")
 }
 
+/// Check if a name is a GT dictionary function: useTranslations, getTranslations
+pub fn is_dictionary_function_name(name: &Atom) -> bool {
+  matches!(name.as_ref(), "useTranslations" | "getTranslations")
+}
+
+/// Check if a name is a GT message decoding function: useMessages, getMessages
+pub fn is_messages_function_name(name: &Atom) -> bool {
+  matches!(name.as_ref(), "useMessages" | "getMessages")
+}
+
+/// Check if its a dictionary translator callback const t = useTranslations()
+pub fn is_dictionary_translator_callback(name: &Atom) -> bool {
+  matches!(
+    name.as_ref(),
+    "useTranslations_callback" | "getTranslations_callback"
+  )
+}
+
+/// Check if its a message decoding callback const m = useMessages()
+pub fn is_messages_translator_callback(name: &Atom) -> bool {
+  matches!(
+    name.as_ref(),
+    "useMessages_callback" | "getMessages_callback"
+  )
+}
+
+/// Check if a name is gt-i18n's module-level translation function: t() and t``
+pub fn is_module_translation_function_name(name: &Atom) -> bool {
+  matches!(name.as_ref(), "t")
+}
+
+/// Check if a name is the deferred message function: msg()
+pub fn is_message_function_name(name: &Atom) -> bool {
+  matches!(name.as_ref(), "msg")
+}
+
 /// Check if a name is derive
 pub fn is_derive_name(name: &Atom) -> bool {
   matches!(name.as_ref(), "derive")
 }
 
+/// Check if a name is declareVar
+pub fn is_declare_var_name(name: &Atom) -> bool {
+  matches!(name.as_ref(), "declareVar")
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -68,7 +112,14 @@ mod tests {
 
     #[test]
     fn recognizes_all_variable_components() {
-      let valid_components = ["Var", "Num", "Currency", "DateTime", "RelativeTime", "Derive"];
+      let valid_components = [
+        "Var",
+        "Num",
+        "Currency",
+        "DateTime",
+        "RelativeTime",
+        "Derive",
+      ];
       for component in &valid_components {
         let name = Atom::new(*component);
         assert!(
@@ -181,13 +232,75 @@ mod tests {
     }
   }
 
+  mod dictionary_functions {
+    use super::*;
+
+    #[test]
+    fn recognizes_dictionary_and_messages_hooks() {
+      assert!(is_dictionary_function_name(&Atom::new("useTranslations")));
+      assert!(is_dictionary_function_name(&Atom::new("getTranslations")));
+      assert!(is_messages_function_name(&Atom::new("useMessages")));
+      assert!(is_messages_function_name(&Atom::new("getMessages")));
+      assert!(!is_dictionary_function_name(&Atom::new("useGT")));
+      assert!(!is_messages_function_name(&Atom::new("useTranslations")));
+    }
+
+    #[test]
+    fn recognizes_callbacks() {
+      assert!(is_dictionary_translator_callback(&Atom::new(
+        "useTranslations_callback"
+      )));
+      assert!(is_messages_translator_callback(&Atom::new(
+        "getMessages_callback"
+      )));
+      assert!(!is_dictionary_translator_callback(&Atom::new(
+        "useGT_callback"
+      )));
+      assert!(!is_messages_translator_callback(&Atom::new("useMessages")));
+    }
+  }
+
+  mod is_message_function_name {
+    use super::*;
+
+    #[test]
+    fn recognizes_msg() {
+      assert!(is_message_function_name(&Atom::new("msg")));
+    }
+
+    #[test]
+    fn rejects_other_names() {
+      for function in ["t", "message", "Msg", "useGT", "msgs"] {
+        assert!(
+          !is_message_function_name(&Atom::new(function)),
+          "Should not recognize '{function}' as message function"
+        );
+      }
+    }
+  }
+
   mod comprehensive_validation {
     use super::*;
 
     #[test]
     fn no_overlap_between_categories() {
       let all_names = [
-        "T", "Var", "Num", "Currency", "Derive", "DateTime", "RelativeTime", "Branch", "Plural", "useGT", "getGT",
+        "T",
+        "Var",
+        "Num",
+        "Currency",
+        "Derive",
+        "DateTime",
+        "RelativeTime",
+        "Branch",
+        "Plural",
+        "useGT",
+        "getGT",
+        "msg",
+        "useTranslations",
+        "getTranslations",
+        "useMessages",
+        "getMessages",
       ];
 
       for name_str in &all_names {
@@ -196,9 +309,20 @@ mod tests {
         let is_variable = is_variable_component_name(&name);
         let is_branch = is_branch_name(&name);
         let is_function = is_translation_function_name(&name);
+        let is_message = is_message_function_name(&name);
+        let is_dictionary = is_dictionary_function_name(&name);
+        let is_messages = is_messages_function_name(&name);
 
         // Each name should only match one category
-        let matches = [is_translation, is_variable, is_branch, is_function];
+        let matches = [
+          is_translation,
+          is_variable,
+          is_branch,
+          is_function,
+          is_message,
+          is_dictionary,
+          is_messages,
+        ];
         let match_count = matches.iter().filter(|&&x| x).count();
 
         assert_eq!(
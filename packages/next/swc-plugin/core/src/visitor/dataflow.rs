@@ -0,0 +1,463 @@
+use std::collections::{HashMap, HashSet};
+
+use swc_core::ecma::{
+  ast::{
+    ArrowExpr, AssignExpr, AssignOp, AssignTarget, AwaitExpr, BindingIdent, BlockStmtOrExpr,
+    CallExpr, Callee, Expr, FnDecl, Function, Id, ImportDecl, Pat, Program, ReturnStmt,
+    SimpleAssignTarget, VarDeclarator,
+  },
+  atoms::Atom,
+  visit::{Visit, VisitWith},
+};
+
+use crate::ast::StringCollector;
+use crate::config::PluginSettings;
+use crate::logging::LogLevel;
+use crate::visitor::analysis::is_translation_function_name;
+use crate::visitor::TransformVisitor;
+
+/// How translators flow through the functions of a module
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TranslatorDataflow {
+  /// Local custom hooks that return a translator, mapped to the GT function it comes from:
+  /// `function useAppT() { return useGT() }` -> useGT
+  pub hooks: HashMap<Id, Atom>,
+  /// Parameters of local helpers that are called with a translator, keyed by the helper's
+  /// binding: `function greet(t) {}` called as `greet(t)` -> { 0: useGT_callback }
+  pub helper_parameters: HashMap<Id, HashMap<usize, Atom>>,
+}
+
+impl TranslatorDataflow {
+  /// GT function whose translator a value holds, once the facts resolved so far say so
+  fn translator(&self, value: &TranslatorValue) -> Option<Atom> {
+    match value {
+      TranslatorValue::Translator(function_name) => Some(function_name.clone()),
+      TranslatorValue::Returned(function) => self.hooks.get(function).cloned(),
+      TranslatorValue::Parameter(function, index) => self
+        .helper_parameters
+        .get(function)?
+        .get(index)?
+        .strip_suffix("_callback")
+        .map(Atom::from),
+    }
+  }
+}
+
+/// A value that may hold a translator, depending on facts about other functions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TranslatorValue {
+  /// The translator of a GT function: `useGT()`, `await getGT()`
+  Translator(Atom),
+  /// What a local function returns: `useAppT()`
+  Returned(Id),
+  /// What a parameter of a local function receives: `t` in `function label(t) {}`
+  Parameter(Id, usize),
+}
+
+/// Facts recorded by the collector, keyed by function binding so functions synthesized by
+/// other transforms (which share `DUMMY_SP`) are kept apart
+#[derive(Debug, Default)]
+pub struct DataflowFacts {
+  /// Named local functions
+  functions: HashSet<Id>,
+  /// Functions being traversed, innermost last. None for anonymous functions.
+  function_stack: Vec<Option<Id>>,
+  /// Bindings that may hold a translator: `const t = useAppT()`, `function label(t) {}`
+  values: HashMap<Id, TranslatorValue>,
+  /// Values returned by local functions, in source order
+  returns: Vec<(Id, TranslatorValue)>,
+  /// Values passed to local functions: (function, argument index, value)
+  arguments: Vec<(Id, usize, TranslatorValue)>,
+}
+
+impl DataflowFacts {
+  /// Enter a function: `function greet() {}`, `const greet = () => {}` or an anonymous one.
+  /// The parameters of a named function are bound to what its callers pass.
+  pub fn enter_function<'a>(&mut self, name: Option<Id>, params: impl Iterator<Item = &'a Pat>) {
+    if let Some(name) = &name {
+      self.functions.insert(name.clone());
+      for (index, param) in params.enumerate() {
+        if let Pat::Ident(BindingIdent { id, .. }) = param {
+          self
+            .values
+            .insert(id.to_id(), TranslatorValue::Parameter(name.clone(), index));
+        }
+      }
+    }
+    self.function_stack.push(name);
+  }
+
+  pub fn exit_function(&mut self) {
+    self.function_stack.pop();
+  }
+
+  /// Bind a variable to what it is initialized or assigned with. Anything else drops it.
+  pub fn bind(&mut self, id: Id, value: Option<TranslatorValue>) {
+    match value {
+      Some(value) => self.values.insert(id, value),
+      None => self.values.remove(&id),
+    };
+  }
+
+  /// Value a binding holds, if it may be a translator
+  pub fn value(&self, id: &Id) -> Option<&TranslatorValue> {
+    self.values.get(id)
+  }
+
+  /// Record a value the current function returns
+  pub fn record_return(&mut self, value: TranslatorValue) {
+    if let Some(Some(name)) = self.function_stack.last() {
+      self.returns.push((name.clone(), value));
+    }
+  }
+
+  /// Record a value passed as an argument to a local function
+  pub fn record_argument(&mut self, function: Id, index: usize, value: TranslatorValue) {
+    self.arguments.push((function, index, value));
+  }
+
+  /// Resolve the recorded facts into hooks and helper parameters. A hook or helper found in
+  /// one round can hand its translator to further functions in the next, so rounds repeat
+  /// until nothing new is found. The first value that resolves wins.
+  pub fn resolve(self) -> TranslatorDataflow {
+    let mut dataflow = TranslatorDataflow::default();
+    loop {
+      let mut changed = false;
+      for (function, value) in &self.returns {
+        if dataflow.hooks.contains_key(function) {
+          continue;
+        }
+        if let Some(function_name) = dataflow.translator(value) {
+          dataflow.hooks.insert(function.clone(), function_name);
+          changed = true;
+        }
+      }
+      for (function, index, value) in &self.arguments {
+        let resolved = dataflow
+          .helper_parameters
+          .get(function)
+          .is_some_and(|parameters| parameters.contains_key(index));
+        if resolved || !self.functions.contains(function) {
+          continue;
+        }
+        if let Some(function_name) = dataflow.translator(value) {
+          dataflow
+            .helper_parameters
+            .entry(function.clone())
+            .or_default()
+            .insert(*index, format!("{function_name}_callback").into());
+          changed = true;
+        }
+      }
+      if !changed {
+        return dataflow;
+      }
+    }
+  }
+}
+
+/// Read-only walk that only follows GT bindings, functions, returns and calls. It reuses the
+/// transform's import tracking, without its checks, hashing or JSX handling.
+struct DataflowCollector {
+  visitor: TransformVisitor,
+  facts: DataflowFacts,
+}
+
+impl DataflowCollector {
+  /// Value an expression evaluates to, if it may be a translator: `useGT()`, `await getGT()`,
+  /// `useAppT()`, a translator `t` or a helper's parameter
+  fn value_of(&self, expr: &Expr) -> Option<TranslatorValue> {
+    match expr {
+      Expr::Await(AwaitExpr { arg, .. }) => self.value_of(arg),
+      Expr::Paren(paren_expr) => self.value_of(&paren_expr.expr),
+      Expr::Call(CallExpr {
+        callee: Callee::Expr(callee_expr),
+        ..
+      }) => match self.visitor.resolve_callee(callee_expr) {
+        Some(callee) => is_translation_function_name(&callee.original_name)
+          .then_some(TranslatorValue::Translator(callee.original_name)),
+        None => match callee_expr.as_ref() {
+          Expr::Ident(function) => Some(TranslatorValue::Returned(function.to_id())),
+          _ => None,
+        },
+      },
+      Expr::Ident(ident) => self.facts.value(&ident.to_id()).cloned(),
+      _ => None,
+    }
+  }
+
+  fn enter_function<'a>(&mut self, params: impl Iterator<Item = &'a Pat>) {
+    let name = self.visitor.traversal_state.pending_function.take();
+    self.facts.enter_function(name, params);
+  }
+}
+
+impl Visit for DataflowCollector {
+  fn visit_import_decl(&mut self, import_decl: &ImportDecl) {
+    self.visitor.process_gt_import_declaration(import_decl);
+  }
+
+  fn visit_var_declarator(&mut self, var_declarator: &VarDeclarator) {
+    // Tracks requires and aliases of GT functions: const useT = useGT
+    self.visitor.track_variable_assignment(var_declarator);
+    if let Pat::Ident(BindingIdent { id, .. }) = &var_declarator.name {
+      let value = var_declarator
+        .init
+        .as_ref()
+        .and_then(|init| self.value_of(init));
+      self.facts.bind(id.to_id(), value);
+    }
+    var_declarator.visit_children_with(self);
+  }
+
+  fn visit_assign_expr(&mut self, assign_expr: &AssignExpr) {
+    self.visitor.track_assignment(assign_expr);
+    if let (
+      AssignOp::Assign,
+      AssignTarget::Simple(SimpleAssignTarget::Ident(BindingIdent { id, .. })),
+    ) = (assign_expr.op, &assign_expr.left)
+    {
+      let value = self.value_of(&assign_expr.right);
+      self.facts.bind(id.to_id(), value);
+    }
+    assign_expr.visit_children_with(self);
+  }
+
+  fn visit_fn_decl(&mut self, fn_decl: &FnDecl) {
+    self.visitor.traversal_state.pending_function = Some(fn_decl.ident.to_id());
+    fn_decl.visit_children_with(self);
+  }
+
+  fn visit_function(&mut self, function: &Function) {
+    self.enter_function(function.params.iter().map(|param| &param.pat));
+    function.visit_children_with(self);
+    self.facts.exit_function();
+  }
+
+  fn visit_arrow_expr(&mut self, arrow: &ArrowExpr) {
+    self.enter_function(arrow.params.iter());
+    // const useAppT = () => useGT()
+    if let BlockStmtOrExpr::Expr(body) = arrow.body.as_ref() {
+      if let Some(value) = self.value_of(body) {
+        self.facts.record_return(value);
+      }
+    }
+    arrow.visit_children_with(self);
+    self.facts.exit_function();
+  }
+
+  fn visit_return_stmt(&mut self, return_stmt: &ReturnStmt) {
+    if let Some(value) = return_stmt.arg.as_ref().and_then(|arg| self.value_of(arg)) {
+      self.facts.record_return(value);
+    }
+    return_stmt.visit_children_with(self);
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    // Translators passed to a local function: greet(t)
+    if let Callee::Expr(callee_expr) = &call_expr.callee {
+      if let Expr::Ident(function) = callee_expr.as_ref() {
+        for (index, arg) in call_expr.args.iter().enumerate() {
+          if let Some(value) = self.value_of(&arg.expr) {
+            self.facts.record_argument(function.to_id(), index, value);
+          }
+        }
+      }
+    }
+    call_expr.visit_children_with(self);
+  }
+}
+
+/// Collect the translator dataflow of a module: one read-only walk records what functions
+/// return and receive, then the facts are resolved without walking the program again
+pub fn collect_translator_dataflow(
+  program: &Program,
+  settings: &PluginSettings,
+) -> TranslatorDataflow {
+  let mut settings = settings.clone();
+  settings.log_level = LogLevel::Silent;
+  settings.compile_time_hash = false;
+  settings.disable_build_checks = true;
+
+  let mut collector = DataflowCollector {
+    visitor: TransformVisitor::with_settings(settings, StringCollector::new()),
+    facts: DataflowFacts::default(),
+  };
+  program.visit_with(&mut collector);
+  collector.facts.resolve()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use swc_core::common::SyntaxContext;
+
+  fn id(name: &str) -> Id {
+    (Atom::new(name), SyntaxContext::empty())
+  }
+
+  fn use_gt() -> TranslatorValue {
+    TranslatorValue::Translator(Atom::new("useGT"))
+  }
+
+  #[test]
+  fn resolves_hooks_and_helper_parameters() {
+    let mut facts = DataflowFacts::default();
+    facts.enter_function(Some(id("useAppT")), std::iter::empty());
+    facts.record_return(use_gt());
+    facts.exit_function();
+    facts.enter_function(Some(id("greet")), std::iter::empty());
+    facts.exit_function();
+    facts.record_argument(id("greet"), 1, use_gt());
+    facts.record_argument(id("unknown"), 0, use_gt());
+
+    let dataflow = facts.resolve();
+    assert_eq!(
+      dataflow.hooks.get(&id("useAppT")),
+      Some(&Atom::new("useGT"))
+    );
+    assert_eq!(dataflow.helper_parameters.len(), 1);
+    assert_eq!(
+      dataflow.helper_parameters[&id("greet")].get(&1),
+      Some(&Atom::new("useGT_callback"))
+    );
+  }
+
+  #[test]
+  fn ignores_returns_outside_named_functions() {
+    let mut facts = DataflowFacts::default();
+    facts.record_return(use_gt());
+    facts.enter_function(None, std::iter::empty());
+    facts.record_return(use_gt());
+    facts.exit_function();
+
+    assert!(facts.resolve().hooks.is_empty());
+  }
+
+  #[test]
+  fn follows_translators_through_nested_functions() {
+    // function label(t) { return describe(t) }, describe(translate) and a hook wrapping a hook,
+    // recorded before the facts they depend on
+    let mut facts = DataflowFacts::default();
+    facts.enter_function(Some(id("label")), std::iter::empty());
+    facts.record_argument(
+      id("describe"),
+      0,
+      TranslatorValue::Parameter(id("label"), 0),
+    );
+    facts.record_return(TranslatorValue::Returned(id("useAppT")));
+    facts.exit_function();
+    facts.enter_function(Some(id("describe")), std::iter::empty());
+    facts.exit_function();
+    facts.enter_function(Some(id("useAppT")), std::iter::empty());
+    facts.record_return(use_gt());
+    facts.exit_function();
+    facts.record_argument(id("label"), 0, use_gt());
+
+    let dataflow = facts.resolve();
+    assert_eq!(dataflow.hooks.get(&id("label")), Some(&Atom::new("useGT")));
+    assert_eq!(
+      dataflow.helper_parameters[&id("describe")].get(&0),
+      Some(&Atom::new("useGT_callback"))
+    );
+  }
+
+  #[test]
+  fn keeps_functions_with_the_same_span_apart() {
+    // Functions synthesized by other transforms all have DUMMY_SP
+    let mut facts = DataflowFacts::default();
+    facts.enter_function(Some(id("useAppT")), std::iter::empty());
+    facts.record_return(use_gt());
+    facts.exit_function();
+    facts.enter_function(Some(id("format")), std::iter::empty());
+    facts.exit_function();
+    facts.record_argument(id("format"), 0, use_gt());
+
+    let dataflow = facts.resolve();
+    assert_eq!(dataflow.hooks.len(), 1);
+    assert!(dataflow.hooks.contains_key(&id("useAppT")));
+    assert_eq!(dataflow.helper_parameters.len(), 1);
+    assert!(dataflow.helper_parameters.contains_key(&id("format")));
+  }
+
+  mod translator_dataflow {
+    use crate::run_visitor;
+    use crate::test_utils::{
+      capture_diagnostics, config, parse_with_source_map, transform_with_diagnostics,
+    };
+
+    fn codes(source: &str) -> Vec<Option<String>> {
+      let (_, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+      diagnostics
+        .into_iter()
+        .map(|diagnostic| diagnostic.code)
+        .collect()
+    }
+
+    #[test]
+    fn follows_aliases() {
+      let source = "import { useGT } from 'gt-next';\nconst t = useGT();\nconst translate = t;\ntranslate('Hello');\ntranslate('Hi ' + name);\n";
+      let (output, _) = transform_with_diagnostics(source, &config(true, false));
+
+      assert_eq!(codes(source), vec![Some("GT002".to_string())]);
+      assert!(output.contains("message: \"Hello\""));
+      assert!(output.contains("translate('Hello', {"));
+    }
+
+    #[test]
+    fn follows_later_assignments() {
+      let source =
+        "import { useGT } from 'gt-next';\nlet t;\nif (ready) {\n  t = useGT();\n}\nt('Hello');\n";
+      let (output, _) = transform_with_diagnostics(source, &config(true, false));
+
+      assert!(output.contains("t = useGT(["));
+      assert!(output.contains("t('Hello', {"));
+    }
+
+    #[test]
+    fn reassignment_stops_tracking() {
+      let source =
+        "import { useGT } from 'gt-next';\nlet t = useGT();\nt = format;\nt('Hi ' + name);\n";
+
+      assert!(codes(source).is_empty());
+    }
+
+    #[test]
+    fn follows_custom_hooks() {
+      let source = "import { useGT } from 'gt-next';\nfunction Page() {\n  const t = useAppT();\n  return t('Hi ' + name);\n}\nfunction useAppT() {\n  return useGT();\n}\nconst useOtherT = () => {\n  const t = useGT();\n  return t;\n};\nconst u = useOtherT();\nu('Hello');\n";
+      let (output, _) = transform_with_diagnostics(source, &config(true, false));
+
+      assert_eq!(codes(source), vec![Some("GT002".to_string())]);
+      assert!(output.contains("u('Hello', {"));
+    }
+
+    #[test]
+    fn follows_helper_parameters() {
+      let source = "import { useGT } from 'gt-next';\nfunction label(t, count) {\n  return t('Items') + describe(t);\n}\nconst describe = (translate) => translate('Hi ' + name);\nfunction Page() {\n  const t = useGT();\n  return label(t, 1);\n}\n";
+      let (output, _) = transform_with_diagnostics(source, &config(true, false));
+
+      assert_eq!(codes(source), vec![Some("GT002".to_string())]);
+      assert!(output.contains("t('Items', {"));
+    }
+
+    #[test]
+    fn helper_parameters_share_no_aggregator() {
+      let source = "import { useGT } from 'gt-next';\nfunction label(t) {\n  return t('Items');\n}\nfunction Page() {\n  const t = useGT();\n  return label(t) + t('Hello');\n}\n";
+      let (cm, program) = parse_with_source_map(source);
+      let ((_, visitor, _), _) = capture_diagnostics(&cm, || {
+        run_visitor(program, &config(true, false), None, None, None, None)
+      });
+
+      // Only Page's useGT() has a content array
+      assert_eq!(visitor.string_collector.get_counter(), 1);
+      assert_eq!(visitor.string_collector.total_content_items(), 1);
+    }
+
+    #[test]
+    fn leaves_unrelated_helpers_alone() {
+      let source = "import { useGT } from 'gt-next';\nfunction label(t) {\n  return t('Hi ' + name);\n}\nlabel(format);\n";
+
+      assert!(codes(source).is_empty());
+    }
+  }
+}
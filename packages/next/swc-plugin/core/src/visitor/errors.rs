@@ -0,0 +1,443 @@
+use swc_core::common::{
+  errors::{DiagnosticId, HANDLER},
+  Span,
+};
+
+use crate::config::RuleSeverity;
+use crate::message_format::MessageFormat;
+
+/// Stable error codes for the plugin's build checks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiagnosticCode {
+  /// GT001: unwrapped dynamic content inside a <T> component
+  DynamicJsxContent,
+  /// GT002: translation function argument is not a string literal or derive() call
+  DynamicFunctionArgument,
+  /// GT003: derive() call with the wrong shape
+  InvalidDerive,
+  /// GT004: suppression comment that did not suppress anything
+  UnusedSuppression,
+  /// GT005: dictionary translator key is not a string literal
+  DynamicDictionaryKey,
+  /// GT006: dictionary key does not exist in the dictionary
+  MissingDictionaryKey,
+  /// GT007: dictionary key resolves to a namespace instead of an entry
+  DictionaryNamespaceKey,
+  /// GT008: dictionary entry is never used. Only the CLI reports it, after seeing every file.
+  UnusedDictionaryEntry,
+  /// GT009: t() or msg() message is not valid in its format's syntax
+  InvalidMessageSyntax,
+  /// GT010: ICU placeholder has no matching value in the options
+  MissingIcuVariable,
+  /// GT011: option value is not referenced by the ICU message
+  UnusedIcuVariable,
+  /// GT012: $format is not a format the runtime supports
+  UnsupportedFormat,
+  /// GT013: <Plural> has no `n` prop to select a branch with
+  MissingPluralCount,
+  /// GT014: <Plural> has no branch for a count the source locale distinguishes
+  MissingPluralBranch,
+  /// GT015: <Plural> branch that no count selects
+  UnreachablePluralBranch,
+  /// GT016: <Branch> has no `branch` prop to select a branch with
+  MissingBranchProp,
+  /// GT017: literal `branch` value that names none of the <Branch>'s branches
+  UnmatchedBranchKey,
+  /// GT018: <Branch> branch whose content renders nothing
+  EmptyBranch,
+  /// GT019: <Branch> children that never render, since a literal `branch` always matches
+  IgnoredBranchChildren,
+}
+
+impl DiagnosticCode {
+  pub const ALL: [DiagnosticCode; 19] = [
+    DiagnosticCode::DynamicJsxContent,
+    DiagnosticCode::DynamicFunctionArgument,
+    DiagnosticCode::InvalidDerive,
+    DiagnosticCode::UnusedSuppression,
+    DiagnosticCode::DynamicDictionaryKey,
+    DiagnosticCode::MissingDictionaryKey,
+    DiagnosticCode::DictionaryNamespaceKey,
+    DiagnosticCode::UnusedDictionaryEntry,
+    DiagnosticCode::InvalidMessageSyntax,
+    DiagnosticCode::MissingIcuVariable,
+    DiagnosticCode::UnusedIcuVariable,
+    DiagnosticCode::UnsupportedFormat,
+    DiagnosticCode::MissingPluralCount,
+    DiagnosticCode::MissingPluralBranch,
+    DiagnosticCode::UnreachablePluralBranch,
+    DiagnosticCode::MissingBranchProp,
+    DiagnosticCode::UnmatchedBranchKey,
+    DiagnosticCode::EmptyBranch,
+    DiagnosticCode::IgnoredBranchChildren,
+  ];
+
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      DiagnosticCode::DynamicJsxContent => "GT001",
+      DiagnosticCode::DynamicFunctionArgument => "GT002",
+      DiagnosticCode::InvalidDerive => "GT003",
+      DiagnosticCode::UnusedSuppression => "GT004",
+      DiagnosticCode::DynamicDictionaryKey => "GT005",
+      DiagnosticCode::MissingDictionaryKey => "GT006",
+      DiagnosticCode::DictionaryNamespaceKey => "GT007",
+      DiagnosticCode::UnusedDictionaryEntry => "GT008",
+      DiagnosticCode::InvalidMessageSyntax => "GT009",
+      DiagnosticCode::MissingIcuVariable => "GT010",
+      DiagnosticCode::UnusedIcuVariable => "GT011",
+      DiagnosticCode::UnsupportedFormat => "GT012",
+      DiagnosticCode::MissingPluralCount => "GT013",
+      DiagnosticCode::MissingPluralBranch => "GT014",
+      DiagnosticCode::UnreachablePluralBranch => "GT015",
+      DiagnosticCode::MissingBranchProp => "GT016",
+      DiagnosticCode::UnmatchedBranchKey => "GT017",
+      DiagnosticCode::EmptyBranch => "GT018",
+      DiagnosticCode::IgnoredBranchChildren => "GT019",
+    }
+  }
+
+  /// Name of the rule that controls this diagnostic in the `rules` plugin option
+  pub fn rule_name(&self) -> &'static str {
+    match self {
+      DiagnosticCode::DynamicJsxContent => "jsx-dynamic-content",
+      DiagnosticCode::DynamicFunctionArgument => "t-dynamic-argument",
+      DiagnosticCode::InvalidDerive => "derive-shape",
+      DiagnosticCode::UnusedSuppression => "unused-suppression",
+      DiagnosticCode::DynamicDictionaryKey => "dictionary-dynamic-key",
+      DiagnosticCode::MissingDictionaryKey => "dictionary-missing-key",
+      DiagnosticCode::DictionaryNamespaceKey => "dictionary-namespace-key",
+      DiagnosticCode::UnusedDictionaryEntry => "dictionary-unused-entry",
+      DiagnosticCode::InvalidMessageSyntax => "message-syntax",
+      DiagnosticCode::MissingIcuVariable => "icu-missing-variable",
+      DiagnosticCode::UnusedIcuVariable => "icu-unused-variable",
+      DiagnosticCode::UnsupportedFormat => "unsupported-format",
+      DiagnosticCode::MissingPluralCount => "plural-missing-n",
+      DiagnosticCode::MissingPluralBranch => "plural-missing-branch",
+      DiagnosticCode::UnreachablePluralBranch => "plural-unreachable-branch",
+      DiagnosticCode::MissingBranchProp => "branch-missing-prop",
+      DiagnosticCode::UnmatchedBranchKey => "branch-unmatched-key",
+      DiagnosticCode::EmptyBranch => "branch-empty",
+      DiagnosticCode::IgnoredBranchChildren => "branch-ignored-children",
+    }
+  }
+
+  /// Severity used when the rule is not configured
+  pub fn default_severity(&self) -> RuleSeverity {
+    match self {
+      // Dynamic keys still resolve at runtime, they just can't be checked at build time
+      DiagnosticCode::UnusedSuppression
+      | DiagnosticCode::DynamicDictionaryKey
+      | DiagnosticCode::UnusedDictionaryEntry
+      | DiagnosticCode::MissingIcuVariable
+      | DiagnosticCode::UnusedIcuVariable
+      | DiagnosticCode::MissingPluralCount
+      | DiagnosticCode::MissingPluralBranch
+      | DiagnosticCode::UnreachablePluralBranch
+      | DiagnosticCode::MissingBranchProp
+      | DiagnosticCode::UnmatchedBranchKey
+      | DiagnosticCode::EmptyBranch
+      | DiagnosticCode::IgnoredBranchChildren => RuleSeverity::Warn,
+      _ => RuleSeverity::Error,
+    }
+  }
+}
+
+/// A build-time diagnostic pointing at the offending expression
+#[derive(Debug, Clone)]
+pub struct GtDiagnostic {
+  /// Stable error code
+  pub code: DiagnosticCode,
+  /// Human readable message (without the gt-next prefix)
+  pub message: String,
+  /// Span of the offending expression
+  pub span: Span,
+}
+
+impl GtDiagnostic {
+  pub fn new(code: DiagnosticCode, message: String, span: Span) -> Self {
+    Self {
+      code,
+      message,
+      span,
+    }
+  }
+
+  /// Plain-text rendering, used when no SWC error handler is available
+  pub fn to_log_message(&self, filename: Option<&str>) -> String {
+    match filename {
+      Some(filename) => format!(
+        "gt-next in {filename}: [{}] {}",
+        self.code.as_str(),
+        self.message
+      ),
+      None => format!("gt-next: [{}] {}", self.code.as_str(), self.message),
+    }
+  }
+
+  /// Emit the diagnostic through SWC's error handler so the host can render a code frame.
+  /// Only error-level diagnostics fail the build.
+  /// Returns false when no handler is set (e.g. outside of a plugin run).
+  pub fn emit(&self, severity: RuleSeverity) -> bool {
+    if !HANDLER.is_set() {
+      return false;
+    }
+    let message = format!("gt-next: {}", self.message);
+    HANDLER.with(|handler| match severity {
+      RuleSeverity::Error => handler
+        .struct_span_err_with_code(
+          self.span,
+          &message,
+          DiagnosticId::Error(self.code.as_str().to_string()),
+        )
+        .emit(),
+      RuleSeverity::Warn => handler
+        .struct_span_warn_with_code(
+          self.span,
+          &message,
+          DiagnosticId::Lint(self.code.as_str().to_string()),
+        )
+        .emit(),
+      RuleSeverity::Off => {}
+    });
+    true
+  }
+}
+
+/// A diagnostic at the severity it was reported with
+#[derive(Debug, Clone)]
+pub struct Finding {
+  pub diagnostic: GtDiagnostic,
+  pub severity: RuleSeverity,
+}
+
+/// Generate diagnostic for dynamic content violations
+pub fn create_dynamic_content_warning(component_name: &str, span: Span) -> GtDiagnostic {
+  GtDiagnostic::new(
+    DiagnosticCode::DynamicJsxContent,
+    format!(
+      "<{component_name}> component contains unwrapped dynamic content. Consider wrapping expressions in <Var>{{expression}}</Var> components for proper translation handling."
+    ),
+    span,
+  )
+}
+
+/// Generate diagnostic for dynamic function call violations
+pub fn create_dynamic_function_warning(
+  function_name: &str,
+  violation_type: &str,
+  span: Span,
+) -> GtDiagnostic {
+  GtDiagnostic::new(
+    DiagnosticCode::DynamicFunctionArgument,
+    format!(
+      "{function_name}() function call uses {violation_type} which prevents proper translation key generation. Use string literals instead."
+    ),
+    span,
+  )
+}
+
+/// Generate diagnostic for malformed derive() calls
+pub fn create_invalid_derive_warning(
+  function_name: &str,
+  violation_type: &str,
+  span: Span,
+) -> GtDiagnostic {
+  GtDiagnostic::new(
+    DiagnosticCode::InvalidDerive,
+    format!("{function_name}() function call has an invalid derive(): {violation_type}"),
+    span,
+  )
+}
+
+/// Generate diagnostic for suppression comments that did not suppress a violation
+pub fn create_unused_suppression_warning(span: Span) -> GtDiagnostic {
+  GtDiagnostic::new(
+    DiagnosticCode::UnusedSuppression,
+    "Unused gt-ignore comment: no build check violation was found on the following node."
+      .to_string(),
+    span,
+  )
+}
+
+/// Generate diagnostic for dictionary keys that can't be resolved at build time
+pub fn create_dynamic_dictionary_key_warning(function_name: &str, span: Span) -> GtDiagnostic {
+  GtDiagnostic::new(
+    DiagnosticCode::DynamicDictionaryKey,
+    format!(
+      "{function_name}() dictionary key is not a string literal, so it can't be checked at build time."
+    ),
+    span,
+  )
+}
+
+/// Generate diagnostic for dictionary keys that don't exist in the dictionary
+pub fn create_missing_dictionary_key_warning(
+  function_name: &str,
+  key: &str,
+  span: Span,
+) -> GtDiagnostic {
+  GtDiagnostic::new(
+    DiagnosticCode::MissingDictionaryKey,
+    format!(
+      "{function_name}() uses dictionary key \"{key}\", which does not exist in the dictionary."
+    ),
+    span,
+  )
+}
+
+/// Generate diagnostic for dictionary keys that resolve to a namespace
+pub fn create_dictionary_namespace_key_warning(
+  function_name: &str,
+  key: &str,
+  span: Span,
+) -> GtDiagnostic {
+  GtDiagnostic::new(
+    DiagnosticCode::DictionaryNamespaceKey,
+    format!(
+      "{function_name}() uses dictionary key \"{key}\", which is a namespace, not an entry. Use a key under it or pass it to useTranslations() instead."
+    ),
+    span,
+  )
+}
+
+/// Generate diagnostic for dictionary entries no translator uses.
+/// Unused entries can only be known across every file, so this has no source span.
+pub fn create_unused_dictionary_entry_warning(key: &str) -> GtDiagnostic {
+  GtDiagnostic::new(
+    DiagnosticCode::UnusedDictionaryEntry,
+    format!("Dictionary entry \"{key}\" is never used."),
+    Span::default(),
+  )
+}
+
+/// Generate diagnostic for messages that are not valid in their format's syntax
+pub fn create_invalid_message_syntax_warning(
+  function_name: &str,
+  syntax_name: &str,
+  error: &str,
+  span: Span,
+) -> GtDiagnostic {
+  GtDiagnostic::new(
+    DiagnosticCode::InvalidMessageSyntax,
+    format!("{function_name}() message is not valid {syntax_name}: {error}"),
+    span,
+  )
+}
+
+/// Generate diagnostic for ICU placeholders without a value in the options
+pub fn create_missing_icu_variable_warning(
+  function_name: &str,
+  name: &str,
+  span: Span,
+) -> GtDiagnostic {
+  GtDiagnostic::new(
+    DiagnosticCode::MissingIcuVariable,
+    format!("{function_name}() message uses {{{name}}}, but no \"{name}\" value is passed in its options."),
+    span,
+  )
+}
+
+/// Generate diagnostic for option values the ICU message never references
+pub fn create_unused_icu_variable_warning(
+  function_name: &str,
+  name: &str,
+  span: Span,
+) -> GtDiagnostic {
+  GtDiagnostic::new(
+    DiagnosticCode::UnusedIcuVariable,
+    format!("{function_name}() passes \"{name}\", which its message never uses."),
+    span,
+  )
+}
+
+/// Generate diagnostic for $format values the runtime does not support
+pub fn create_unsupported_format_warning(
+  function_name: &str,
+  format: Option<&str>,
+  span: Span,
+) -> GtDiagnostic {
+  let supported = MessageFormat::ALL.map(|format| format.name()).join(", ");
+  let message = match format {
+    Some(format) => format!(
+      "{function_name}() uses $format \"{format}\", which is not supported. Use one of {supported}."
+    ),
+    None => format!("{function_name}() $format must be a string literal, one of {supported}."),
+  };
+  GtDiagnostic::new(DiagnosticCode::UnsupportedFormat, message, span)
+}
+
+/// Generate diagnostic for <Plural> components without an `n` prop
+pub fn create_missing_plural_count_warning(span: Span) -> GtDiagnostic {
+  GtDiagnostic::new(
+    DiagnosticCode::MissingPluralCount,
+    "<Plural> has no n prop, so it always renders its children.".to_string(),
+    span,
+  )
+}
+
+/// Generate diagnostic for counts a <Plural> has no branch for: `category` is None when there
+/// is no catch-all branch at all
+pub fn create_missing_plural_branch_warning(
+  category: Option<&str>,
+  locale: Option<&str>,
+  span: Span,
+) -> GtDiagnostic {
+  let message = match (category, locale) {
+    (Some(category), Some(locale)) => {
+      format!("<Plural> has no \"{category}\" branch, though {locale} uses that plural category.")
+    }
+    _ => {
+      "<Plural> has no \"other\" branch or children for counts no other branch matches.".to_string()
+    }
+  };
+  GtDiagnostic::new(DiagnosticCode::MissingPluralBranch, message, span)
+}
+
+/// Generate diagnostic for <Plural> branches no count selects, with the reason why
+pub fn create_unreachable_plural_branch_warning(
+  branch: &str,
+  reason: &str,
+  span: Span,
+) -> GtDiagnostic {
+  GtDiagnostic::new(
+    DiagnosticCode::UnreachablePluralBranch,
+    format!("<Plural> branch \"{branch}\" is never rendered: {reason}."),
+    span,
+  )
+}
+
+/// Generate diagnostic for <Branch> components without a `branch` prop
+pub fn create_missing_branch_prop_warning(span: Span) -> GtDiagnostic {
+  GtDiagnostic::new(
+    DiagnosticCode::MissingBranchProp,
+    "<Branch> has no branch prop, so it always renders its children.".to_string(),
+    span,
+  )
+}
+
+/// Generate diagnostic for literal `branch` values that name no branch
+pub fn create_unmatched_branch_key_warning(key: &str, span: Span) -> GtDiagnostic {
+  GtDiagnostic::new(
+    DiagnosticCode::UnmatchedBranchKey,
+    format!("<Branch> has no \"{key}\" branch, so it always renders its children."),
+    span,
+  )
+}
+
+/// Generate diagnostic for <Branch> branches that render nothing
+pub fn create_empty_branch_warning(name: &str, span: Span) -> GtDiagnostic {
+  GtDiagnostic::new(
+    DiagnosticCode::EmptyBranch,
+    format!("<Branch> branch \"{name}\" is empty, so it renders nothing."),
+    span,
+  )
+}
+
+/// Generate diagnostic for <Branch> children a literal `branch` always skips
+pub fn create_ignored_branch_children_warning(key: &str, span: Span) -> GtDiagnostic {
+  GtDiagnostic::new(
+    DiagnosticCode::IgnoredBranchChildren,
+    format!("<Branch> always renders its \"{key}\" branch, so its children are never rendered."),
+    span,
+  )
+}
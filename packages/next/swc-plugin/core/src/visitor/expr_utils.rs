@@ -0,0 +1,733 @@
+use swc_core::common::{BytePos, EqIgnoreSpan, Span, Spanned, SyntaxContext, DUMMY_SP};
+use swc_core::ecma::ast::*;
+use swc_core::ecma::atoms::Atom;
+
+/**
+ * Takes in a call expression and checks if:
+ * - it has exactly one argument
+ * - the argument is a call expression or an await expression wrapping a call expression
+ *
+ * Examples:
+ *   derive(getName())
+ *   derive(await getName())
+ */
+pub fn validate_derive(call_expr: &CallExpr, errors: &mut Vec<String>) {
+  // Check if the expression is a call expression
+  // Check if it has only one argument
+  if call_expr.args.len() != 1 {
+    errors.push(format!(
+      "derive() must have exactly one argument, found {}",
+      call_expr.args.len()
+    ));
+    return;
+  }
+
+  // Check if that argument is a call expression or await expression wrapping a call
+  if let Some(first_arg) = call_expr.args.first() {
+    let is_valid = match first_arg.expr.as_ref() {
+      // Direct call expression: derive(getName())
+      Expr::Call(_) => true,
+
+      // Await expression: derive(await getName())
+      Expr::Await(await_expr) => {
+        // Validate that the awaited expression is a call expression
+        matches!(await_expr.arg.as_ref(), Expr::Call(_))
+      }
+
+      _ => false,
+    };
+
+    if !is_valid {
+      errors.push("derive() first argument must be a call expression".to_string());
+    }
+  }
+}
+
+/// Extract the value of a string literal or a template literal without expressions
+pub fn extract_static_string(expr: &Expr) -> Option<String> {
+  match expr {
+    Expr::Lit(Lit::Str(s)) => Some(s.value.to_string_lossy().into_owned()),
+    Expr::Tpl(tpl) if tpl.exprs.is_empty() => tpl
+      .quasis
+      .first()
+      .and_then(|quasi| quasi.cooked.as_ref())
+      .map(|cooked| cooked.to_string_lossy().into_owned()),
+    Expr::Paren(paren) => extract_static_string(&paren.expr),
+    _ => None,
+  }
+}
+
+/// Span of the character at byte `offset` of a literal's value, as read by
+/// [`extract_static_string`], so diagnostics can point inside the literal. Falls back to the
+/// whole expression when the literal has no source text, e.g. after a rewrite.
+pub fn literal_char_span(expr: &Expr, offset: usize) -> Span {
+  let (raw, base) = match expr {
+    Expr::Paren(paren) => return literal_char_span(&paren.expr, offset),
+    Expr::Lit(Lit::Str(s)) => match &s.raw {
+      Some(raw) if raw.len() >= 2 && !s.span.is_dummy() => {
+        (&raw[1..raw.len() - 1], s.span.lo + BytePos(1))
+      }
+      _ => return expr.span(),
+    },
+    Expr::Tpl(tpl) => match tpl.quasis.first() {
+      Some(quasi) if tpl.exprs.is_empty() && !quasi.span.is_dummy() => (&*quasi.raw, quasi.span.lo),
+      _ => return expr.span(),
+    },
+    _ => return expr.span(),
+  };
+  match raw_offset(raw, offset) {
+    Some((start, len)) => {
+      let lo = base + BytePos(start as u32);
+      Span::new(lo, lo + BytePos(len as u32))
+    }
+    None => expr.span(),
+  }
+}
+
+/// Byte range in a literal's source text of the character at byte `offset` of its value,
+/// walking escape sequences
+fn raw_offset(raw: &str, offset: usize) -> Option<(usize, usize)> {
+  let mut cooked = 0;
+  let mut chars = raw.char_indices().peekable();
+  while let Some((start, ch)) = chars.next() {
+    let cooked_len = match ch {
+      '\\' => match chars.next()?.1 {
+        'u' if chars.peek().is_some_and(|(_, ch)| *ch == '{') => {
+          let digits: String = chars
+            .by_ref()
+            .skip(1)
+            .take_while(|(_, ch)| *ch != '}')
+            .map(|(_, ch)| ch)
+            .collect();
+          escaped_char_len(&digits)
+        }
+        'u' => escaped_char_len(&chars.by_ref().take(4).map(|(_, ch)| ch).collect::<String>()),
+        'x' => escaped_char_len(&chars.by_ref().take(2).map(|(_, ch)| ch).collect::<String>()),
+        // Line continuations add nothing to the value
+        '\r' => {
+          chars.next_if(|(_, ch)| *ch == '\n');
+          0
+        }
+        '\n' | '\u{2028}' | '\u{2029}' => 0,
+        escaped => escaped.len_utf8(),
+      },
+      // Template literals normalize CRLF to LF
+      '\r' => {
+        chars.next_if(|(_, ch)| *ch == '\n');
+        1
+      }
+      ch => ch.len_utf8(),
+    };
+    if offset < cooked + cooked_len {
+      let end = chars.peek().map_or(raw.len(), |(index, _)| *index);
+      return Some((start, end - start));
+    }
+    cooked += cooked_len;
+  }
+  (offset == cooked).then_some((raw.len(), 0))
+}
+
+/// UTF-8 length of a `\u` or `\x` escape's character. Lone surrogates take three bytes.
+fn escaped_char_len(hex: &str) -> usize {
+  u32::from_str_radix(hex, 16)
+    .ok()
+    .and_then(char::from_u32)
+    .map_or(3, char::len_utf8)
+}
+
+/**
+ * Takes in a declareVar() call and checks that:
+ * - it has one or two arguments
+ * - the options, when present, are an object literal with a string literal $name
+ *
+ * Valid forms:
+ *   declareVar(value)
+ *   declareVar(value, { $name: "name" })
+ */
+pub fn validate_declare_var(call_expr: &CallExpr, errors: &mut Vec<String>) {
+  if call_expr.args.is_empty() || call_expr.args.len() > 2 {
+    errors.push(format!(
+      "declareVar() must have one or two arguments, found {}",
+      call_expr.args.len()
+    ));
+    return;
+  }
+  if let Some(options) = call_expr.args.get(1) {
+    if extract_declare_var_name(&options.expr).is_none() {
+      errors.push(
+        "declareVar() options must be an object literal with a string literal $name".to_string(),
+      );
+    }
+  }
+}
+
+/// Extract `$name` from declareVar() options: Some(None) when absent, None when not static
+pub fn extract_declare_var_name(options: &Expr) -> Option<Option<String>> {
+  let Expr::Object(object) = options else {
+    return None;
+  };
+  let mut name = None;
+  for prop in &object.props {
+    let PropOrSpread::Prop(prop) = prop else {
+      return None;
+    };
+    let Prop::KeyValue(KeyValueProp { key, value }) = prop.as_ref() else {
+      return None;
+    };
+    let key = match key {
+      PropName::Ident(ident) => ident.sym.to_string(),
+      PropName::Str(str_lit) => str_lit.value.to_string_lossy().into_owned(),
+      _ => return None,
+    };
+    if key == "$name" {
+      name = Some(extract_static_string(value)?);
+    }
+  }
+  Some(name)
+}
+
+/// Port of the runtime's sanitizeVar(): escapes ICU syntax so a value fits inside
+/// `{_gt_, select, other {value}}`
+pub fn sanitize_var(value: &str) -> String {
+  let result = value.replace('\'', "''");
+  let is_special = |c: char| matches!(c, '{' | '}' | '<' | '>');
+  let (Some(first), Some(last)) = (result.find(is_special), result.rfind(is_special)) else {
+    return result;
+  };
+  format!(
+    "{}'{}'{}",
+    &result[..first],
+    &result[first..=last],
+    &result[last + 1..]
+  )
+}
+
+/// Source of a declareVar() call as the runtime hashes it. declareVar() encodes
+/// `{_gt_, select, other {value} _gt_var_name {name}}` and indexVars() numbers each variable
+/// from 1 and empties its value.
+pub fn declare_var_source(index: usize, name: Option<&str>) -> String {
+  let name_section = name
+    .filter(|name| !name.is_empty())
+    .map(|name| format!(" _gt_var_name {{{}}}", sanitize_var(name)))
+    .unwrap_or_default();
+  format!("{{_gt_{index}, select, other {{}}{name_section}}}")
+}
+
+// Helper function to extract string values from expressions
+pub fn extract_string_from_expr(expr: &Expr) -> Option<String> {
+  match expr {
+    Expr::Lit(Lit::Str(s)) => Some(s.value.to_string_lossy().into_owned()),
+    Expr::Lit(Lit::Num(n)) => Some(n.value.to_string()),
+    Expr::Lit(Lit::Bool(b)) => Some(b.value.to_string()),
+    Expr::Ident(ident) => Some(ident.sym.to_string()),
+    Expr::Tpl(tpl) => {
+      if tpl.exprs.is_empty() && tpl.quasis.len() == 1 {
+        if let Some(quasi) = tpl.quasis.first() {
+          if let Some(cooked) = &quasi.cooked {
+            return Some(cooked.to_string_lossy().into_owned());
+          } else {
+            return Some(quasi.raw.to_string());
+          }
+        }
+      }
+      None
+    }
+    // Add more cases as needed for other expression types
+    _ => None,
+  }
+}
+
+// Helper function to extract positive integers from expressions
+pub fn extract_number_from_expr(expr: &Expr) -> Option<i32> {
+  match expr {
+    Expr::Lit(Lit::Num(n)) => {
+      if n.value.fract() == 0.0 {
+        Some(n.value.abs() as i32)
+      } else {
+        None
+      }
+    }
+    // Handle unary expressions: accept +42 and -42 (take absolute value)
+    Expr::Unary(unary_expr) => {
+      if unary_expr.op == UnaryOp::Plus || unary_expr.op == UnaryOp::Minus {
+        if let Expr::Lit(Lit::Num(num)) = unary_expr.arg.as_ref() {
+          if num.value.fract() == 0.0 {
+            Some(num.value.abs() as i32)
+          } else {
+            None
+          }
+        } else {
+          None
+        }
+      } else {
+        None
+      }
+    }
+    // Add more cases as needed for other expression types
+    _ => None,
+  }
+}
+
+/// Checks if an expression is a derive() call by name
+fn is_derive_call_expr(expr: &Expr) -> bool {
+  if let Expr::Call(call_expr) = expr {
+    if let Callee::Expr(callee_expr) = &call_expr.callee {
+      if let Expr::Ident(ident) = callee_expr.as_ref() {
+        return ident.sym.as_str() == "derive";
+      }
+    }
+  }
+  false
+}
+
+/// Recursively checks if an expression contains a derive() call.
+/// Handles: bare call, binary concat ("a" + derive(fn())), template literal (`a${derive(fn())}`)
+pub fn contains_derive_call(expr: &Expr) -> bool {
+  match expr {
+    Expr::Call(_) => is_derive_call_expr(expr),
+    Expr::Bin(bin_expr) => {
+      contains_derive_call(&bin_expr.left) || contains_derive_call(&bin_expr.right)
+    }
+    Expr::Tpl(tpl) => tpl.exprs.iter().any(|e| contains_derive_call(e)),
+    Expr::Paren(paren) => contains_derive_call(&paren.expr),
+    Expr::Cond(cond) => contains_derive_call(&cond.cons) || contains_derive_call(&cond.alt),
+    _ => false,
+  }
+}
+
+// Helper function to extract $id, $context, $maxChars, and $format from options
+// Returns (id, context, maxChars, format, has_derive_context)
+pub fn extract_id_and_context_from_options(
+  options: Option<&ExprOrSpread>,
+) -> (
+  Option<String>,
+  Option<String>,
+  Option<i32>,
+  Option<String>,
+  bool,
+) {
+  let (id, context, max_chars, format, has_derive_context) = match options {
+    Some(options) => match options.expr.as_ref() {
+      Expr::Object(obj) => {
+        let mut id_value = None;
+        let mut context_value = None;
+        let mut max_chars_value = None;
+        let mut format_value = None;
+        let mut has_derive_context = false;
+
+        for prop in &obj.props {
+          if let PropOrSpread::Prop(prop) = prop {
+            if let Prop::KeyValue(key_value) = prop.as_ref() {
+              if let PropName::Ident(ident) = &key_value.key {
+                match ident.sym.as_str() {
+                  "$id" => {
+                    id_value = extract_string_from_expr(&key_value.value);
+                  }
+                  "$context" => {
+                    context_value = extract_string_from_expr(&key_value.value);
+                    if context_value.is_none() && contains_derive_call(&key_value.value) {
+                      has_derive_context = true;
+                    }
+                  }
+                  "$maxChars" => {
+                    max_chars_value = extract_number_from_expr(&key_value.value);
+                  }
+                  "$format" => {
+                    format_value = extract_string_from_expr(&key_value.value);
+                  }
+                  _ => {}
+                }
+              }
+            }
+          }
+        }
+
+        (
+          id_value,
+          context_value,
+          max_chars_value,
+          format_value,
+          has_derive_context,
+        )
+      }
+      _ => (None, None, None, None, false),
+    },
+    None => (None, None, None, None, false),
+  };
+  (id, context, max_chars, format, has_derive_context)
+}
+
+pub fn create_string_prop(key: &str, value: &str, span: Span) -> PropOrSpread {
+  PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+    key: PropName::Ident(Ident::new(key.into(), span, SyntaxContext::empty()).into()),
+    value: Box::new(Expr::Lit(Lit::Str(Str {
+      span,
+      value: value.into(),
+      raw: None,
+    }))),
+  })))
+}
+
+pub fn has_prop(props: &[PropOrSpread], key: &str) -> bool {
+  props.iter().any(|prop| {
+    if let PropOrSpread::Prop(p) = prop {
+      if let Prop::KeyValue(kv) = p.as_ref() {
+        if let PropName::Ident(ident) = &kv.key {
+          return ident.sym.as_ref() == key;
+        }
+      }
+    }
+    false
+  })
+}
+
+/// Build the ICU source the gt-i18n runtime uses for a tagged template: each interpolation
+/// becomes a positional placeholder, t`Hello ${name}` -> "Hello {0}".
+/// Returns None when a quasi has no cooked value (invalid escape sequence).
+pub fn build_tagged_template_source(tpl: &Tpl) -> Option<String> {
+  let mut source = String::new();
+  for (index, quasi) in tpl.quasis.iter().enumerate() {
+    source.push_str(&quasi.cooked.as_ref()?.to_string_lossy());
+    if index < tpl.exprs.len() {
+      source.push_str(&format!("{{{index}}}"));
+    }
+  }
+  Some(source)
+}
+
+/// Placeholder name an interpolation gets in an ICU message: `${name}` -> "name",
+/// `${user.name}` -> "name". None for other expressions and `$`-prefixed (reserved) names.
+fn icu_placeholder_name(expr: &Expr) -> Option<Atom> {
+  let name = match expr {
+    Expr::Ident(ident) => ident.sym.clone(),
+    Expr::Member(MemberExpr {
+      prop: MemberProp::Ident(prop),
+      ..
+    }) => prop.sym.clone(),
+    Expr::Paren(paren_expr) => return icu_placeholder_name(&paren_expr.expr),
+    _ => return None,
+  };
+  (!name.starts_with('$')).then_some(name)
+}
+
+/// Keys of an options object literal, None when a spread hides them
+fn object_keys(props: &[PropOrSpread]) -> Option<Vec<String>> {
+  let keys = object_key_spans(props)?;
+  Some(keys.into_iter().map(|(key, _)| key).collect())
+}
+
+/// Keys of an options object literal with the span of each, None when a spread or a computed
+/// key hides them
+fn object_key_spans(props: &[PropOrSpread]) -> Option<Vec<(String, Span)>> {
+  props
+    .iter()
+    .map(|prop| match prop {
+      PropOrSpread::Prop(prop) => match prop.as_ref() {
+        Prop::Shorthand(ident) => Some((ident.sym.to_string(), ident.span)),
+        Prop::KeyValue(KeyValueProp { key, .. }) => match key {
+          PropName::Ident(ident) => Some((ident.sym.to_string(), ident.span)),
+          PropName::Str(str_lit) => {
+            Some((str_lit.value.to_string_lossy().into_owned(), str_lit.span))
+          }
+          PropName::Num(number) => Some((number.value.to_string(), number.span)),
+          _ => None,
+        },
+        _ => None,
+      },
+      PropOrSpread::Spread(_) => None,
+    })
+    .collect()
+}
+
+/// Value of a key in an options object literal: `"STRING"` for `$format` in
+/// `{ $format: "STRING" }`
+pub fn option_value<'a>(options: Option<&'a ExprOrSpread>, key: &str) -> Option<&'a Expr> {
+  let Expr::Object(obj) = options?.expr.as_ref() else {
+    return None;
+  };
+  obj.props.iter().find_map(|prop| match prop {
+    PropOrSpread::Prop(prop) => match prop.as_ref() {
+      Prop::KeyValue(KeyValueProp {
+        key: PropName::Ident(ident),
+        value,
+      }) if ident.sym == key => Some(value.as_ref()),
+      _ => None,
+    },
+    PropOrSpread::Spread(_) => None,
+  })
+}
+
+/// Variables passed in the options of a t() or msg() call: the keys not starting with `$`,
+/// with their spans. None when a spread or a non-literal options argument hides them.
+pub fn option_variables(options: Option<&ExprOrSpread>) -> Option<Vec<(String, Span)>> {
+  let props = match options {
+    None => return Some(Vec::new()),
+    Some(ExprOrSpread { spread: None, expr }) => match expr.as_ref() {
+      Expr::Object(obj) => &obj.props,
+      _ => return None,
+    },
+    Some(_) => return None,
+  };
+  let keys = object_key_spans(props)?;
+  Some(
+    keys
+      .into_iter()
+      .filter(|(key, _)| !key.starts_with('$'))
+      .collect(),
+  )
+}
+
+/// Build the ICU message and variables for an interpolated template literal:
+/// `Hello ${user.name}, you have ${count} items` ->
+/// ("Hello {name}, you have {count} items", [name: user.name, count]).
+/// Placeholders are named after identifiers and member properties; other interpolations, and
+/// names already taken by a different value or an option, fall back to their position.
+/// Repeated identifiers and member expressions share a placeholder. Returns None when the text
+/// holds ICU braces or a placeholder can't be named.
+pub fn build_icu_template_message(
+  tpl: &Tpl,
+  options: &[PropOrSpread],
+) -> Option<(String, Vec<PropOrSpread>)> {
+  let mut taken = object_keys(options)?;
+  let mut placeholders: Vec<(String, &Expr)> = Vec::new();
+  let mut message = String::new();
+  for (index, quasi) in tpl.quasis.iter().enumerate() {
+    let text = quasi.cooked.as_ref()?.to_string_lossy();
+    if text.contains(['{', '}']) {
+      return None;
+    }
+    message.push_str(&text);
+    let Some(expr) = tpl.exprs.get(index) else {
+      continue;
+    };
+    // An apostrophe right before a placeholder would quote it: '{name}'
+    if text.ends_with('\'') {
+      message.push('\'');
+    }
+    let repeated = placeholders.iter().find(|(_, previous)| {
+      matches!(expr.as_ref(), Expr::Ident(_) | Expr::Member(_))
+        && previous.eq_ignore_span(&expr.as_ref())
+    });
+    let name = match repeated {
+      Some((name, _)) => name.clone(),
+      None => {
+        let name = icu_placeholder_name(expr)
+          .map(|name| name.to_string())
+          .filter(|name| !taken.contains(name))
+          .unwrap_or_else(|| index.to_string());
+        if taken.contains(&name) {
+          return None;
+        }
+        taken.push(name.clone());
+        placeholders.push((name.clone(), expr.as_ref()));
+        name
+      }
+    };
+    message.push_str(&format!("{{{name}}}"));
+  }
+
+  let span = tpl.span;
+  let variables = placeholders
+    .into_iter()
+    .map(|(name, expr)| {
+      let prop = match expr {
+        Expr::Ident(ident) if ident.sym == name => Prop::Shorthand(ident.clone()),
+        _ => Prop::KeyValue(KeyValueProp {
+          key: match name.parse::<f64>() {
+            Ok(value) => PropName::Num(Number {
+              span,
+              value,
+              raw: None,
+            }),
+            Err(_) => PropName::Ident(IdentName::new(name.into(), span)),
+          },
+          value: Box::new(expr.clone()),
+        }),
+      };
+      PropOrSpread::Prop(Box::new(prop))
+    })
+    .collect();
+  Some((message, variables))
+}
+
+/// Options that spread an existing options expression and add the hash:
+/// `t("Hi", opts)` -> `t("Hi", { ...opts, $_hash: "..." })`
+pub fn create_spread_options(spread_expr: Box<Expr>, hash: &str, span: Span) -> Expr {
+  let props = vec![
+    PropOrSpread::Spread(SpreadElement {
+      dot3_token: span,
+      expr: spread_expr,
+    }),
+    create_string_prop("$_hash", hash, span),
+  ];
+  Expr::Object(ObjectLit { span, props })
+}
+
+/// `import { imported as local } from 'source'`
+pub fn create_named_import(imported: &str, local: Ident, source: &str) -> ModuleItem {
+  ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+    span: DUMMY_SP,
+    specifiers: vec![ImportSpecifier::Named(ImportNamedSpecifier {
+      span: DUMMY_SP,
+      local,
+      imported: Some(ModuleExportName::Ident(Ident::new_no_ctxt(
+        imported.into(),
+        DUMMY_SP,
+      ))),
+      is_type_only: false,
+    })],
+    src: Box::new(source.into()),
+    type_only: false,
+    with: None,
+    phase: Default::default(),
+  }))
+}
+
+pub fn get_callee_expr_function_name(call_expr: &CallExpr) -> Option<Atom> {
+  if let Callee::Expr(callee_expr) = &call_expr.callee {
+    if let Expr::Ident(ident) = callee_expr.as_ref() {
+      return Some(ident.sym.clone());
+    }
+  }
+  None
+}
+
+/// Module loaded by `require('module')`, `import('module')` or `await import('module')`
+pub fn get_required_module(expr: &Expr) -> Option<String> {
+  match expr {
+    Expr::Await(AwaitExpr { arg, .. }) => get_required_module(arg),
+    Expr::Paren(paren_expr) => get_required_module(&paren_expr.expr),
+    Expr::Call(call_expr) => {
+      let is_loader = match &call_expr.callee {
+        Callee::Import(_) => true,
+        Callee::Expr(callee) => {
+          matches!(callee.as_ref(), Expr::Ident(ident) if ident.sym == "require")
+        }
+        Callee::Super(_) => false,
+      };
+      if !is_loader || call_expr.args.len() != 1 {
+        return None;
+      }
+      extract_static_string(&call_expr.args[0].expr)
+    }
+    _ => None,
+  }
+}
+
+/// Check if a JSX expression is allowed as dynamic content in JSX translation components
+/// Allowed: string literals, number literals, template strings without expressions, JSX elements with safe content
+/// Not allowed: complex expressions, function calls, binary operations, JSX with dynamic content, etc.
+pub fn is_allowed_dynamic_content(jsx_expr: &JSXExpr) -> bool {
+  match jsx_expr {
+    JSXExpr::JSXEmptyExpr(_) => true, // Empty expressions are allowed
+    JSXExpr::Expr(expr) => is_allowed_expr_content(expr.as_ref()),
+  }
+}
+
+/// Helper function to recursively check if an expression contains only allowed content
+fn is_allowed_expr_content(expr: &Expr) -> bool {
+  match expr {
+    // String literals are allowed: {"hello"}
+    Expr::Lit(Lit::Str(_)) => true,
+
+    // Number literals are allowed: {42}
+    Expr::Lit(Lit::Num(_)) => true,
+
+    // Boolean literals are allowed: {true}
+    Expr::Lit(Lit::Bool(_)) => true,
+
+    // Null and undefined literals are allowed
+    Expr::Lit(Lit::Null(_)) => true,
+
+    // Template literals without expressions are allowed: {`hello`}
+    Expr::Tpl(tpl) => tpl.exprs.is_empty(),
+
+    // Allow specific safe identifiers: undefined
+    Expr::Ident(ident) => {
+      matches!(ident.sym.as_str(), "undefined")
+    }
+
+    // Unary expressions: allow only numeric literals with + or - operators
+    // {+123}, {-123} are allowed, but not {!value}, {++counter}, etc.
+    Expr::Unary(unary) => match unary.op {
+      UnaryOp::Plus | UnaryOp::Minus => {
+        // Only allow unary +/- on number literals
+        matches!(unary.arg.as_ref(), Expr::Lit(Lit::Num(_)))
+      }
+      _ => false, // Other unary operators (!value, typeof, etc.) are not allowed
+    },
+
+    // JSX elements are allowed only if their content is safe
+    Expr::JSXElement(element) => is_jsx_element_safe(element),
+
+    // JSX fragments are allowed only if their content is safe
+    Expr::JSXFragment(fragment) => is_jsx_fragment_safe(fragment),
+
+    // Array literals are not allowed in general: [1, 2, 3] or [...items]
+    Expr::Array(_) => false,
+
+    // Object literals are not allowed in general: {key: value} or {...obj}
+    Expr::Object(_) => false,
+
+    // Everything else is not allowed (variables, function calls, binary expressions, etc.)
+    _ => false,
+  }
+}
+
+/// Check if a JSX element contains only safe content
+fn is_jsx_element_safe(element: &JSXElement) -> bool {
+  // Check all children of the JSX element
+  for child in &element.children {
+    match child {
+      JSXElementChild::JSXText(_) => continue, // Text is always safe
+      JSXElementChild::JSXElement(child_element) => {
+        if !is_jsx_element_safe(child_element) {
+          return false;
+        }
+      }
+      JSXElementChild::JSXFragment(child_fragment) => {
+        if !is_jsx_fragment_safe(child_fragment) {
+          return false;
+        }
+      }
+      JSXElementChild::JSXExprContainer(expr_container) => {
+        if !is_allowed_dynamic_content(&expr_container.expr) {
+          return false;
+        }
+      }
+      JSXElementChild::JSXSpreadChild(_) => return false, // Spread children are not allowed
+    }
+  }
+  true
+}
+
+/// Check if a JSX fragment contains only safe content
+fn is_jsx_fragment_safe(fragment: &JSXFragment) -> bool {
+  // Check all children of the JSX fragment
+  for child in &fragment.children {
+    match child {
+      JSXElementChild::JSXText(_) => continue, // Text is always safe
+      JSXElementChild::JSXElement(child_element) => {
+        if !is_jsx_element_safe(child_element) {
+          return false;
+        }
+      }
+      JSXElementChild::JSXFragment(child_fragment) => {
+        if !is_jsx_fragment_safe(child_fragment) {
+          return false;
+        }
+      }
+      JSXElementChild::JSXExprContainer(expr_container) => {
+        if !is_allowed_dynamic_content(&expr_container.expr) {
+          return false;
+        }
+      }
+      JSXElementChild::JSXSpreadChild(_) => return false, // Spread children are not allowed
+    }
+  }
+  true
+}
+
+#[cfg(test)]
+#[path = "expr_utils_tests.rs"]
+mod tests;
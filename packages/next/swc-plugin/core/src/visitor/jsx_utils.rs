@@ -1,4 +1,5 @@
-use crate::visitor::expr_utils::contains_derive_call;
+use crate::ast::js_number_to_string;
+use crate::visitor::expr_utils::{contains_derive_call, is_allowed_dynamic_content};
 use swc_core::ecma::ast::*;
 
 pub fn extract_template_string(tpl: &Tpl) -> Option<String> {
@@ -30,6 +31,23 @@ pub fn extract_string_from_jsx_attr(jsx_attr: &JSXAttr) -> Option<String> {
   }
 }
 
+/// The key a literal `branch` prop selects, stringified like the runtime does: `branch="admin"`,
+/// `branch={1}`, `branch={true}`
+pub fn extract_branch_key_from_jsx_attr(jsx_attr: &JSXAttr) -> Option<String> {
+  if let Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+    expr: JSXExpr::Expr(expr),
+    ..
+  })) = &jsx_attr.value
+  {
+    match expr.as_ref() {
+      Expr::Lit(Lit::Num(num_lit)) => return Some(js_number_to_string(num_lit.value)),
+      Expr::Lit(Lit::Bool(bool_lit)) => return Some(bool_lit.value.to_string()),
+      _ => {}
+    }
+  }
+  extract_string_from_jsx_attr(jsx_attr)
+}
+
 pub fn extract_attribute_from_jsx_attr(
   element: &JSXElement,
   attribute_name: &str,
@@ -51,10 +69,7 @@ pub fn extract_attribute_from_jsx_attr(
   })
 }
 
-pub fn extract_max_chars_from_jsx_attr(
-  element: &JSXElement,
-  attribute_name: &str,
-) -> Option<i32> {
+pub fn extract_max_chars_from_jsx_attr(element: &JSXElement, attribute_name: &str) -> Option<i32> {
   element.opening.attrs.iter().find_map(|attr| {
     if let JSXAttrOrSpread::JSXAttr(jsx_attr) = attr {
       if let JSXAttrName::Ident(ident) = &jsx_attr.name {
@@ -125,6 +140,64 @@ pub fn jsx_attr_contains_derive_call(element: &JSXElement, attr_name: &str) -> b
   })
 }
 
+/// Check if children hold dynamic content the GT001 check reports: expression containers that
+/// are not allowed, directly or inside fragments
+pub fn has_unwrapped_dynamic_content(children: &[JSXElementChild]) -> bool {
+  children.iter().any(|child| match child {
+    JSXElementChild::JSXExprContainer(container) => !is_allowed_dynamic_content(&container.expr),
+    JSXElementChild::JSXFragment(fragment) => has_unwrapped_dynamic_content(&fragment.children),
+    _ => false,
+  })
+}
+
+/// Check if children render more than whitespace, like the fallback content of `<Plural>` and
+/// `<Branch>`
+pub fn has_visible_children(children: &[JSXElementChild]) -> bool {
+  children.iter().any(|child| match child {
+    JSXElementChild::JSXText(text) => !text.value.trim().is_empty(),
+    _ => true,
+  })
+}
+
+/// Wrap the dynamic content found by [`has_unwrapped_dynamic_content`] in a variable
+/// component: `Hello {user.name}` -> `Hello <Var>{user.name}</Var>`
+pub fn wrap_unwrapped_dynamic_content(children: &mut [JSXElementChild], var_name: &JSXElementName) {
+  for child in children {
+    match child {
+      JSXElementChild::JSXExprContainer(container)
+        if !is_allowed_dynamic_content(&container.expr) =>
+      {
+        let element = create_variable_element(var_name, container.clone());
+        *child = JSXElementChild::JSXElement(Box::new(element));
+      }
+      JSXElementChild::JSXFragment(fragment) => {
+        wrap_unwrapped_dynamic_content(&mut fragment.children, var_name);
+      }
+      _ => {}
+    }
+  }
+}
+
+/// `<Var>{expr}</Var>`, spanning the wrapped expression container
+fn create_variable_element(name: &JSXElementName, container: JSXExprContainer) -> JSXElement {
+  let span = container.span;
+  JSXElement {
+    span,
+    opening: JSXOpeningElement {
+      name: name.clone(),
+      span,
+      attrs: vec![],
+      self_closing: false,
+      type_args: None,
+    },
+    children: vec![JSXElementChild::JSXExprContainer(container)],
+    closing: Some(JSXClosingElement {
+      span,
+      name: name.clone(),
+    }),
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
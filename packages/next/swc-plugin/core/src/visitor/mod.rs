@@ -1,8 +1,10 @@
 pub mod analysis;
+pub mod dataflow;
 pub mod errors;
 pub mod expr_utils;
 pub mod jsx_utils;
 pub mod state;
+pub mod suppression;
 pub mod transform;
 
 pub use transform::TransformVisitor;
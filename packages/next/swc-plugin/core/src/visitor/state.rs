@@ -0,0 +1,82 @@
+use crate::ast::BindingTracker;
+use crate::config::ImportSourcePreset;
+use swc_core::common::Span;
+use swc_core::ecma::ast::Id;
+
+/// A key passed to a useTranslations()/getTranslations() translator
+#[derive(Debug, Clone)]
+pub struct DictionaryKeyUsage {
+  /// Fully qualified key including the translator's namespace,
+  /// None when the key or the namespace is not a string literal
+  pub key: Option<String>,
+  /// Span of the key argument
+  pub span: Span,
+}
+
+// For tracking statistics for the plugin
+#[derive(Default)]
+pub struct Statistics {
+  pub jsx_element_count: u32,
+  /// Error-level build check violations
+  pub dynamic_content_violations: u32,
+  /// Warning-level build check violations
+  pub build_check_warnings: u32,
+  /// Keys passed to dictionary translators (useTranslations, getTranslations)
+  pub dictionary_keys: Vec<DictionaryKeyUsage>,
+  /// Calls to message decoding translators (useMessages, getMessages)
+  pub message_decode_count: u32,
+}
+
+// For tracking the current state during AST traversal
+#[derive(Default)]
+pub struct TraversalState {
+  /// Track whether we're inside a translation component (T, Plural, etc.)
+  pub in_translation_component: bool,
+  /// Track whether we're inside a variable component (Var, Num, Currency, DateTime, RelativeTime, Derive)
+  pub in_variable_component: bool,
+  /// Track whether we're inside a JSX attribute expression (to ignore them)
+  pub in_jsx_attribute: bool,
+  /// Whether the program is an ES module, which can receive generated imports
+  pub in_module: bool,
+  /// Binding of the function about to be visited: `function greet() {}`,
+  /// `const greet = () => {}`. Taken when the function is entered.
+  pub pending_function: Option<Id>,
+}
+
+// For tracking gt-next imports and their aliases
+#[derive(Default)]
+pub struct ImportTracker {
+  /// GT bindings, keyed by resolved identity
+  pub bindings: BindingTracker,
+
+  /// Namespace imports (`import * as GT`, `const GT = require(...)`) and the preset of the
+  /// module they were loaded from
+  pub namespace_imports: std::collections::HashMap<Id, ImportSourcePreset>,
+
+  /// Module each imported GT binding was loaded from
+  pub import_modules: std::collections::HashMap<Id, String>,
+
+  /// Module to import `Var` from for auto-wrapped content, when the file does not import it
+  pub auto_var_import: Option<String>,
+
+  /// Namespaces passed to useTranslations()/getTranslations(), indexed by translator identifier.
+  /// None when the namespace is not a string literal
+  pub dictionary_namespaces: Vec<Option<String>>,
+}
+
+impl ImportTracker {
+  pub fn new() -> Self {
+    Self {
+      bindings: BindingTracker::default(),
+      namespace_imports: std::collections::HashMap::new(),
+      import_modules: std::collections::HashMap::new(),
+      auto_var_import: None,
+      dictionary_namespaces: Vec::new(),
+    }
+  }
+
+  /// Whether any GT export was imported or required
+  pub fn has_gt_imports(&self) -> bool {
+    !self.bindings.is_empty() || !self.namespace_imports.is_empty()
+  }
+}
@@ -0,0 +1,406 @@
+use swc_core::common::{
+  comments::{Comment, Comments},
+  sync::Lrc,
+  BytePos, SourceFile, Span,
+};
+
+/// `// gt-ignore-next-line` - suppresses violations on the line that follows
+pub const IGNORE_NEXT_LINE_DIRECTIVE: &str = "gt-ignore-next-line";
+/// `{/* gt-ignore */}` - suppresses violations in the JSX child that follows
+pub const IGNORE_DIRECTIVE: &str = "gt-ignore";
+
+/// What a suppression comment covers
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Target {
+  /// Violations starting on this 0-based line
+  Line(usize),
+  /// Violations inside this node
+  Node(Span),
+  /// A `gt-ignore` no JSX child has claimed yet, which covers nothing
+  Unclaimed,
+}
+
+/// A suppression comment and what it applies to
+#[derive(Debug, Clone)]
+struct Suppression {
+  /// Span of the comment itself, used to report unused suppressions
+  comment: Span,
+  target: Target,
+  used: bool,
+}
+
+/// Tracks suppression comments, found once up front in the source file
+#[derive(Default)]
+pub struct SuppressionTracker {
+  /// Resolves violations to lines for `gt-ignore-next-line`
+  file: Option<Lrc<SourceFile>>,
+  suppressions: Vec<Suppression>,
+}
+
+impl SuppressionTracker {
+  /// Find every suppression comment in the file. `gt-ignore-next-line` covers the line after
+  /// it wherever it is written, so one before a function leaves its body checked, while
+  /// `gt-ignore` waits for the JSX child it precedes.
+  pub fn new(comments: Option<Box<dyn Comments>>, file: Option<Lrc<SourceFile>>) -> Self {
+    let mut tracker = Self::default();
+    let (Some(comments), Some(file)) = (comments, file) else {
+      return tracker;
+    };
+    for comment in scan_directives(&file, &*comments) {
+      let target = if comment.text.trim().starts_with(IGNORE_NEXT_LINE_DIRECTIVE) {
+        match file.lookup_line(comment.span.hi) {
+          Some(line) => Target::Line(line + 1),
+          None => continue,
+        }
+      } else {
+        Target::Unclaimed
+      };
+      tracker.register(comment.span, target);
+    }
+    tracker.file = Some(file);
+    tracker
+  }
+
+  /// Claim the `gt-ignore` written inside a JSX expression container, i.e. `{/* gt-ignore */}`,
+  /// for `target`. Returns true when the container holds a suppression comment.
+  pub fn register_jsx_container(&mut self, container: Span, target: Span) -> bool {
+    let unclaimed = self.suppressions.iter_mut().find(|s| {
+      s.target == Target::Unclaimed && container.lo < s.comment.lo && s.comment.hi < container.hi
+    });
+    match unclaimed {
+      Some(suppression) => {
+        suppression.target = Target::Node(target);
+        true
+      }
+      None => false,
+    }
+  }
+
+  fn register(&mut self, comment: Span, target: Target) {
+    // Keep the first registration of a comment
+    if self.suppressions.iter().any(|s| s.comment == comment) {
+      return;
+    }
+    self.suppressions.push(Suppression {
+      comment,
+      target,
+      used: false,
+    });
+  }
+
+  /// Returns true if a violation at `span` is suppressed, marking the innermost suppression
+  /// that covers it as used: the one whose comment is closest before it
+  pub fn suppress(&mut self, span: Span) -> bool {
+    if self.suppressions.is_empty() {
+      return false;
+    }
+    let line = self
+      .file
+      .as_ref()
+      .and_then(|file| file.lookup_line(span.lo));
+    let covering = self.suppressions.iter_mut().filter(|s| match s.target {
+      Target::Line(target) => line == Some(target),
+      Target::Node(target) => target.lo <= span.lo && span.hi <= target.hi,
+      Target::Unclaimed => false,
+    });
+    match covering.max_by_key(|s| s.comment.lo) {
+      Some(suppression) => {
+        suppression.used = true;
+        true
+      }
+      None => false,
+    }
+  }
+
+  /// Spans of suppression comments that did not suppress anything
+  pub fn unused(&self) -> Vec<Span> {
+    self
+      .suppressions
+      .iter()
+      .filter(|s| !s.used)
+      .map(|s| s.comment)
+      .collect()
+  }
+}
+
+/// Find the suppression comments of a file by searching its source for the directives, then
+/// confirming each against the comment map. A comment is attached either before the token that
+/// follows it or after the token it follows, so confirming takes at most two lookups per
+/// directive instead of one per node.
+fn scan_directives(file: &SourceFile, comments: &dyn Comments) -> Vec<Comment> {
+  let src: &str = &file.src;
+  let mut directives: Vec<Comment> = Vec::new();
+  for (offset, _) in src.match_indices(IGNORE_DIRECTIVE) {
+    let Some((lo, hi)) = comment_around(src, offset) else {
+      continue;
+    };
+    let pos = |offset: usize| file.start_pos + BytePos(offset as u32);
+    if directives.iter().any(|comment| comment.span.lo == pos(lo)) {
+      continue;
+    }
+    let next_token = pos(next_token_start(src, hi));
+    let previous_token = pos(previous_token_end(src, lo));
+    let comment = find_directive(comments.get_leading(next_token), pos(lo))
+      .or_else(|| find_directive(comments.get_trailing(previous_token), pos(lo)));
+    directives.extend(comment);
+  }
+  directives
+}
+
+/// Byte range of the comment a directive at `offset` opens, i.e. `// gt-ignore-next-line` or
+/// `/* gt-ignore */`. None when the directive is not the first word of a comment.
+fn comment_around(src: &str, offset: usize) -> Option<(usize, usize)> {
+  let before = src[..offset].trim_end_matches([' ', '\t']);
+  let lo = before.len().checked_sub(2)?;
+  let rest = &src[offset..];
+  let hi = if before.ends_with("//") {
+    offset + rest.find(['\n', '\r']).unwrap_or(rest.len())
+  } else if before.ends_with("/*") {
+    offset + rest.find("*/")? + 2
+  } else {
+    return None;
+  };
+  Some((lo, hi))
+}
+
+/// Start of the first token at or after `pos`, skipping whitespace and comments
+fn next_token_start(src: &str, mut pos: usize) -> usize {
+  loop {
+    let rest = src[pos..].trim_start();
+    pos = src.len() - rest.len();
+    if rest.starts_with("//") {
+      pos += rest.find(['\n', '\r']).unwrap_or(rest.len());
+    } else if let Some(block) = rest.strip_prefix("/*") {
+      match block.find("*/") {
+        Some(end) => pos += end + 4,
+        None => return src.len(),
+      }
+    } else {
+      return pos;
+    }
+  }
+}
+
+/// End of the last token before `pos`, skipping whitespace and block comments
+fn previous_token_end(src: &str, pos: usize) -> usize {
+  let mut before = src[..pos].trim_end();
+  while before.ends_with("*/") {
+    match before.rfind("/*") {
+      Some(start) => before = before[..start].trim_end(),
+      None => break,
+    }
+  }
+  before.len()
+}
+/// The directive comment starting at `lo`, if any
+fn find_directive(comments: Option<Vec<Comment>>, lo: BytePos) -> Option<Comment> {
+  comments?
+    .into_iter()
+    .find(|comment| comment.span.lo == lo && is_directive(&comment.text))
+}
+
+/// Matches `gt-ignore-next-line` and `gt-ignore`, optionally followed by a reason
+pub fn is_directive(text: &str) -> bool {
+  let text = text.trim();
+  [IGNORE_NEXT_LINE_DIRECTIVE, IGNORE_DIRECTIVE]
+    .iter()
+    .any(|directive| match text.strip_prefix(directive) {
+      Some(rest) => rest.is_empty() || rest.starts_with(char::is_whitespace),
+      None => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn matches_directives() {
+    assert!(is_directive(" gt-ignore-next-line"));
+    assert!(is_directive(" gt-ignore "));
+    assert!(is_directive("gt-ignore legacy copy, see #123"));
+    assert!(!is_directive("gt-ignored"));
+    assert!(!is_directive("gt-ignore-this"));
+    assert!(!is_directive("eslint-disable-next-line"));
+  }
+
+  #[test]
+  fn finds_comment_bounds() {
+    let src = "a; // gt-ignore-next-line\nb({/* gt-ignore */}); x = '/ gt-ignore';";
+
+    assert_eq!(comment_around(src, 6), Some((3, 25)));
+    assert_eq!(comment_around(src, 32), Some((29, 44)));
+    assert_eq!(comment_around(src, src.rfind("gt-ignore").unwrap()), None);
+    assert_eq!(next_token_start(src, 25), 26);
+    assert_eq!(previous_token_end(src, 44), 29);
+    assert_eq!(previous_token_end(src, 3), 2);
+  }
+
+  #[test]
+  fn suppresses_spans_inside_target() {
+    let mut tracker = SuppressionTracker::default();
+    let target = Target::Node(Span::new(BytePos(10), BytePos(20)));
+    tracker.register(Span::new(BytePos(1), BytePos(5)), target);
+
+    assert!(!tracker.suppress(Span::new(BytePos(21), BytePos(25))));
+    assert_eq!(tracker.unused().len(), 1);
+    assert!(tracker.suppress(Span::new(BytePos(12), BytePos(15))));
+    assert!(tracker.unused().is_empty());
+  }
+
+  #[test]
+  fn keeps_first_registration_per_comment() {
+    let mut tracker = SuppressionTracker::default();
+    let comment = Span::new(BytePos(1), BytePos(5));
+    tracker.register(comment, Target::Node(Span::new(BytePos(10), BytePos(40))));
+    tracker.register(comment, Target::Node(Span::new(BytePos(10), BytePos(20))));
+
+    assert!(tracker.suppress(Span::new(BytePos(30), BytePos(35))));
+  }
+
+  #[test]
+  fn marks_the_innermost_covering_suppression() {
+    let mut tracker = SuppressionTracker::default();
+    let outer = Span::new(BytePos(1), BytePos(5));
+    tracker.register(outer, Target::Node(Span::new(BytePos(10), BytePos(40))));
+    let inner = Target::Node(Span::new(BytePos(20), BytePos(30)));
+    tracker.register(Span::new(BytePos(12), BytePos(16)), inner);
+
+    assert!(tracker.suppress(Span::new(BytePos(22), BytePos(25))));
+    assert_eq!(tracker.unused(), vec![outer]);
+  }
+
+  mod suppression_comments {
+    use crate::test_utils::{config, transform_with_diagnostics};
+
+    #[test]
+    fn ignore_next_line_suppresses_call_violation() {
+      let source = r#"
+        import { useGT } from 'gt-next';
+        const t = useGT();
+        // gt-ignore-next-line
+        t('Hi ' + name);
+        t('Bye ' + name);
+      "#;
+      let (_, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+      assert_eq!(diagnostics.len(), 1);
+      assert_eq!(diagnostics[0].line, 6);
+    }
+
+    #[test]
+    fn ignore_next_line_only_covers_the_following_line() {
+      let source = r#"
+        import { useGT } from 'gt-next';
+        // gt-ignore-next-line
+        function Greeting({ name }) { const t = useGT(); t('Hi ' + name);
+          return t('Bye ' + name);
+        }
+      "#;
+      let (_, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+      assert_eq!(diagnostics.len(), 1);
+      assert_eq!(diagnostics[0].line, 5);
+    }
+
+    #[test]
+    fn ignore_next_line_inside_call_arguments() {
+      let source = r#"
+        import { useGT } from 'gt-next';
+        const t = useGT();
+        console.log(
+          // gt-ignore-next-line
+          t('Hi ' + name),
+          t('Bye ' + name),
+        );
+        const labels = {
+          // gt-ignore-next-line
+          greeting: t('Hey ' + name),
+        };
+      "#;
+      let (_, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+      assert_eq!(diagnostics.len(), 1);
+      assert_eq!(diagnostics[0].line, 7);
+    }
+
+    #[test]
+    fn directives_inside_strings_are_not_suppressions() {
+      let source = r#"
+        import { useGT } from 'gt-next';
+        const t = useGT();
+        const note = "// gt-ignore-next-line";
+        t('Hi ' + name);
+      "#;
+      let (_, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+      assert_eq!(diagnostics.len(), 1);
+      assert_eq!(diagnostics[0].code.as_deref(), Some("GT002"));
+    }
+
+    #[test]
+    fn nested_suppressions_mark_the_innermost_used() {
+      let source = r#"
+        import { T } from 'gt-next';
+        // gt-ignore-next-line
+        const el = <T>Hello {/* gt-ignore */}{user.name}</T>;
+      "#;
+      let (_, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+      assert_eq!(diagnostics.len(), 1);
+      assert_eq!(diagnostics[0].code.as_deref(), Some("GT004"));
+      assert_eq!(diagnostics[0].line, 3);
+    }
+
+    #[test]
+    fn jsx_ignore_suppresses_following_child() {
+      let source = r#"
+        import { T } from 'gt-next';
+        const el = <T>Hello {/* gt-ignore */}{user.name}, {other}</T>;
+      "#;
+      let (_, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+      assert_eq!(diagnostics.len(), 1);
+      assert!(diagnostics[0].is_error());
+      assert_eq!(diagnostics[0].col, 60);
+    }
+
+    #[test]
+    fn jsx_ignore_inside_container_suppresses_it() {
+      let source = r#"
+        import { T } from 'gt-next';
+        const el = <T>Hello {/* gt-ignore */ user.name}</T>;
+      "#;
+      let (_, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+      assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn reports_unused_suppressions_as_warnings() {
+      let source = r#"
+        import { T } from 'gt-next';
+        // gt-ignore-next-line
+        const el = <T>Hello world</T>;
+      "#;
+      let (_, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+      assert_eq!(diagnostics.len(), 1);
+      assert_eq!(diagnostics[0].code.as_deref(), Some("GT004"));
+      assert!(!diagnostics[0].is_error());
+      assert_eq!(diagnostics[0].line, 3);
+    }
+
+    #[test]
+    fn ignores_suppressions_when_checks_disabled() {
+      let source = r#"
+        import { T } from 'gt-next';
+        // gt-ignore-next-line
+        const el = <T>Hello world</T>;
+      "#;
+      let (_, diagnostics) = transform_with_diagnostics(source, &config(true, true));
+
+      assert!(diagnostics.is_empty());
+    }
+  }
+}
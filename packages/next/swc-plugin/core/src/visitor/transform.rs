@@ -1,19 +1,48 @@
-use super::state::{ImportTracker, Statistics, TraversalState};
-use crate::ast::{JsxTraversal, StringCollector};
-use crate::config::PluginSettings;
+use super::state::{DictionaryKeyUsage, ImportTracker, Statistics, TraversalState};
+use crate::ast::{constants::PLURAL_FORMS, JsxTraversal, StringCollector};
+use crate::config::{ImportSourcePreset, PluginSettings, RuleSeverity};
+use crate::dictionary::DictionaryEntry;
+use crate::hash::SanitizedChild;
+use crate::icu::PLURAL_CATEGORIES;
 use crate::logging::{LogLevel, Logger};
-use crate::visitor::errors::create_dynamic_function_warning;
+use crate::manifest::{Manifest, ManifestEntry};
+use crate::message_format::MessageFormat;
+use crate::plurals::{
+  cardinal_categories, is_cldr_category, missing_categories, unreachable_branches,
+};
+use crate::visitor::dataflow::TranslatorDataflow;
+use crate::visitor::errors::{
+  create_dictionary_namespace_key_warning, create_dynamic_dictionary_key_warning,
+  create_dynamic_function_warning, create_empty_branch_warning,
+  create_ignored_branch_children_warning, create_invalid_derive_warning,
+  create_invalid_message_syntax_warning, create_missing_branch_prop_warning,
+  create_missing_dictionary_key_warning, create_missing_icu_variable_warning,
+  create_missing_plural_branch_warning, create_missing_plural_count_warning,
+  create_unmatched_branch_key_warning, create_unreachable_plural_branch_warning,
+  create_unsupported_format_warning, create_unused_icu_variable_warning,
+  create_unused_suppression_warning, DiagnosticCode, Finding, GtDiagnostic,
+};
 use crate::visitor::expr_utils::{
-  create_spread_options_call_expr, create_string_prop, extract_id_and_context_from_options, extract_string_from_expr, has_prop, inject_new_args, validate_derive,
+  build_icu_template_message, build_tagged_template_source, create_spread_options,
+  create_string_prop, declare_var_source, extract_declare_var_name,
+  extract_id_and_context_from_options, extract_static_string, extract_string_from_expr,
+  get_callee_expr_function_name, get_required_module, has_prop, literal_char_span, option_value,
+  option_variables, validate_declare_var, validate_derive,
 };
+use crate::visitor::jsx_utils::{
+  extract_attribute_from_jsx_attr, extract_branch_key_from_jsx_attr, has_unwrapped_dynamic_content,
+  has_visible_children, wrap_unwrapped_dynamic_content,
+};
+use crate::visitor::suppression::SuppressionTracker;
 use swc_core::{
-  common::SyntaxContext,
+  common::{Span, Spanned, SyntaxContext, DUMMY_SP},
   ecma::{ast::*, atoms::Atom},
 };
 
 use crate::visitor::analysis::{
-  is_branch_name, is_derive_name, is_translation_component_name,
-  is_translation_function_name, is_variable_component_name,
+  is_branch_name, is_declare_var_name, is_derive_name, is_dictionary_function_name,
+  is_message_function_name, is_messages_function_name, is_module_translation_function_name,
+  is_translation_component_name, is_translation_function_name, is_variable_component_name,
 };
 
 /// Main transformation visitor for the SWC plugin
@@ -28,13 +57,57 @@ pub struct TransformVisitor {
   pub settings: PluginSettings,
   /// Logger
   pub logger: Logger,
-  /// String collector for two-pass transformation
+  /// String collector for the content arrays of useGT()/getGT() calls
   pub string_collector: StringCollector,
+  /// Suppression comments (gt-ignore) found during traversal
+  pub suppressions: SuppressionTracker,
+  /// Translators that flow through local hooks and helpers
+  pub dataflow: TranslatorDataflow,
+  /// Hashed entries for the extraction manifest, only set when one is collected
+  pub manifest: Option<Manifest>,
+  /// Diagnostics reported so far
+  pub findings: Vec<Finding>,
+}
+
+/// A callee resolved to a GT export or translator
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedCallee {
+  /// Name as written, for diagnostics: `t`, `GT.useGT`
+  pub function_name: Atom,
+  /// The GT export or translator callback it refers to: `useGT`, `useGT_callback`
+  pub original_name: Atom,
+  /// Identifier of the translator, 0 for exports
+  pub identifier: u32,
+}
+
+/// Local name of the `Var` import added for auto-wrapped content
+pub const AUTO_WRAP_VAR_NAME: &str = "GTVar";
+
+/// Check if an export of a module with the given preset is one the plugin tracks
+fn is_tracked_export(name: &Atom, preset: ImportSourcePreset) -> bool {
+  is_translation_component_name(name)
+    || is_variable_component_name(name)
+    || is_branch_name(name)
+    || is_translation_function_name(name)
+    || is_message_function_name(name)
+    || is_dictionary_function_name(name)
+    || is_messages_function_name(name)
+    || (preset.exports_module_translation_function() && is_module_translation_function_name(name))
+    || is_derive_name(name)
+    || is_declare_var_name(name)
 }
 
 impl Default for TransformVisitor {
   fn default() -> Self {
-    Self::new(LogLevel::Warn, false, None, false, false, false, StringCollector::new())
+    Self::new(
+      LogLevel::Warn,
+      false,
+      None,
+      false,
+      false,
+      false,
+      StringCollector::new(),
+    )
   }
 }
 
@@ -46,171 +119,474 @@ impl TransformVisitor {
     disable_build_checks: bool,
     autoderive_jsx: bool,
     autoderive_strings: bool,
-    mut string_collector: StringCollector,
+    string_collector: StringCollector,
   ) -> Self {
+    Self::with_settings(
+      PluginSettings::new(
+        log_level,
+        compile_time_hash,
+        filename,
+        disable_build_checks,
+        autoderive_jsx,
+        autoderive_strings,
+      ),
+      string_collector,
+    )
+  }
+
+  /// Create a visitor from fully resolved settings
+  pub fn with_settings(settings: PluginSettings, mut string_collector: StringCollector) -> Self {
     // Reset the counter to 0
     string_collector.reset_counter();
     Self {
       traversal_state: TraversalState::default(),
       statistics: Statistics::default(),
       import_tracker: ImportTracker::new(),
-      settings: PluginSettings::new(log_level.clone(), compile_time_hash, filename.clone(), disable_build_checks, autoderive_jsx, autoderive_strings),
-      logger: Logger::new(log_level),
+      logger: Logger::new(settings.log_level.clone()),
+      settings,
       string_collector,
+      suppressions: SuppressionTracker::default(),
+      dataflow: TranslatorDataflow::default(),
+      manifest: None,
+      findings: Vec::new(),
     }
   }
 
-  /// Single helper method - handles scope enter/exit only
-  pub fn with_scope<T, F>(&mut self, operation: F) -> T
-  where
-    F: FnOnce(&mut Self) -> T,
-  {
-    self.import_tracker.scope_tracker.enter_scope();
-    let result = operation(self);
-    self.import_tracker.scope_tracker.exit_scope();
-    result
+  /// Inject the hash attribute on a translation component, unless it already has one
+  pub fn inject_hash_attributes(&mut self, element: &mut JSXElement) {
+    if TransformVisitor::determine_has_hash_attr(element) {
+      return;
+    }
+    // Calculate real hash using AST traversal, before the children are transformed
+    let (hash_value, json_string) = JsxTraversal::new(self).calculate_element_hash(element);
+    let id = extract_attribute_from_jsx_attr(element, "id")
+      .or_else(|| extract_attribute_from_jsx_attr(element, "$id"));
+    self.record_manifest_entry(&hash_value, Some(json_string), id, element.span);
+    let hash_attr = TransformVisitor::create_attr(element, &hash_value, "_hash");
+    element.opening.attrs.push(hash_attr);
   }
 
-  /// Inject hash attributes on translation components
-  pub fn inject_hash_attributes(&mut self, mut element: JSXElement) -> JSXElement {
-    // Check if hash attribute already exists
-    let has_hash_attr = TransformVisitor::determine_has_hash_attr(&element);
-
-    if !has_hash_attr {
-      // Get the hash from the aggregator
-      let counter_id = self.string_collector.increment_counter();
-      let translation_jsx = self.string_collector.get_translation_jsx(counter_id);
+  /// Wrap the dynamic content of a translation component in `<Var>` (autoWrapVar), before its
+  /// hash is computed: `<T>Hello {user.name}</T>` -> `<T>Hello <Var>{user.name}</Var></T>`.
+  /// Content is left for the GT001 check when `Var` cannot be referenced.
+  pub fn wrap_dynamic_content(&mut self, element: &mut JSXElement) {
+    if !has_unwrapped_dynamic_content(&element.children) {
+      return;
+    }
+    if let Some(var_name) = self.variable_component_name(&element.opening.name) {
+      wrap_unwrapped_dynamic_content(&mut element.children, &var_name);
+    }
+  }
 
-      // Inject hash
-      if let Some(translation_jsx) = translation_jsx {
-        let hash_value = translation_jsx.hash.clone();
-        let hash_attr = TransformVisitor::create_attr(&element, &hash_value, "_hash");
-        element.opening.attrs.push(hash_attr);
+  /// How to reference `Var` next to a translation component: the local name it is imported as,
+  /// `GT.Var` for namespace components, or a generated import from the component's module
+  fn variable_component_name(&mut self, component: &JSXElementName) -> Option<JSXElementName> {
+    match component {
+      JSXElementName::Ident(ident) => {
+        if let Some((sym, ctxt)) = self
+          .import_tracker
+          .bindings
+          .find_in_scope("Var", ident.ctxt)
+        {
+          return Some(JSXElementName::Ident(Ident::new(
+            sym.clone(),
+            DUMMY_SP,
+            *ctxt,
+          )));
+        }
+        // Generated imports need an ES module and the module the component came from
+        if !self.traversal_state.in_module {
+          return None;
+        }
+        let module = self
+          .import_tracker
+          .import_modules
+          .get(&ident.to_id())?
+          .clone();
+        let local = Ident::new_no_ctxt(AUTO_WRAP_VAR_NAME.into(), DUMMY_SP);
+        self
+          .import_tracker
+          .bindings
+          .track_translation_variable(local.to_id(), "Var".into(), 0);
+        self.import_tracker.auto_var_import.get_or_insert(module);
+        Some(JSXElementName::Ident(local))
+      }
+      JSXElementName::JSXMemberExpr(member_expr) => {
+        Some(JSXElementName::JSXMemberExpr(JSXMemberExpr {
+          span: DUMMY_SP,
+          obj: member_expr.obj.clone(),
+          prop: IdentName::new("Var".into(), DUMMY_SP),
+        }))
       }
+      JSXElementName::JSXNamespacedName(_) => None,
     }
-
-    element
   }
 
-  /// Inject content array on translation function call
-  pub fn inject_content_array_on_translation_function_call(
+  /// Track t() function calls: the message is added to its translator's content array.
+  /// Returns the hash to inject, if the call can be hashed at compile time.
+  pub fn track_translation_callback(
     &mut self,
     call_expr: &CallExpr,
-  ) -> Option<CallExpr> {
-    let counter_id = self.string_collector.get_counter();
-    if let Some(content) = self.string_collector.get_translation_data(counter_id) {
-      // Create the content array
-      let content_array = self
-        .string_collector
-        .create_content_array(&content.content, call_expr.span);
+    string: &ExprOrSpread,
+    identifier: u32,
+  ) -> Option<String> {
+    // Get the options
+    let options = call_expr.args.get(1);
+
+    // Get context and id
+    let (id, context, max_chars, format, has_derive_context) =
+      extract_id_and_context_from_options(options);
+
+    // Calculate hash for the call expression
+    let (hash, json_string) = self.calculate_hash_for_call_expr(string, options);
+
+    let mut recorded_hash = None;
+    if let Some((message, _)) = self.message_source(string.expr.as_ref()) {
+      // If context contains derive(), skip hashing (empty hash) — CLI handles resolution
+      let hash = if has_derive_context {
+        Some(String::new())
+      } else {
+        hash
+      };
+      if let Some(hash) = hash {
+        self.record_manifest_entry(&hash, json_string, id.clone(), call_expr.span);
 
-      // Check for existing content
-      if call_expr.args.is_empty() {
-        return Some(inject_new_args(call_expr, content_array.clone()));
+        // Construct the translation content object
+        let translation_content = StringCollector::create_translation_content(
+          message,
+          hash.clone(),
+          id,
+          context,
+          max_chars,
+          format,
+        );
+
+        // Add the translation content to the string collector, unless the translator has no
+        // content array: helper parameters and custom hooks
+        if identifier != StringCollector::NO_AGGREGATOR {
+          self
+            .string_collector
+            .set_translation_content(identifier, translation_content);
+        }
+
+        recorded_hash = Some(hash);
       }
     }
-    None
+    recorded_hash
   }
 
-  pub fn inject_hash_attributes_on_translation_function_call(
+  /// Hash calls that have no useGT()/getGT() content array: msg() and gt-i18n's t()
+  pub fn track_standalone_call(
     &mut self,
     call_expr: &CallExpr,
-  ) -> Option<CallExpr> {
-    if let Some(_string) = call_expr.args.first() {
-      // Get the options
-      let options = call_expr.args.get(1);
-
-      // Get the hash from the t() aggregator
-      let counter_id = self.string_collector.increment_counter();
-      let translation_hash = self.string_collector.get_translation_hash(counter_id);
-
-      if let Some(translation_hash) = translation_hash {
-        // Inject hash attribute on the call expression
-        let modified_call_expr = self.inject_hash_attribute_on_call_expr(
-          call_expr,
-          options,
-          translation_hash.hash.clone(),
-          None,
-        );
+    string: &ExprOrSpread,
+  ) -> Option<String> {
+    // Calculate hash for the call expression (empty when the context uses derive())
+    let options = call_expr.args.get(1);
+    let (hash, json_string) = self.calculate_hash_for_call_expr(string, options);
+    if let Some(hash) = &hash {
+      let (id, ..) = extract_id_and_context_from_options(options);
+      self.record_manifest_entry(hash, json_string, id, call_expr.span);
+    }
+    hash
+  }
 
-        return Some(modified_call_expr);
+  /// Check and extract each entry of `msg(['Hello, Alice!', 'Hello, Bob!'])`. The runtime hashes
+  /// every entry itself with the shared options, so no hash is injected, and an `$id` becomes
+  /// `${id}.${index}` per entry.
+  pub fn track_message_array(
+    &mut self,
+    call_expr: &CallExpr,
+    array: &ArrayLit,
+    function_name: &str,
+  ) {
+    let options = call_expr.args.get(1);
+    let (id, ..) = extract_id_and_context_from_options(options);
+    for (index, entry) in array.elems.iter().enumerate() {
+      let Some(entry) = entry else {
+        continue;
+      };
+      self.check_call_expr_for_violations(entry, function_name);
+      self.check_message_format(entry, options, function_name);
+
+      if self.settings.compile_time_hash {
+        if let (Some(hash), json_string) = self.calculate_hash_for_call_expr(entry, options) {
+          let id = id
+            .as_ref()
+            .filter(|id| !id.is_empty())
+            .map(|id| format!("{id}.{index}"));
+          self.record_manifest_entry(&hash, json_string, id, entry.expr.span());
+        }
       }
     }
-    None
   }
 
-  pub fn track_hash_attributes(&mut self, element: &mut JSXElement) {
-    // Traversal
-    let mut traversal = JsxTraversal::new(self);
+  /// Record a hashed entry for the extraction manifest, when one is being collected. Entries
+  /// left to the CLI (empty hashes) are skipped.
+  fn record_manifest_entry(
+    &mut self,
+    hash: &str,
+    json_string: Option<String>,
+    id: Option<String>,
+    span: Span,
+  ) {
+    let Some(manifest) = &mut self.manifest else {
+      return;
+    };
+    let Some(json_string) = json_string.filter(|_| !hash.is_empty()) else {
+      return;
+    };
+    if let Some(entry) = ManifestEntry::new(hash.to_string(), &json_string, id, span) {
+      manifest.record(entry);
+    }
+  }
 
-    // Check if hash attribute already exists
-    let has_hash_attr = TransformVisitor::determine_has_hash_attr(element);
+  /// Track a key passed to a useTranslations()/getTranslations() translator: t('nav.home')
+  pub fn track_dictionary_key(&mut self, key: &ExprOrSpread, identifier: u32, function_name: &str) {
+    let literal = extract_static_string(&key.expr);
+    if literal.is_none() {
+      let diagnostic = create_dynamic_dictionary_key_warning(function_name, key.expr.span());
+      self.report_diagnostic(diagnostic);
+    }
 
-    if !has_hash_attr {
-      // Calculate real hash using AST traversal
-      let (hash_value, _) = traversal.calculate_element_hash(element);
+    let namespace = self
+      .import_tracker
+      .dictionary_namespaces
+      .get(identifier as usize)
+      .cloned()
+      .flatten();
+    let key_path = match (namespace, literal) {
+      (Some(namespace), Some(key)) if !namespace.is_empty() => Some(format!("{namespace}.{key}")),
+      (Some(_), Some(key)) => Some(key),
+      _ => None,
+    };
 
-      // Store the t() function call
-      let counter_id = self.string_collector.increment_counter();
-      self.string_collector.initialize_aggregator(counter_id);
+    // Validate the key against the dictionary
+    if let (Some(dictionary), Some(key_path)) = (&self.settings.dictionary, &key_path) {
+      let diagnostic = match dictionary.get(key_path) {
+        Some(DictionaryEntry::Leaf) => None,
+        Some(DictionaryEntry::Namespace) => Some(create_dictionary_namespace_key_warning(
+          function_name,
+          key_path,
+          key.expr.span(),
+        )),
+        None => Some(create_missing_dictionary_key_warning(
+          function_name,
+          key_path,
+          key.expr.span(),
+        )),
+      };
+      if let Some(diagnostic) = diagnostic {
+        self.report_diagnostic(diagnostic);
+      }
+    }
 
-      // Add the message to the string collector for the t() function
-      self.string_collector.set_translation_jsx(
-        counter_id,
-        StringCollector::create_translation_jsx(hash_value),
-      );
+    self.statistics.dictionary_keys.push(DictionaryKeyUsage {
+      key: key_path,
+      span: key.expr.span(),
+    });
+  }
+
+  /// Check if a binding resolves to gt-i18n's module-level t
+  pub fn is_module_translation_function(&self, id: &Id) -> bool {
+    self
+      .import_tracker
+      .bindings
+      .get_translation_variable(id)
+      .is_some_and(|variable| is_module_translation_function_name(&variable.original_name))
+  }
+
+  /// Source of a t`...` tagged template, or None when it can't be hashed at compile time
+  /// (derive() interpolations are resolved by the CLI)
+  pub fn tagged_template_source(&self, tpl: &Tpl) -> Option<String> {
+    if tpl.exprs.iter().any(|expr| self.is_derive_call(expr)) {
+      return None;
     }
+    build_tagged_template_source(tpl)
   }
 
-  /// Track t() function calls
-  pub fn track_translation_callback(
-    &mut self,
-    call_expr: &CallExpr,
-    string: &ExprOrSpread,
-    identifier: u32,
-  ) {
-    // Get the options
-    let options = call_expr.args.get(1);
+  /// Check the derive() interpolations of a t`...` tagged template. Other interpolations are
+  /// runtime variables and always allowed.
+  pub fn check_tagged_template_for_violations(&mut self, tpl: &Tpl, function_name: &str) {
+    for expr in &tpl.exprs {
+      if let Expr::Call(call_expr) = expr.as_ref() {
+        if self.is_derive_call(expr) {
+          let mut errors = Vec::new();
+          validate_derive(call_expr, &mut errors);
+          for error in errors {
+            let diagnostic = create_invalid_derive_warning(function_name, &error, call_expr.span);
+            self.report_diagnostic(diagnostic);
+          }
+        }
+      }
+    }
+  }
 
-    // Get context and id
-    let (id, context, max_chars, _format, has_derive_context) = extract_id_and_context_from_options(options);
+  /// Rewrite an interpolated template literal message into an ICU message and its variables
+  /// (autoIcuTemplates), so it can be hashed like any other string:
+  /// t(`Hello ${name}`) -> t("Hello {name}", { name }). Templates with derive() or declareVar()
+  /// calls, non-ICU formats or options that can't be merged are left for the build checks.
+  pub fn rewrite_template_message(&self, call_expr: &mut CallExpr) {
+    let Some(ExprOrSpread { spread: None, expr }) = call_expr.args.first() else {
+      return;
+    };
+    let Expr::Tpl(tpl) = expr.as_ref() else {
+      return;
+    };
+    if tpl.exprs.is_empty()
+      || tpl
+        .exprs
+        .iter()
+        .any(|expr| self.is_derive_call(expr) || self.is_declare_var_call(expr))
+    {
+      return;
+    }
+    let options = call_expr.args.get(1);
+    let (_, _, _, format, _) = extract_id_and_context_from_options(options);
+    if format.is_some_and(|format| format != "ICU") {
+      return;
+    }
+    let existing = match options {
+      None => &[][..],
+      Some(ExprOrSpread { spread: None, expr }) => match expr.as_ref() {
+        Expr::Object(object) => &object.props[..],
+        _ => return,
+      },
+      Some(_) => return,
+    };
+    let Some((message, mut variables)) = build_icu_template_message(tpl, existing) else {
+      return;
+    };
 
-    // Calculate hash for the call expression
-    let (hash, _) = self.calculate_hash_for_call_expr(string, options);
+    let span = tpl.span;
+    variables.extend(existing.iter().cloned());
+    *call_expr.args[0].expr = Expr::Lit(Lit::Str(Str {
+      span,
+      value: message.into(),
+      raw: None,
+    }));
+    let options = Box::new(Expr::Object(ObjectLit {
+      span,
+      props: variables,
+    }));
+    match call_expr.args.get_mut(1) {
+      Some(existing) => existing.expr = options,
+      None => call_expr.args.push(ExprOrSpread {
+        spread: None,
+        expr: options,
+      }),
+    }
+  }
 
-    if let Some(message) = extract_string_from_expr(string.expr.as_ref()) {
-      // If context contains derive(), skip hashing (empty hash) — CLI handles resolution
-      let hash = if has_derive_context { Some(String::new()) } else { hash };
-      if let Some(hash) = hash {
-        // Construct the translation content object
-        let translation_content =
-          StringCollector::create_translation_content(message, hash.clone(), id, context, max_chars);
+  /// Record a t`...` tagged template for the extraction manifest, hashed as the positional
+  /// message the runtime falls back to: t`Hello ${name}` as "Hello {0}"
+  pub fn track_tagged_template(&mut self, tagged_tpl: &TaggedTpl) {
+    let Some(source) = self.tagged_template_source(&tagged_tpl.tpl) else {
+      return;
+    };
+    let string = ExprOrSpread {
+      spread: None,
+      expr: Box::new(Expr::Lit(Lit::Str(Str {
+        span: tagged_tpl.tpl.span,
+        value: source.as_str().into(),
+        raw: None,
+      }))),
+    };
+    if let (Some(hash), json_string) = self.calculate_hash_for_call_expr(&string, None) {
+      self.record_manifest_entry(&hash, json_string, None, tagged_tpl.span);
+    }
+  }
 
-        // Add the translation content to the string collector
-        self
-          .string_collector
-          .set_translation_content(identifier, translation_content);
+  /// Source of a t()/msg() message as the runtime hashes it, and how many declareVar() calls
+  /// were folded into it. None when it can't be known at compile time.
+  pub fn message_source(&self, expr: &Expr) -> Option<(String, usize)> {
+    if let Some(string) = extract_string_from_expr(expr) {
+      return Some((string, 0));
+    }
+    let mut source = String::new();
+    let mut declared_vars = 0;
+    self.append_message_source(expr, &mut source, &mut declared_vars)?;
+    Some((source, declared_vars))
+  }
 
-        // Store the t() function call
-        let counter_id = self.string_collector.increment_counter();
-        self.string_collector.initialize_aggregator(counter_id);
+  /// Append the static parts of a string literal, template literal or concatenation,
+  /// encoding declareVar() calls the way the runtime does
+  fn append_message_source(
+    &self,
+    expr: &Expr,
+    source: &mut String,
+    declared_vars: &mut usize,
+  ) -> Option<()> {
+    match expr {
+      Expr::Lit(Lit::Str(str_lit)) => source.push_str(&str_lit.value.to_string_lossy()),
+      Expr::Tpl(tpl) => {
+        for (index, quasi) in tpl.quasis.iter().enumerate() {
+          source.push_str(&quasi.cooked.as_ref()?.to_string_lossy());
+          if let Some(expr) = tpl.exprs.get(index) {
+            self.append_message_source(expr, source, declared_vars)?;
+          }
+        }
+      }
+      Expr::Bin(BinExpr {
+        op: BinaryOp::Add,
+        left,
+        right,
+        ..
+      }) => {
+        self.append_message_source(left, source, declared_vars)?;
+        self.append_message_source(right, source, declared_vars)?;
+      }
+      Expr::Paren(paren_expr) => {
+        self.append_message_source(&paren_expr.expr, source, declared_vars)?
+      }
+      Expr::Call(call_expr) => {
+        let is_declare_var = self
+          .resolve_call(call_expr)
+          .is_some_and(|callee| is_declare_var_name(&callee.original_name));
+        if !is_declare_var || call_expr.args.is_empty() {
+          return None;
+        }
+        let name = match call_expr.args.get(1) {
+          Some(options) => extract_declare_var_name(&options.expr)?,
+          None => None,
+        };
+        *declared_vars += 1;
+        source.push_str(&declare_var_source(*declared_vars, name.as_deref()));
+      }
+      _ => return None,
+    }
+    Some(())
+  }
 
-        // Add the message to the string collector for the t() function
-        self
-          .string_collector
-          .set_translation_hash(counter_id, StringCollector::create_translation_hash(hash));
+  /// GT export a JSX element name resolves to: the original name of an imported component
+  /// (`Branch` for `<Choice>` after `import { Branch as Choice }`) or the member of a GT
+  /// namespace (`Branch` for `<GT.Branch>`)
+  pub fn resolve_component(&self, name: &JSXElementName) -> Option<Atom> {
+    match name {
+      JSXElementName::Ident(ident) => self
+        .import_tracker
+        .bindings
+        .get_translation_variable(&ident.to_id())
+        .map(|binding| binding.original_name.clone()),
+      JSXElementName::JSXMemberExpr(JSXMemberExpr {
+        obj: JSXObject::Ident(namespace),
+        prop,
+        ..
+      }) if self
+        .import_tracker
+        .namespace_imports
+        .contains_key(&namespace.to_id()) =>
+      {
+        Some(prop.sym.clone())
       }
+      _ => None,
     }
   }
 
   /// Check if we should track this component based on imports or known components
-  pub fn should_track_component_as_translation(&self, name: &Atom) -> bool {
+  pub fn should_track_component_as_translation(&self, id: &Id) -> bool {
     // // Direct imports from gt-next - includes T components
-    if let Some(translation_variable) = self
-      .import_tracker
-      .scope_tracker
-      .get_translation_variable(name)
-    {
+    if let Some(translation_variable) = self.import_tracker.bindings.get_translation_variable(id) {
       if is_translation_component_name(&translation_variable.original_name) {
         return true;
       }
@@ -219,9 +595,9 @@ impl TransformVisitor {
   }
 
   /// Check if we should track this component as a variable component
-  pub fn should_track_component_as_variable(&self, name: &Atom) -> bool {
+  pub fn should_track_component_as_variable(&self, id: &Id) -> bool {
     // Direct imports from gt-next
-    if let Some(variable) = self.import_tracker.scope_tracker.get_variable(name) {
+    if let Some(variable) = self.import_tracker.bindings.get_translation_variable(id) {
       if is_variable_component_name(&variable.original_name) {
         return true;
       }
@@ -230,9 +606,9 @@ impl TransformVisitor {
   }
 
   /// Check if we should track this component as a branch component
-  pub fn should_track_component_as_branch(&self, name: &Atom) -> bool {
+  pub fn should_track_component_as_branch(&self, id: &Id) -> bool {
     // Branch and Plural components components
-    if let Some(branch_variable) = self.import_tracker.scope_tracker.get_variable(name) {
+    if let Some(branch_variable) = self.import_tracker.bindings.get_translation_variable(id) {
       if is_branch_name(&branch_variable.original_name) {
         return true;
       }
@@ -240,21 +616,99 @@ impl TransformVisitor {
     false
   }
 
-  /// Check if a name is derive or an alias of it
-  pub fn is_derive(&self, name: &Atom) -> bool {
-    if let Some(variable) = self
-      .import_tracker
-      .scope_tracker
-      .get_translation_variable(name)
-    {
+  /// Check if a binding is derive or an alias of it
+  pub fn is_derive(&self, id: &Id) -> bool {
+    if let Some(variable) = self.import_tracker.bindings.get_translation_variable(id) {
       return is_derive_name(&variable.original_name);
     }
     false
   }
 
+  /// Check if a binding is declareVar or an alias of it
+  pub fn is_declare_var(&self, id: &Id) -> bool {
+    self
+      .import_tracker
+      .bindings
+      .get_translation_variable(id)
+      .is_some_and(|variable| is_declare_var_name(&variable.original_name))
+  }
+
+  /// Resolve a callee to the GT export or translator it refers to: `useGT`, an alias of it,
+  /// a translator like `t`, or a namespace member like `GT.useGT`
+  pub fn resolve_callee(&self, callee: &Expr) -> Option<ResolvedCallee> {
+    match callee {
+      Expr::Ident(ident) => {
+        let variable = self
+          .import_tracker
+          .bindings
+          .get_translation_variable(&ident.to_id())?;
+        Some(ResolvedCallee {
+          function_name: ident.sym.clone(),
+          original_name: variable.original_name.clone(),
+          identifier: variable.identifier,
+        })
+      }
+      Expr::Member(MemberExpr { obj, prop, .. }) => {
+        let Expr::Ident(namespace) = obj.as_ref() else {
+          return None;
+        };
+        let preset = *self
+          .import_tracker
+          .namespace_imports
+          .get(&namespace.to_id())?;
+        let export = match prop {
+          MemberProp::Ident(ident) => ident.sym.clone(),
+          MemberProp::Computed(ComputedPropName { expr, .. }) => {
+            Atom::new(extract_static_string(expr)?)
+          }
+          MemberProp::PrivateName(_) => return None,
+        };
+        if !is_tracked_export(&export, preset) {
+          return None;
+        }
+        Some(ResolvedCallee {
+          function_name: format!("{}.{}", namespace.sym, export).into(),
+          original_name: export,
+          identifier: 0,
+        })
+      }
+      Expr::Paren(paren_expr) => self.resolve_callee(&paren_expr.expr),
+      _ => None,
+    }
+  }
+
+  /// Resolve the callee of a call expression, see [`Self::resolve_callee`]
+  pub fn resolve_call(&self, call_expr: &CallExpr) -> Option<ResolvedCallee> {
+    match &call_expr.callee {
+      Callee::Expr(callee) => self.resolve_callee(callee),
+      _ => None,
+    }
+  }
+
+  /// Check if an expression is a call to derive(), an alias or a namespace member of it
+  fn is_derive_call(&self, expr: &Expr) -> bool {
+    matches!(expr, Expr::Call(call_expr) if self
+      .resolve_call(call_expr)
+      .is_some_and(|callee| is_derive_name(&callee.original_name)))
+  }
+
+  /// Check if an expression is a call to declareVar(), an alias or a namespace member of it
+  fn is_declare_var_call(&self, expr: &Expr) -> bool {
+    matches!(expr, Expr::Call(call_expr) if self
+      .resolve_call(call_expr)
+      .is_some_and(|callee| is_declare_var_name(&callee.original_name)))
+  }
+
+  /// Check if a tagged template's tag is gt-i18n's module-level t
+  pub fn is_module_translation_tag(&self, tag: &Expr) -> bool {
+    self
+      .resolve_callee(tag)
+      .is_some_and(|callee| is_module_translation_function_name(&callee.original_name))
+  }
+
   /// Check if we should track a namespace component (GT.T, GT.Var, etc.)
-  pub fn should_track_namespace_component(&self, obj: &Atom, prop: &Atom) -> (bool, bool, bool) {
-    if self.import_tracker.namespace_imports.contains(obj) {
+  pub fn should_track_namespace_component(&self, obj: &Id, prop: &Atom) -> (bool, bool, bool) {
+    if self.import_tracker.namespace_imports.contains_key(obj) {
       let is_translation = is_translation_component_name(prop);
       let is_variable = is_variable_component_name(prop);
       let is_branch = is_branch_name(prop);
@@ -267,51 +721,130 @@ impl TransformVisitor {
   /// Process GT-Next import declarations to track imports and aliases
   pub fn process_gt_import_declaration(&mut self, import_decl: &ImportDecl) {
     let src_value = import_decl.src.value.to_string_lossy().into_owned();
-    match src_value.as_str() {
-      "gt-next" | "gt-next/server" | "gt-i18n" => {
-        // Process named imports: import { T, Var, useGT } from 'gt-next'
-        for specifier in &import_decl.specifiers {
-          match specifier {
-            ImportSpecifier::Named(ImportNamedSpecifier {
-              local, imported, ..
-            }) => {
-              let local_name = local.sym.clone();
-              let original_name = match imported {
-                Some(ModuleExportName::Ident(ident)) => ident.sym.clone(),
-                Some(ModuleExportName::Str(str_lit)) => Atom::new(str_lit.value.to_string_lossy().into_owned()),
-                None => local_name.clone(),
-              };
+    let Some(preset) = self.settings.import_source_preset(&src_value) else {
+      return;
+    };
+    // Process named imports: import { T, Var, useGT } from 'gt-next'
+    for specifier in &import_decl.specifiers {
+      match specifier {
+        ImportSpecifier::Named(ImportNamedSpecifier {
+          local, imported, ..
+        }) => {
+          let original_name = match imported {
+            Some(ModuleExportName::Ident(ident)) => ident.sym.clone(),
+            Some(ModuleExportName::Str(str_lit)) => {
+              Atom::new(str_lit.value.to_string_lossy().into_owned())
+            }
+            None => local.sym.clone(),
+          };
+          self.track_import_binding(local.to_id(), original_name, &src_value, preset);
+        }
+        ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
+          // Handle namespace imports: import * as GT from 'gt-next'
+          self.track_namespace_import(local.to_id(), preset);
+        }
+        _ => {}
+      }
+    }
+  }
+
+  /// Track a namespace object of GT exports
+  fn track_namespace_import(&mut self, local: Id, preset: ImportSourcePreset) {
+    self.import_tracker.namespace_imports.insert(local, preset);
+  }
+
+  /// Track a binding of a GT export loaded from `module`: `local` -> `original_name`. Returns
+  /// false if the export is not one the plugin tracks.
+  fn track_import_binding(
+    &mut self,
+    local: Id,
+    original_name: Atom,
+    module: &str,
+    preset: ImportSourcePreset,
+  ) -> bool {
+    if is_tracked_export(&original_name, preset) {
+      self
+        .import_tracker
+        .import_modules
+        .insert(local.clone(), module.to_string());
+      self.import_tracker.bindings.track_translation_variable(
+        local,
+        original_name,
+        0, // We don't care about the identifier for imports
+      );
+      return true;
+    }
+    false
+  }
 
-              // Store the mapping: local_name -> original_name
-              if is_translation_component_name(&original_name)
-                || is_variable_component_name(&original_name)
-                || is_branch_name(&original_name)
-                || is_translation_function_name(&original_name)
-                || is_derive_name(&original_name)
-              {
-                self
-                  .import_tracker
-                  .scope_tracker
-                  .track_translation_variable(
-                    local_name.clone(),
-                    original_name.clone(),
-                    0, // We don't care about the identifier for imports
-                  );
+  /// Process CommonJS and dynamic imports of GT modules:
+  /// `const { T, useGT } = require('gt-next')`, `const GT = require('gt-next')` and
+  /// `const { getGT } = await import('gt-next/server')`. Returns false if the declarator
+  /// does not load a GT module.
+  pub fn process_gt_require_declaration(&mut self, var_declarator: &VarDeclarator) -> bool {
+    let Some(init_expr) = &var_declarator.init else {
+      return false;
+    };
+    let Some(src_value) = get_required_module(init_expr) else {
+      return false;
+    };
+    let Some(preset) = self.settings.import_source_preset(&src_value) else {
+      return false;
+    };
+
+    match &var_declarator.name {
+      // Namespace objects: const GT = require('gt-next')
+      Pat::Ident(BindingIdent { id, .. }) => {
+        self.track_namespace_import(id.to_id(), preset);
+      }
+      // Named bindings: const { T, useGT: useTranslate } = require('gt-next')
+      Pat::Object(object_pat) => {
+        for prop in &object_pat.props {
+          match prop {
+            ObjectPatProp::KeyValue(KeyValuePatProp { key, value }) => {
+              let original_name = match key {
+                PropName::Ident(ident) => Some(ident.sym.clone()),
+                PropName::Str(str_lit) => {
+                  Some(Atom::new(str_lit.value.to_string_lossy().into_owned()))
+                }
+                _ => None,
+              };
+              // Defaults don't change the binding: const { useGT: useT = fallback } = ...
+              let binding = match value.as_ref() {
+                Pat::Assign(AssignPat { left, .. }) => left.as_ref(),
+                pat => pat,
+              };
+              let tracked = match (original_name, binding) {
+                (Some(original_name), Pat::Ident(BindingIdent { id, .. })) => {
+                  self.track_import_binding(id.to_id(), original_name, &src_value, preset)
+                }
+                _ => false,
+              };
+              if !tracked {
+                let mut identifiers = Vec::new();
+                self.extract_identifiers_from_pattern(value, &mut identifiers);
+                for identifier in identifiers {
+                  self.import_tracker.bindings.untrack(&identifier);
+                }
               }
             }
-            ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
-              // Handle namespace imports: import * as GT from 'gt-next'
-              self
-                .import_tracker
-                .namespace_imports
-                .insert(local.sym.clone());
+            ObjectPatProp::Assign(AssignPatProp { key, .. }) => {
+              if !self.track_import_binding(key.to_id(), key.sym.clone(), &src_value, preset) {
+                self.import_tracker.bindings.untrack(&key.to_id());
+              }
+            }
+            // The rest object holds the remaining exports: const { T, ...GT } = require('gt-next')
+            ObjectPatProp::Rest(RestPat { arg, .. }) => {
+              if let Pat::Ident(BindingIdent { id, .. }) = arg.as_ref() {
+                self.track_namespace_import(id.to_id(), preset);
+              }
             }
-            _ => {}
           }
         }
       }
-      _ => {}
+      _ => return false,
     }
+    true
   }
 
   /// Check for violations in a call expression
@@ -320,108 +853,343 @@ impl TransformVisitor {
     let mut errors = Vec::new();
     self.validate_string_literal_or_derive(arg.expr.as_ref(), &mut errors);
 
-    if !errors.is_empty() {
-      if !self.settings.disable_build_checks && !self.settings.autoderive_strings {
-        self.statistics.dynamic_content_violations += 1;
-        // Use the first error message for the violation type
-        let default_error = &"invalid expression".to_string();
-        let violation_type = errors.first().unwrap_or(default_error);
-        let warning = create_dynamic_function_warning(
-          self.settings.filename.as_deref(),
+    if self.settings.disable_build_checks || self.settings.autoderive_strings {
+      return;
+    }
+
+    for (code, span, violation_type) in errors {
+      let diagnostic = match code {
+        DiagnosticCode::InvalidDerive => {
+          create_invalid_derive_warning(function_name, &violation_type, span)
+        }
+        _ => create_dynamic_function_warning(function_name, &violation_type, span),
+      };
+      self.report_diagnostic(diagnostic);
+    }
+  }
+
+  /// Check a call's `$format`, then a literal message against the syntax of its format,
+  /// pointing at the offending character, and its placeholders against the option values.
+  /// Messages built from concatenations or derive() are left to the runtime.
+  pub fn check_message_format(
+    &mut self,
+    string: &ExprOrSpread,
+    options: Option<&ExprOrSpread>,
+    function_name: &str,
+  ) {
+    let format = match option_value(options, "$format") {
+      None => MessageFormat::Icu,
+      Some(value) => {
+        let name = extract_static_string(value);
+        match name.as_deref().and_then(MessageFormat::from_name) {
+          Some(format) => format,
+          None => {
+            let diagnostic =
+              create_unsupported_format_warning(function_name, name.as_deref(), value.span());
+            self.report_diagnostic(diagnostic);
+            return;
+          }
+        }
+      }
+    };
+    let Some(message) = extract_static_string(&string.expr) else {
+      return;
+    };
+    let placeholders = match format.placeholders(&message) {
+      Ok(placeholders) => placeholders,
+      Err(error) => {
+        let span = literal_char_span(&string.expr, error.offset);
+        let diagnostic = create_invalid_message_syntax_warning(
           function_name,
-          violation_type,
+          format.syntax_name(),
+          &error.message,
+          span,
         );
-        self.logger.log_error(&warning);
+        self.report_diagnostic(diagnostic);
+        return;
       }
+    };
+
+    // A spread or non-literal options object may pass any variable
+    let Some(variables) = option_variables(options) else {
+      return;
+    };
+    let mut reported: Vec<&str> = Vec::new();
+    for (name, offset) in &placeholders {
+      if reported.contains(&name.as_str()) || variables.iter().any(|(key, _)| key == name) {
+        continue;
+      }
+      reported.push(name);
+      let span = literal_char_span(&string.expr, *offset);
+      let diagnostic = create_missing_icu_variable_warning(function_name, name, span);
+      self.report_diagnostic(diagnostic);
+    }
+    if !format.uses_every_value() {
       return;
     }
+    for (key, span) in &variables {
+      if !placeholders.iter().any(|(name, _)| name == key) {
+        let diagnostic = create_unused_icu_variable_warning(function_name, key, *span);
+        self.report_diagnostic(diagnostic);
+      }
+    }
   }
 
+  /// Check a `<Plural>`'s props: an `n` to select a branch with, a catch-all for counts no
+  /// other branch matches and no branch the runtime never selects. With `locales`, every plural
+  /// category of the source locale needs a branch. Props passed through a spread are unknown,
+  /// so those components are left alone.
+  pub fn check_plural_component(&mut self, element: &JSXElement) {
+    let mut has_count = false;
+    let mut branches = Vec::new();
+    for attr in &element.opening.attrs {
+      let JSXAttrOrSpread::JSXAttr(jsx_attr) = attr else {
+        return;
+      };
+      let JSXAttrName::Ident(name) = &jsx_attr.name else {
+        continue;
+      };
+      let name = name.sym.as_str();
+      if name == "n" {
+        has_count = true;
+      } else if PLURAL_FORMS.contains(name) && jsx_attr.value.is_some() {
+        branches.push((name, jsx_attr.span));
+      }
+    }
+    let span = element.opening.name.span();
+    if !has_count {
+      self.report_diagnostic(create_missing_plural_count_warning(span));
+      return;
+    }
 
-/**
- * Validates if an expression is composed only of:
- * - String literals (including static strings)
- * - derive() function calls
- * - Combinations of the above using string concatenation or template literals
- *
- * Valid examples:
- * - "Hello World"
- * - derive(getName())
- * - "Hello World" + derive(getName())
- * - `Hello there ${derive(getName())}`
- *
- * Invalid examples:
- * - variable
- * - otherFunction()
- * - `Hello ${variable}`
- */
-pub fn validate_string_literal_or_derive(&self,expr: &Expr, errors: &mut Vec<String>) {
-  match expr {
-    // String literal - always valid
-    Expr::Lit(Lit::Str(_)) => {
-      // Valid
+    let names: Vec<&str> = branches.iter().map(|(name, _)| *name).collect();
+    let has_fallback = has_visible_children(&element.children);
+    if !names.contains(&"other") && !names.contains(&"plural") && !has_fallback {
+      self.report_diagnostic(create_missing_plural_branch_warning(None, None, span));
     }
 
-    // Template literal - check all expressions are derive calls
-    Expr::Tpl(tpl) => {
-      for expr in &tpl.exprs {
-        self.validate_string_literal_or_derive(expr.as_ref(), errors);
+    // Without bundled data for the source locale, a count may fall in any category
+    let source_locale = self.settings.locales.first().cloned();
+    let categories = source_locale.as_deref().and_then(cardinal_categories);
+    if let (Some(locale), Some(categories)) = (source_locale.as_deref(), categories) {
+      for category in missing_categories(&names, categories) {
+        let diagnostic = create_missing_plural_branch_warning(Some(category), Some(locale), span);
+        self.report_diagnostic(diagnostic);
       }
     }
-
-    // Binary operation (e.g., string concatenation) - check both sides
-    Expr::Bin(bin_expr) => {
-      self.validate_string_literal_or_derive(bin_expr.left.as_ref(), errors);
-      self.validate_string_literal_or_derive(bin_expr.right.as_ref(), errors);
+    for branch in unreachable_branches(&names, categories.unwrap_or(PLURAL_CATEGORIES)) {
+      let reason = match (branch, source_locale.as_deref()) {
+        ("one", _) if names.contains(&"singular") => "\"singular\" takes precedence".to_string(),
+        ("two", _) if names.contains(&"dual") => "\"dual\" takes precedence".to_string(),
+        (_, Some(locale)) if is_cldr_category(branch) => {
+          format!("{locale} has no \"{branch}\" plural category")
+        }
+        _ => "every count selects another branch".to_string(),
+      };
+      let span = branches
+        .iter()
+        .find(|(name, _)| *name == branch)
+        .map_or(span, |(_, span)| *span);
+      let diagnostic = create_unreachable_plural_branch_warning(branch, &reason, span);
+      self.report_diagnostic(diagnostic);
     }
+  }
 
-    // Call expression - must be derive
-    Expr::Call(call_expr) => {
-      if let Callee::Expr(callee_expr) = &call_expr.callee {
-        if let Expr::Ident(ident) = callee_expr.as_ref() {
-          if self.is_derive(&ident.sym) {
-            // Validate that the call expression has exactly one argument and the argument is a call expression
-            validate_derive(call_expr, errors);
-          } else {
-            errors.push(format!(
-              "Only derive() function calls are allowed, found: {}()",
-              ident.sym
-            ));
-          }
-          // If it's derive, it's valid
-        } else {
-          errors.push(
-            "Only derive() function calls are allowed".to_string()
-          );
-        }
-      } else {
-        errors.push(
-          "Only derive() function calls are allowed".to_string()
-        );
+  /// Check a `<Branch>`'s props: a `branch` prop to select with, a literal `branch` that names
+  /// one of its branches, and branches with content to render. Children never render when a
+  /// literal `branch` always selects a branch. Props passed through a spread are unknown, so
+  /// those components are left alone.
+  pub fn check_branch_component(&mut self, element: &JSXElement) {
+    let mut branch_prop = None;
+    let mut branches = Vec::new();
+    for attr in &element.opening.attrs {
+      let JSXAttrOrSpread::JSXAttr(jsx_attr) = attr else {
+        return;
+      };
+      let JSXAttrName::Ident(name) = &jsx_attr.name else {
+        continue;
+      };
+      let name = name.sym.as_str();
+      if name == "branch" {
+        branch_prop = Some(jsx_attr);
+      } else if !name.starts_with("data-") {
+        branches.push((name, jsx_attr));
       }
     }
 
-    // Parenthesized expression - check the inner expression
-    Expr::Paren(paren_expr) => {
-      self.validate_string_literal_or_derive(paren_expr.expr.as_ref(), errors);
+    let empty_branches: Vec<(&str, Span)> = {
+      let mut traversal = JsxTraversal::new(self);
+      branches
+        .iter()
+        .filter(|(_, jsx_attr)| is_empty_branch(&mut traversal, jsx_attr))
+        .map(|(name, jsx_attr)| (*name, jsx_attr.span))
+        .collect()
+    };
+    for (name, span) in empty_branches {
+      self.report_diagnostic(create_empty_branch_warning(name, span));
     }
 
-    // Variables are not allowed
-    Expr::Ident(ident) => {
-      errors.push(format!(
-        "Variables are not allowed. Use a string literal or derive() instead. Found: {}",
-        ident.sym
-      ));
+    let Some(branch_prop) = branch_prop else {
+      let diagnostic = create_missing_branch_prop_warning(element.opening.name.span());
+      self.report_diagnostic(diagnostic);
+      return;
+    };
+    let Some(key) = extract_branch_key_from_jsx_attr(branch_prop) else {
+      return;
+    };
+    // The runtime ignores `data-` keys, like it does `data-` props
+    let matches_branch = !key.starts_with("data-") && branches.iter().any(|(name, _)| *name == key);
+    if !matches_branch {
+      let diagnostic = create_unmatched_branch_key_warning(&key, branch_prop.span);
+      self.report_diagnostic(diagnostic);
+    } else if has_visible_children(&element.children) {
+      let diagnostic = create_ignored_branch_children_warning(&key, branch_prop.span);
+      self.report_diagnostic(diagnostic);
     }
+  }
 
-    // Any other expression type is invalid
-    _ => {
-      errors.push(
-        "Expression must be a string literal, derive() call, or a combination of both".to_string()
-      );
+  /// Report suppression comments that did not suppress any violation
+  pub fn report_unused_suppressions(&mut self) {
+    for span in self.suppressions.unused() {
+      self.report_diagnostic(create_unused_suppression_warning(span));
+    }
+  }
+
+  /// Record a build check violation at its configured severity and emit it through SWC's
+  /// error handler
+  pub fn report_diagnostic(&mut self, diagnostic: GtDiagnostic) {
+    if diagnostic.code != DiagnosticCode::UnusedSuppression
+      && self.suppressions.suppress(diagnostic.span)
+    {
+      return;
+    }
+    let severity = self.settings.severity(diagnostic.code);
+    match severity {
+      RuleSeverity::Off => return,
+      RuleSeverity::Warn => self.statistics.build_check_warnings += 1,
+      RuleSeverity::Error => self.statistics.dynamic_content_violations += 1,
+    }
+    if !diagnostic.emit(severity) {
+      let message = diagnostic.to_log_message(self.settings.filename.as_deref());
+      match severity {
+        RuleSeverity::Warn => self.logger.log_warning(&message),
+        _ => self.logger.log_error(&message),
+      }
+    }
+    self.findings.push(Finding {
+      diagnostic,
+      severity,
+    });
+  }
+
+  /**
+   * Validates if an expression is composed only of:
+   * - String literals (including static strings)
+   * - derive() function calls
+   * - Combinations of the above using string concatenation or template literals
+   *
+   * Valid examples:
+   * - "Hello World"
+   * - derive(getName())
+   * - "Hello World" + derive(getName())
+   * - `Hello there ${derive(getName())}`
+   *
+   * Invalid examples:
+   * - variable
+   * - otherFunction()
+   * - `Hello ${variable}`
+   */
+  pub fn validate_string_literal_or_derive(
+    &self,
+    expr: &Expr,
+    errors: &mut Vec<(DiagnosticCode, Span, String)>,
+  ) {
+    match expr {
+      // String literal - always valid
+      Expr::Lit(Lit::Str(_)) => {
+        // Valid
+      }
+
+      // Template literal - check all expressions are derive calls
+      Expr::Tpl(tpl) => {
+        for expr in &tpl.exprs {
+          self.validate_string_literal_or_derive(expr.as_ref(), errors);
+        }
+      }
+
+      // Binary operation (e.g., string concatenation) - check both sides
+      Expr::Bin(bin_expr) => {
+        self.validate_string_literal_or_derive(bin_expr.left.as_ref(), errors);
+        self.validate_string_literal_or_derive(bin_expr.right.as_ref(), errors);
+      }
+
+      // Call expression - must be derive or declareVar
+      Expr::Call(call_expr) => match self.resolve_call(call_expr) {
+        Some(callee) if is_derive_name(&callee.original_name) => {
+          // Validate that the call expression has exactly one argument and the argument is a call expression
+          let mut derive_errors = Vec::new();
+          validate_derive(call_expr, &mut derive_errors);
+          errors.extend(
+            derive_errors
+              .into_iter()
+              .map(|error| (DiagnosticCode::InvalidDerive, call_expr.span, error)),
+          );
+        }
+        Some(callee) if is_declare_var_name(&callee.original_name) => {
+          // Validate that the declared variable's name can be read at compile time
+          let mut declare_var_errors = Vec::new();
+          validate_declare_var(call_expr, &mut declare_var_errors);
+          errors.extend(declare_var_errors.into_iter().map(|error| {
+            (
+              DiagnosticCode::DynamicFunctionArgument,
+              call_expr.span,
+              error,
+            )
+          }));
+        }
+        _ => {
+          let message = match get_callee_expr_function_name(call_expr) {
+            Some(name) => {
+              format!("Only derive() and declareVar() function calls are allowed, found: {name}()")
+            }
+            None => "Only derive() and declareVar() function calls are allowed".to_string(),
+          };
+          errors.push((
+            DiagnosticCode::DynamicFunctionArgument,
+            call_expr.span,
+            message,
+          ));
+        }
+      },
+
+      // Parenthesized expression - check the inner expression
+      Expr::Paren(paren_expr) => {
+        self.validate_string_literal_or_derive(paren_expr.expr.as_ref(), errors);
+      }
+
+      // Variables are not allowed
+      Expr::Ident(ident) => {
+        errors.push((
+          DiagnosticCode::DynamicFunctionArgument,
+          ident.span,
+          format!(
+            "Variables are not allowed. Use a string literal or derive() instead. Found: {}",
+            ident.sym
+          ),
+        ));
+      }
+
+      // Any other expression type is invalid
+      _ => {
+        errors.push((
+          DiagnosticCode::DynamicFunctionArgument,
+          expr.span(),
+          "Expression must be a string literal, derive() call, or a combination of both"
+            .to_string(),
+        ));
+      }
     }
   }
-}
 
   // Calculate hash for a call expression return the hash and the json string
   pub fn calculate_hash_for_call_expr(
@@ -429,16 +1197,20 @@ pub fn validate_string_literal_or_derive(&self,expr: &Expr, errors: &mut Vec<Str
     string: &ExprOrSpread,
     options: Option<&ExprOrSpread>,
   ) -> (Option<String>, Option<String>) {
-    // Extract the string content
-    let string_content = extract_string_from_expr(&string.expr);
-    if string_content.is_none() {
-      return (None, None);
-    }
-
     // Extract the options content
     let (_, context, max_chars, format, has_derive_context) =
       extract_id_and_context_from_options(options);
 
+    // Extract the string content
+    let Some((string_content, declared_vars)) = self.message_source(&string.expr) else {
+      return (None, None);
+    };
+
+    // declareVar() encodings are only indexed for ICU messages
+    if declared_vars > 0 && format.as_deref().is_some_and(|format| format != "ICU") {
+      return (None, None);
+    }
+
     // If context contains derive(), skip hashing — CLI handles resolution
     if has_derive_context {
       return (Some(String::new()), None);
@@ -448,7 +1220,7 @@ pub fn validate_string_literal_or_derive(&self,expr: &Expr, errors: &mut Vec<Str
     use crate::hash::{SanitizedChild, SanitizedChildren, SanitizedData};
     let sanitized_data = SanitizedData {
       source: Some(Box::new(SanitizedChildren::Single(Box::new(
-        SanitizedChild::Text(string_content.unwrap()),
+        SanitizedChild::Text(string_content),
       )))),
       context,
       max_chars,
@@ -456,110 +1228,65 @@ pub fn validate_string_literal_or_derive(&self,expr: &Expr, errors: &mut Vec<Str
     };
     // Calculate hash using stable stringify (like TypeScript fast-json-stable-stringify)
     use crate::hash::JsxHasher;
-    let json_string =
-      JsxHasher::stable_stringify(&sanitized_data).expect("Failed to serialize sanitized data");
+    let Ok(json_string) = JsxHasher::stable_stringify(&sanitized_data) else {
+      return (None, None);
+    };
     let hash = JsxHasher::hash_string(&json_string);
 
     (Some(hash), Some(json_string))
   }
 
-  // Inject hash attribute into options
-  pub fn inject_hash_attribute_on_call_expr(
-    &mut self,
-    call_expr: &CallExpr,
-    options: Option<&ExprOrSpread>,
-    hash: String,
-    _: Option<String>,
-  ) -> CallExpr {
-    // Inject $hash & $json attribute into options object
-    if let Some(options) = options {
-      match options.expr.as_ref() {
-        Expr::Object(existing_obj) => {
-          // Build a new CallExpr with the new options
-          let mut new_props = existing_obj.props.clone();
-          if !has_prop(&existing_obj.props, "$_hash") {
-            new_props.push(create_string_prop("$_hash", &hash, call_expr.span));
-          }
-
-          // Construct a new options object
-          let modified_options = Expr::Object(ObjectLit {
-            span: existing_obj.span,
-            props: new_props,
-          });
-
-          // Replace options with modified version
-          let mut new_args = call_expr.args.clone();
-          new_args[1] = ExprOrSpread {
-            spread: None,
-            expr: Box::new(modified_options),
-          };
-
-          CallExpr {
-            args: new_args,
-            ..call_expr.clone()
-          }
-        }
-        Expr::Ident(_)
-        | Expr::Member(_)
-        | Expr::Call(_)
-        | Expr::Await(_)
-        | Expr::Cond(_)
-        | Expr::Paren(_)
-        | Expr::Assign(_) => create_spread_options_call_expr(
-          call_expr,
-          options.expr.as_ref(),
-          &hash,
-          None,
-          call_expr.span,
-        ),
-        // Handle logical OR specially (common pattern: opts || {})
-        Expr::Bin(BinExpr {
-          op: BinaryOp::LogicalOr,
-          ..
-        }) => create_spread_options_call_expr(
-          call_expr,
-          options.expr.as_ref(),
-          &hash,
-          None,
-          call_expr.span,
-        ),
-        _ => call_expr.clone(),
-      }
-    } else {
+  /// Inject the $_hash option into a t() or msg() call
+  pub fn inject_hash_attribute_on_call_expr(call_expr: &mut CallExpr, hash: &str) {
+    let span = call_expr.span;
+    let Some(options) = call_expr.args.get_mut(1) else {
       // Create a new options object
-      let new_options = Expr::Object(ObjectLit {
-        span: call_expr.span,
-        props: vec![
-          create_string_prop("$_hash", &hash, call_expr.span),
-          // For debugging
-          // create_string_prop("$json", &json, call_expr.span),
-        ],
-      });
-
-      // Build a new CallExpr with the new options
-      let mut new_args = call_expr.args.clone();
-      new_args.push(ExprOrSpread {
+      call_expr.args.push(ExprOrSpread {
         spread: None,
-        expr: Box::new(new_options),
+        expr: Box::new(Expr::Object(ObjectLit {
+          span,
+          props: vec![create_string_prop("$_hash", hash, span)],
+        })),
       });
-      CallExpr {
-        args: new_args,
-        ..call_expr.clone()
+      return;
+    };
+    match options.expr.as_mut() {
+      Expr::Object(existing_obj) if !has_prop(&existing_obj.props, "$_hash") => {
+        existing_obj
+          .props
+          .push(create_string_prop("$_hash", hash, span));
       }
+      // Spread options we can't modify: t("Hi", opts), t("Hi", opts || {})
+      Expr::Ident(_)
+      | Expr::Member(_)
+      | Expr::Call(_)
+      | Expr::Await(_)
+      | Expr::Cond(_)
+      | Expr::Paren(_)
+      | Expr::Assign(_)
+      | Expr::Bin(BinExpr {
+        op: BinaryOp::LogicalOr,
+        ..
+      }) => {
+        let spread_expr =
+          std::mem::replace(&mut options.expr, Box::new(Expr::Invalid(Invalid { span })));
+        *options.expr = create_spread_options(spread_expr, hash, span);
+      }
+      _ => {}
     }
   }
 
-  fn extract_identifiers_from_pattern(&self, pattern: &Pat, identifiers: &mut Vec<Atom>) {
+  fn extract_identifiers_from_pattern(&self, pattern: &Pat, identifiers: &mut Vec<Id>) {
     match pattern {
       Pat::Ident(BindingIdent { id, .. }) => {
-        identifiers.push(id.sym.clone());
+        identifiers.push(id.to_id());
       }
       Pat::Object(ObjectPat { props, .. }) => {
         for prop in props {
           match prop {
             ObjectPatProp::Assign(AssignPatProp { key, .. }) => {
               // { key }
-              identifiers.push(key.sym.clone());
+              identifiers.push(key.to_id());
             }
             ObjectPatProp::KeyValue(KeyValuePatProp { value, .. }) => {
               // { key: value }
@@ -573,10 +1300,8 @@ pub fn validate_string_literal_or_derive(&self,expr: &Expr, errors: &mut Vec<Str
         }
       }
       Pat::Array(ArrayPat { elems, .. }) => {
-        for elem in elems {
-          if let Some(elem) = elem {
-            self.extract_identifiers_from_pattern(elem, identifiers);
-          }
+        for elem in elems.iter().flatten() {
+          self.extract_identifiers_from_pattern(elem, identifiers);
         }
       }
       Pat::Assign(AssignPat { left, .. }) => {
@@ -589,121 +1314,163 @@ pub fn validate_string_literal_or_derive(&self,expr: &Expr, errors: &mut Vec<Str
     }
   }
 
-  fn track_overriding_variable(&mut self, variable_name: &Atom) {
-    if self
-      .import_tracker
-      .scope_tracker
-      .get_variable(variable_name)
-      .is_some()
-    {
-      self
-        .import_tracker
-        .scope_tracker
-        .track_regular_variable(variable_name.clone(), "other".into());
-    }
-  }
+  /// Translator created by a call like useGT(), useTranslations('nav') or a local custom hook:
+  /// its callback name and identifier
+  fn translator_for_call(&mut self, call_expr: &CallExpr) -> Option<(Atom, u32)> {
+    let Callee::Expr(callee_expr) = &call_expr.callee else {
+      return None;
+    };
+    let args = &call_expr.args;
+    // Check if the callee is a translation function: useGT(), an alias or GT.useGT()
+    let callee = self.resolve_callee(callee_expr);
+    let original_name = match &callee {
+      Some(callee) => callee.original_name.clone(),
+      // Local custom hooks: const t = useAppT()
+      None => self.resolve_custom_hook(callee_expr)?,
+    };
 
-  /// Track function parameters that could shadow existing variables
-  pub fn track_parameter_overrides(&mut self, params: &[Param]) {
-    for param in params {
-      let mut identifiers = Vec::new();
-      self.extract_identifiers_from_pattern(&param.pat, &mut identifiers);
-      for identifier in identifiers {
-        self.track_overriding_variable(&identifier);
+    // Check if its getGT or useGT
+    if is_translation_function_name(&original_name) {
+      let callback = format!("{original_name}_callback").into();
+      // Custom hooks don't take a content array: const t = useAppT()
+      if callee.is_none() {
+        return Some((callback, StringCollector::NO_AGGREGATOR));
       }
+      // Get counter_id
+      let counter_id = self.string_collector.increment_counter();
+      // Create a new entry in the string collector for this call
+      self.string_collector.initialize_aggregator(counter_id);
+      // The call receives its content array once the scope declaring the translator is left.
+      // Validation-only mode leaves the program untouched.
+      if self.settings.compile_time_hash {
+        self.string_collector.record_injection_target(counter_id);
+      }
+
+      // Track translation function as a binding (useGT_callback, getGT_callback)
+      Some((callback, counter_id))
+    }
+    // Dictionary translators: const t = useTranslations('namespace')
+    else if is_dictionary_function_name(&original_name) {
+      let namespace = match args.first().map(|arg| arg.expr.as_ref()) {
+        None => Some(String::new()),
+        Some(expr) => extract_static_string(expr),
+      };
+      let identifier = self.import_tracker.dictionary_namespaces.len() as u32;
+      self.import_tracker.dictionary_namespaces.push(namespace);
+      Some((format!("{original_name}_callback").into(), identifier))
+    }
+    // Message decoding translators: const m = useMessages()
+    else if is_messages_function_name(&original_name) {
+      Some((format!("{original_name}_callback").into(), 0))
+    } else {
+      None
     }
   }
 
-  /// Track arrow function parameters (arrow functions)  
-  pub fn track_arrow_parameter_overrides(&mut self, params: &[Pat]) {
-    for param in params {
-      let mut identifiers = Vec::new();
-      self.extract_identifiers_from_pattern(param, &mut identifiers);
-      for identifier in identifiers {
-        self.track_overriding_variable(&identifier);
-      }
+  /// Translator a variable is initialized or assigned with: a translator call, an awaited one,
+  /// or an alias of another tracked binding (`const translate = t`)
+  fn translator_for_expr(&mut self, expr: &Expr) -> Option<(Atom, u32)> {
+    match expr {
+      Expr::Call(call_expr) => self.translator_for_call(call_expr),
+      Expr::Await(AwaitExpr { arg, .. }) => match arg.as_ref() {
+        Expr::Call(call_expr) => self.translator_for_call(call_expr),
+        _ => None,
+      },
+      Expr::Ident(_) => self
+        .resolve_callee(expr)
+        .map(|callee| (callee.original_name, callee.identifier)),
+      Expr::Paren(paren_expr) => self.translator_for_expr(&paren_expr.expr),
+      _ => None,
     }
   }
 
-  // Track function call assignments
-  fn track_function_call_assignment(&mut self, callee_expr: &Box<Expr>, variable_name: &Atom) {
-    if let Expr::Ident(Ident {
-      sym: callee_name, ..
-    }) = callee_expr.as_ref()
-    {
-      // Check if the callee is a translation function
-      if let Some(translation_variable) = self
-        .import_tracker
-        .scope_tracker
-        .get_translation_variable(callee_name)
-      {
-        // This will be either useGT or getGT, not the alias
-        let original_name = translation_variable.original_name.clone();
+  /// GT function a local custom hook wraps, if the callee is one
+  fn resolve_custom_hook(&self, callee_expr: &Expr) -> Option<Atom> {
+    let Expr::Ident(ident) = callee_expr else {
+      return None;
+    };
+    self.dataflow.hooks.get(&ident.to_id()).cloned()
+  }
 
-        // Check if its getGT or useGT
-        if is_translation_function_name(&original_name) {
-          // Get counter_id
-          let counter_id = self.string_collector.increment_counter();
-          // Create a new entry in the string collector for this call
-          self.string_collector.initialize_aggregator(counter_id);
+  /// Track helper parameters that receive a translator. Their t() calls are checked and hashed
+  /// in place; the content arrays belong to the useGT()/getGT() calls of their callers.
+  pub fn track_translator_parameters<'a>(
+    &mut self,
+    function: Option<&Id>,
+    params: impl Iterator<Item = &'a Pat>,
+  ) {
+    let Some(parameters) = function
+      .and_then(|function| self.dataflow.helper_parameters.get(function))
+      .cloned()
+    else {
+      return;
+    };
+    for (index, param) in params.enumerate() {
+      let (Some(callback), Pat::Ident(BindingIdent { id, .. })) = (parameters.get(&index), param)
+      else {
+        continue;
+      };
+      self.import_tracker.bindings.track_translation_variable(
+        id.to_id(),
+        callback.clone(),
+        StringCollector::NO_AGGREGATOR,
+      );
+    }
+  }
 
-          // Track translation function using scope system (useGT_callback, getGT_callback)
-          self
-            .import_tracker
-            .scope_tracker
-            .track_translation_variable(
-              variable_name.clone(),
-              format!("{}_callback", original_name.clone()).into(),
-              counter_id,
-            );
-        }
-      } else {
-        // TODO: do the check that this is not a translation_variable sooner?
-        self.track_overriding_variable(variable_name);
+  /// Track assignments to existing variables: `let t; t = useGT()`
+  pub fn track_assignment(&mut self, assign_expr: &AssignExpr) {
+    if assign_expr.op != AssignOp::Assign {
+      return;
+    }
+    let AssignTarget::Simple(SimpleAssignTarget::Ident(BindingIdent { id, .. })) =
+      &assign_expr.left
+    else {
+      return;
+    };
+    match self.translator_for_expr(&assign_expr.right) {
+      Some((callback, identifier)) => {
+        self
+          .import_tracker
+          .bindings
+          .track_translation_variable(id.to_id(), callback, identifier)
       }
+      None => self.import_tracker.bindings.untrack(&id.to_id()),
     }
   }
 
   pub fn track_variable_assignment(&mut self, var_declarator: &VarDeclarator) {
-    if let Some(init_expr) = &var_declarator.init {
-      match &var_declarator.name {
-        // Handle simple identifier assignment: const t = useGT()
-        Pat::Ident(BindingIdent { id, .. }) => {
-          match init_expr.as_ref() {
-            Expr::Call(CallExpr {
-              callee: Callee::Expr(callee_expr),
-              ..
-            }) => {
-              // Only direct assignments can be translation functions
-              self.track_function_call_assignment(callee_expr, &id.sym);
-            }
-            Expr::Await(AwaitExpr { arg, .. }) => {
-              if let Expr::Call(CallExpr {
-                callee: Callee::Expr(callee_expr),
-                ..
-              }) = arg.as_ref()
-              {
-                // Only direct assignments can be translation functions
-                self.track_function_call_assignment(callee_expr, &id.sym);
-              } else {
-                // Not a function call, treat as overriding
-                self.track_overriding_variable(&id.sym);
-              }
-            }
-            _ => {
-              // Not a function call, treat as overriding
-              self.track_overriding_variable(&id.sym);
-            }
-          }
+    // require('gt-next') and await import('gt-next') bind GT exports, not regular variables
+    if self.process_gt_require_declaration(var_declarator) {
+      return;
+    }
+    let Some(init_expr) = &var_declarator.init else {
+      return;
+    };
+    match &var_declarator.name {
+      // Handle simple identifier assignment: const t = useGT(), const translate = t
+      Pat::Ident(BindingIdent { id, .. }) => {
+        // const greet = (t) => ... may be a helper or a custom hook
+        if matches!(init_expr.as_ref(), Expr::Arrow(_) | Expr::Fn(_)) {
+          self.traversal_state.pending_function = Some(id.to_id());
         }
-        // Handle ALL destructuring patterns: const { t } = anything
-        _ => {
-          let mut identifiers = Vec::new();
-          self.extract_identifiers_from_pattern(&var_declarator.name, &mut identifiers);
+        match self.translator_for_expr(init_expr) {
+          Some((callback, identifier)) => self.import_tracker.bindings.track_translation_variable(
+            id.to_id(),
+            callback,
+            identifier,
+          ),
+          // Not a translator: a redeclaration (`var t = other`) drops the binding
+          None => self.import_tracker.bindings.untrack(&id.to_id()),
+        }
+      }
+      // Handle ALL destructuring patterns: const { t } = anything
+      _ => {
+        let mut identifiers = Vec::new();
+        self.extract_identifiers_from_pattern(&var_declarator.name, &mut identifiers);
 
-          for identifier in identifiers {
-            self.track_overriding_variable(&identifier);
-          }
+        for identifier in identifiers {
+          self.import_tracker.bindings.untrack(&identifier);
         }
       }
     }
@@ -712,17 +1479,15 @@ pub fn validate_string_literal_or_derive(&self,expr: &Expr, errors: &mut Vec<Str
   pub fn determine_component_type(&mut self, element: &JSXElement) -> (bool, bool, bool) {
     match &element.opening.name {
       JSXElementName::Ident(ident) => {
-        let name = &ident.sym;
-        let is_translation = self.should_track_component_as_translation(name);
-        let is_variable = self.should_track_component_as_variable(name);
-        let is_branch = self.should_track_component_as_branch(name);
+        let id = ident.to_id();
+        let is_translation = self.should_track_component_as_translation(&id);
+        let is_variable = self.should_track_component_as_variable(&id);
+        let is_branch = self.should_track_component_as_branch(&id);
         (is_translation, is_variable, is_branch)
       }
       JSXElementName::JSXMemberExpr(member_expr) => {
         if let JSXObject::Ident(obj_ident) = &member_expr.obj {
-          let obj_name = &obj_ident.sym;
-          let prop_name = &member_expr.prop.sym;
-          self.should_track_namespace_component(obj_name, prop_name)
+          self.should_track_namespace_component(&obj_ident.to_id(), &member_expr.prop.sym)
         } else {
           (false, false, false)
         }
@@ -762,41 +1527,77 @@ pub fn validate_string_literal_or_derive(&self,expr: &Expr, errors: &mut Vec<Str
   }
 }
 
+/// Whether a `<Branch>` prop renders nothing: a prop without a value, which is `true`, or
+/// content that sanitizes to blank text, a boolean or null. Empty expressions and `undefined`
+/// are dropped by sanitizing, as are dynamic values, which do render.
+fn is_empty_branch(traversal: &mut JsxTraversal, jsx_attr: &JSXAttr) -> bool {
+  let Some(value) = &jsx_attr.value else {
+    return true;
+  };
+  match traversal.build_sanitized_child_from_attr_value(value) {
+    Some(SanitizedChild::Text(text)) => text.trim().is_empty(),
+    Some(SanitizedChild::Boolean(_) | SanitizedChild::Null(_)) => true,
+    Some(_) => false,
+    None => match value {
+      JSXAttrValue::JSXExprContainer(container) => match &container.expr {
+        JSXExpr::JSXEmptyExpr(_) => true,
+        JSXExpr::Expr(expr) => {
+          matches!(expr.as_ref(), Expr::Ident(ident) if ident.sym == "undefined")
+        }
+      },
+      _ => false,
+    },
+  }
+}
+
+#[cfg(test)]
+#[path = "transform_tests.rs"]
+mod transform_tests;
+
 #[cfg(test)]
 mod tests {
   use super::*;
   use swc_core::common::{SyntaxContext, DUMMY_SP};
   use swc_core::ecma::atoms::Atom;
 
+  fn id(name: &str) -> Id {
+    (Atom::new(name), SyntaxContext::empty())
+  }
+
   // Helper to create a test visitor with specific imports
   fn create_visitor_with_imports() -> TransformVisitor {
-    let mut visitor = TransformVisitor::new(LogLevel::Silent, false, None, false, false, false, StringCollector::new());
+    let mut visitor = TransformVisitor::new(
+      LogLevel::Silent,
+      false,
+      None,
+      false,
+      false,
+      false,
+      StringCollector::new(),
+    );
 
-    // Add some test imports using the scope tracker
+    // Add some test imports using the binding tracker
     visitor
       .import_tracker
-      .scope_tracker
-      .track_translation_variable(Atom::new("T"), Atom::new("T"), 0);
-    visitor.import_tracker.scope_tracker.track_variable(
-      Atom::new("Var"),
-      Atom::new("Var"),
-      true,
-      0,
-    );
-    visitor.import_tracker.scope_tracker.track_variable(
-      Atom::new("Branch"),
+      .bindings
+      .track_translation_variable(id("T"), Atom::new("T"), 0);
+    visitor
+      .import_tracker
+      .bindings
+      .track_translation_variable(id("Var"), Atom::new("Var"), 0);
+    visitor.import_tracker.bindings.track_translation_variable(
+      id("Branch"),
       Atom::new("Branch"),
-      true,
       0,
     );
     visitor
       .import_tracker
-      .scope_tracker
-      .track_translation_variable(Atom::new("useGT"), Atom::new("useGT"), 0);
+      .bindings
+      .track_translation_variable(id("useGT"), Atom::new("useGT"), 0);
     visitor
       .import_tracker
       .namespace_imports
-      .insert(Atom::new("GT"));
+      .insert(id("GT"), ImportSourcePreset::Next);
 
     visitor
   }
@@ -955,7 +1756,7 @@ mod tests {
       // Check defaults are set
       assert_eq!(visitor.statistics.jsx_element_count, 0);
       assert_eq!(visitor.statistics.dynamic_content_violations, 0);
-      // Scope tracker should be empty initially
+      // Import tracker should be empty initially
       assert!(visitor.import_tracker.namespace_imports.is_empty());
     }
 
@@ -976,27 +1777,27 @@ mod tests {
     fn tracks_translation_components() {
       let visitor = create_visitor_with_imports();
 
-      assert!(visitor.should_track_component_as_translation(&Atom::new("T")));
-      assert!(!visitor.should_track_component_as_translation(&Atom::new("div")));
-      assert!(!visitor.should_track_component_as_translation(&Atom::new("Var")));
+      assert!(visitor.should_track_component_as_translation(&id("T")));
+      assert!(!visitor.should_track_component_as_translation(&id("div")));
+      assert!(!visitor.should_track_component_as_translation(&id("Var")));
     }
 
     #[test]
     fn tracks_variable_components() {
       let visitor = create_visitor_with_imports();
 
-      assert!(visitor.should_track_component_as_variable(&Atom::new("Var")));
-      assert!(!visitor.should_track_component_as_variable(&Atom::new("T")));
-      assert!(!visitor.should_track_component_as_variable(&Atom::new("div")));
+      assert!(visitor.should_track_component_as_variable(&id("Var")));
+      assert!(!visitor.should_track_component_as_variable(&id("T")));
+      assert!(!visitor.should_track_component_as_variable(&id("div")));
     }
 
     #[test]
     fn tracks_branch_components() {
       let visitor = create_visitor_with_imports();
 
-      assert!(visitor.should_track_component_as_branch(&Atom::new("Branch")));
-      assert!(!visitor.should_track_component_as_branch(&Atom::new("T")));
-      assert!(!visitor.should_track_component_as_branch(&Atom::new("div")));
+      assert!(visitor.should_track_component_as_branch(&id("Branch")));
+      assert!(!visitor.should_track_component_as_branch(&id("T")));
+      assert!(!visitor.should_track_component_as_branch(&id("div")));
     }
 
     #[test]
@@ -1004,19 +1805,19 @@ mod tests {
       let visitor = create_visitor_with_imports();
 
       let (is_translation, is_variable, is_branch) =
-        visitor.should_track_namespace_component(&Atom::new("GT"), &Atom::new("T"));
+        visitor.should_track_namespace_component(&id("GT"), &Atom::new("T"));
       assert!(is_translation);
       assert!(!is_variable);
       assert!(!is_branch);
 
       let (is_translation, is_variable, is_branch) =
-        visitor.should_track_namespace_component(&Atom::new("GT"), &Atom::new("Var"));
+        visitor.should_track_namespace_component(&id("GT"), &Atom::new("Var"));
       assert!(!is_translation);
       assert!(is_variable);
       assert!(!is_branch);
 
       let (is_translation, is_variable, is_branch) =
-        visitor.should_track_namespace_component(&Atom::new("Unknown"), &Atom::new("T"));
+        visitor.should_track_namespace_component(&id("Unknown"), &Atom::new("T"));
       assert!(!is_translation);
       assert!(!is_variable);
       assert!(!is_branch);
@@ -1025,12 +1826,13 @@ mod tests {
 
   mod warning_message_generation {
     use super::*;
-    use crate::visitor::errors::{create_dynamic_content_warning, create_dynamic_function_warning};
+    use crate::visitor::errors::{
+      create_dynamic_content_warning, create_dynamic_function_warning, DiagnosticCode,
+    };
 
     #[test]
     fn creates_dynamic_content_warning_without_filename() {
-      TransformVisitor::new(LogLevel::Warn, false, None, false, false, false, StringCollector::new());
-      let warning = create_dynamic_content_warning(None, "T");
+      let warning = create_dynamic_content_warning("T", DUMMY_SP).to_log_message(None);
 
       assert!(warning.contains("gt-next"));
       assert!(warning.contains("<T> component contains unwrapped dynamic content"));
@@ -1040,16 +1842,8 @@ mod tests {
 
     #[test]
     fn creates_dynamic_content_warning_with_filename() {
-      TransformVisitor::new(
-        LogLevel::Warn,
-        false,
-        Some("components/Test.tsx".to_string()),
-        false,
-        false,
-        false,
-        StringCollector::new(),
-      );
-      let warning = create_dynamic_content_warning(Some("components/Test.tsx"), "T");
+      let warning =
+        create_dynamic_content_warning("T", DUMMY_SP).to_log_message(Some("components/Test.tsx"));
 
       assert!(warning.contains("gt-next in components/Test.tsx"));
       assert!(warning.contains("<T> component contains unwrapped dynamic content"));
@@ -1057,8 +1851,8 @@ mod tests {
 
     #[test]
     fn creates_dynamic_function_warning_without_filename() {
-      TransformVisitor::new(LogLevel::Warn, false, None, false, false, false, StringCollector::new());
-      let warning = create_dynamic_function_warning(None, "useGT", "template literals");
+      let warning = create_dynamic_function_warning("useGT", "template literals", DUMMY_SP)
+        .to_log_message(None);
 
       assert!(warning.contains("gt-next"));
       assert!(warning.contains("useGT() function call uses template literals"));
@@ -1068,24 +1862,28 @@ mod tests {
 
     #[test]
     fn creates_dynamic_function_warning_with_filename() {
-      TransformVisitor::new(
-        LogLevel::Warn,
-        false,
-        Some("hooks/useTranslation.ts".to_string()),
-        false,
-        false,
-        false,
-        StringCollector::new(),
-      );
-      let warning = create_dynamic_function_warning(
-        Some("hooks/useTranslation.ts"),
-        "t",
-        "string concatenation",
-      );
+      let warning = create_dynamic_function_warning("t", "string concatenation", DUMMY_SP)
+        .to_log_message(Some("hooks/useTranslation.ts"));
 
       assert!(warning.contains("gt-next in hooks/useTranslation.ts"));
       assert!(warning.contains("t() function call uses string concatenation"));
     }
+
+    #[test]
+    fn diagnostics_carry_stable_codes() {
+      let content = create_dynamic_content_warning("T", DUMMY_SP);
+      let function = create_dynamic_function_warning("t", "variables", DUMMY_SP);
+
+      assert_eq!(content.code, DiagnosticCode::DynamicJsxContent);
+      assert_eq!(content.code.as_str(), "GT001");
+      assert_eq!(function.code.as_str(), "GT002");
+      assert!(content.to_log_message(None).contains("[GT001]"));
+    }
+
+    #[test]
+    fn emit_without_handler_is_a_no_op() {
+      assert!(!create_dynamic_content_warning("T", DUMMY_SP).emit(RuleSeverity::Error));
+    }
   }
 
   mod import_processing {
@@ -1093,8 +1891,15 @@ mod tests {
 
     #[test]
     fn processes_gt_next_named_imports() {
-      let mut visitor =
-        TransformVisitor::new(LogLevel::Silent, false, None, false, false, false, StringCollector::new());
+      let mut visitor = TransformVisitor::new(
+        LogLevel::Silent,
+        false,
+        None,
+        false,
+        false,
+        false,
+        StringCollector::new(),
+      );
       let import_decl = create_import_decl(
         "gt-next",
         vec![
@@ -1106,28 +1911,35 @@ mod tests {
 
       visitor.process_gt_import_declaration(&import_decl);
 
-      // Check that imports are tracked in the scope tracker
+      // Check that imports are tracked in the binding tracker
       assert!(visitor
         .import_tracker
-        .scope_tracker
-        .get_translation_variable(&Atom::new("T"))
+        .bindings
+        .get_translation_variable(&id("T"))
         .is_some());
       assert!(visitor
         .import_tracker
-        .scope_tracker
-        .get_variable(&Atom::new("MyVar"))
+        .bindings
+        .get_translation_variable(&id("MyVar"))
         .is_some());
       assert!(visitor
         .import_tracker
-        .scope_tracker
-        .get_translation_variable(&Atom::new("useGT"))
+        .bindings
+        .get_translation_variable(&id("useGT"))
         .is_some());
     }
 
     #[test]
     fn processes_namespace_imports() {
-      let mut visitor =
-        TransformVisitor::new(LogLevel::Silent, false, None, false, false, false, StringCollector::new());
+      let mut visitor = TransformVisitor::new(
+        LogLevel::Silent,
+        false,
+        None,
+        false,
+        false,
+        false,
+        StringCollector::new(),
+      );
       let import_decl = create_import_decl("gt-next", vec![create_namespace_import("GT")]);
 
       visitor.process_gt_import_declaration(&import_decl);
@@ -1135,28 +1947,42 @@ mod tests {
       assert!(visitor
         .import_tracker
         .namespace_imports
-        .contains(&Atom::new("GT")));
+        .contains_key(&id("GT")));
     }
 
     #[test]
     fn processes_gt_next_imports() {
-      let mut visitor =
-        TransformVisitor::new(LogLevel::Silent, false, None, false, false, false, StringCollector::new());
+      let mut visitor = TransformVisitor::new(
+        LogLevel::Silent,
+        false,
+        None,
+        false,
+        false,
+        false,
+        StringCollector::new(),
+      );
       let import_decl = create_import_decl("gt-next", vec![create_named_import("T", None)]);
 
       visitor.process_gt_import_declaration(&import_decl);
 
       assert!(visitor
         .import_tracker
-        .scope_tracker
-        .get_translation_variable(&Atom::new("T"))
+        .bindings
+        .get_translation_variable(&id("T"))
         .is_some());
     }
 
     #[test]
     fn ignores_non_gt_imports() {
-      let mut visitor =
-        TransformVisitor::new(LogLevel::Silent, false, None, false, false, false, StringCollector::new());
+      let mut visitor = TransformVisitor::new(
+        LogLevel::Silent,
+        false,
+        None,
+        false,
+        false,
+        false,
+        StringCollector::new(),
+      );
       let import_decl = create_import_decl("react", vec![create_named_import("React", None)]);
 
       visitor.process_gt_import_declaration(&import_decl);
@@ -1164,8 +1990,8 @@ mod tests {
       // Should not track non-gt imports
       assert!(visitor
         .import_tracker
-        .scope_tracker
-        .get_translation_variable(&Atom::new("React"))
+        .bindings
+        .get_translation_variable(&id("React"))
         .is_none());
       assert!(visitor.import_tracker.namespace_imports.is_empty());
     }
@@ -1431,8 +2257,8 @@ mod tests {
           // Only check violations if it's a tracked function
           let is_tracked_callee = visitor
             .import_tracker
-            .scope_tracker
-            .get_translation_variable(&ident.sym)
+            .bindings
+            .get_translation_variable(&ident.to_id())
             .is_some();
 
           if is_tracked_callee {
@@ -1453,10 +2279,11 @@ mod tests {
     fn allows_derive_calls() {
       let mut visitor = create_visitor_with_imports();
       // Track derive import
-      visitor
-        .import_tracker
-        .scope_tracker
-        .track_translation_variable(Atom::new("derive"), Atom::new("derive"), 0);
+      visitor.import_tracker.bindings.track_translation_variable(
+        id("derive"),
+        Atom::new("derive"),
+        0,
+      );
 
       // Create derive(getName()) expression
       let derive_call = Expr::Call(CallExpr {
@@ -1503,10 +2330,11 @@ mod tests {
     fn allows_string_concatenation_with_derive() {
       let mut visitor = create_visitor_with_imports();
       // Track derive import
-      visitor
-        .import_tracker
-        .scope_tracker
-        .track_translation_variable(Atom::new("derive"), Atom::new("derive"), 0);
+      visitor.import_tracker.bindings.track_translation_variable(
+        id("derive"),
+        Atom::new("derive"),
+        0,
+      );
 
       // Create "Hello " + derive(getName())
       let concat_expr = Expr::Bin(BinExpr {
@@ -1562,10 +2390,11 @@ mod tests {
     fn allows_template_literal_with_derive() {
       let mut visitor = create_visitor_with_imports();
       // Track derive import
-      visitor
-        .import_tracker
-        .scope_tracker
-        .track_translation_variable(Atom::new("derive"), Atom::new("derive"), 0);
+      visitor.import_tracker.bindings.track_translation_variable(
+        id("derive"),
+        Atom::new("derive"),
+        0,
+      );
 
       // Create `Hello ${derive(getName())}`
       let template_expr = Expr::Tpl(Tpl {
@@ -1631,8 +2460,8 @@ mod tests {
       // Track derive import with alias: import { derive as ds }
       visitor
         .import_tracker
-        .scope_tracker
-        .track_translation_variable(Atom::new("ds"), Atom::new("derive"), 0);
+        .bindings
+        .track_translation_variable(id("ds"), Atom::new("derive"), 0);
 
       // Create ds(getName()) expression
       let derive_call = Expr::Call(CallExpr {
@@ -1734,10 +2563,11 @@ mod tests {
     fn detects_derive_with_invalid_arguments() {
       let mut visitor = create_visitor_with_imports();
       // Track derive import
-      visitor
-        .import_tracker
-        .scope_tracker
-        .track_translation_variable(Atom::new("derive"), Atom::new("derive"), 0);
+      visitor.import_tracker.bindings.track_translation_variable(
+        id("derive"),
+        Atom::new("derive"),
+        0,
+      );
 
       // Create derive("string literal") - should fail because arg must be a call expression
       let derive_call = Expr::Call(CallExpr {
@@ -1779,27 +2609,35 @@ mod tests {
 
     /// Creates a visitor with autoderive strings enabled and standard imports tracked
     fn create_visitor_with_autoderive() -> TransformVisitor {
-      let mut visitor =
-        TransformVisitor::new(LogLevel::Silent, false, None, false, false, false, StringCollector::new());
+      let mut visitor = TransformVisitor::new(
+        LogLevel::Silent,
+        false,
+        None,
+        false,
+        false,
+        false,
+        StringCollector::new(),
+      );
       visitor.settings.autoderive_strings = true;
 
       // Track standard gt-next imports
       visitor
         .import_tracker
-        .scope_tracker
-        .track_translation_variable(Atom::new("T"), Atom::new("T"), 0);
-      visitor
-        .import_tracker
-        .scope_tracker
-        .track_translation_variable(Atom::new("useGT"), Atom::new("useGT"), 0);
+        .bindings
+        .track_translation_variable(id("T"), Atom::new("T"), 0);
+      visitor.import_tracker.bindings.track_translation_variable(
+        id("useGT"),
+        Atom::new("useGT"),
+        0,
+      );
       visitor
         .import_tracker
-        .scope_tracker
-        .track_translation_variable(Atom::new("gt"), Atom::new("gt"), 0);
+        .bindings
+        .track_translation_variable(id("gt"), Atom::new("gt"), 0);
       visitor
         .import_tracker
-        .scope_tracker
-        .track_translation_variable(Atom::new("t"), Atom::new("t"), 0);
+        .bindings
+        .track_translation_variable(id("t"), Atom::new("t"), 0);
 
       visitor
     }
@@ -1977,10 +2815,11 @@ mod tests {
     fn explicit_derive_works_with_autoderive_on() {
       let mut visitor = create_visitor_with_autoderive();
       // Also track derive import
-      visitor
-        .import_tracker
-        .scope_tracker
-        .track_translation_variable(Atom::new("derive"), Atom::new("derive"), 0);
+      visitor.import_tracker.bindings.track_translation_variable(
+        id("derive"),
+        Atom::new("derive"),
+        0,
+      );
 
       let template_expr = Expr::Tpl(Tpl {
         span: DUMMY_SP,
@@ -2076,29 +2915,49 @@ mod tests {
     use super::*;
     use swc_core::ecma::visit::VisitMut;
 
+    /// Value of the `_hash` attribute injected on an element
+    fn injected_hash(element: &JSXElement) -> Option<String> {
+      element.opening.attrs.iter().find_map(|attr| match attr {
+        JSXAttrOrSpread::JSXAttr(JSXAttr {
+          name: JSXAttrName::Ident(name),
+          value: Some(JSXAttrValue::Str(value)),
+          ..
+        }) if name.sym == "_hash" => Some(value.value.to_string_lossy().into_owned()),
+        _ => None,
+      })
+    }
+
     /// Creates a visitor with autoderive jsx enabled and standard imports tracked
     fn create_visitor_with_autoderive() -> TransformVisitor {
-      let mut visitor =
-        TransformVisitor::new(LogLevel::Silent, false, None, false, false, false, StringCollector::new());
+      let mut visitor = TransformVisitor::new(
+        LogLevel::Silent,
+        false,
+        None,
+        false,
+        false,
+        false,
+        StringCollector::new(),
+      );
       visitor.settings.autoderive_jsx = true;
 
       // Track standard gt-next imports
       visitor
         .import_tracker
-        .scope_tracker
-        .track_translation_variable(Atom::new("T"), Atom::new("T"), 0);
-      visitor
-        .import_tracker
-        .scope_tracker
-        .track_translation_variable(Atom::new("useGT"), Atom::new("useGT"), 0);
+        .bindings
+        .track_translation_variable(id("T"), Atom::new("T"), 0);
+      visitor.import_tracker.bindings.track_translation_variable(
+        id("useGT"),
+        Atom::new("useGT"),
+        0,
+      );
       visitor
         .import_tracker
-        .scope_tracker
-        .track_translation_variable(Atom::new("gt"), Atom::new("gt"), 0);
+        .bindings
+        .track_translation_variable(id("gt"), Atom::new("gt"), 0);
       visitor
         .import_tracker
-        .scope_tracker
-        .track_translation_variable(Atom::new("t"), Atom::new("t"), 0);
+        .bindings
+        .track_translation_variable(id("t"), Atom::new("t"), 0);
 
       visitor
     }
@@ -2171,13 +3030,13 @@ mod tests {
       assert_eq!(visitor.statistics.dynamic_content_violations, 0);
     }
 
-    // ── Hash calculation tests: track_hash_attributes ──
+    // ── Hash calculation tests: inject_hash_attributes ──
 
     #[test]
     fn autoderive_on_dynamic_content_produces_empty_hash() {
       let mut visitor = TransformVisitor::new(
         LogLevel::Silent,
-        true,  // compile_time_hash = true
+        true, // compile_time_hash = true
         None,
         false, // disable_build_checks
         true,  // autoderive_jsx = true
@@ -2187,8 +3046,8 @@ mod tests {
       // Track T import
       visitor
         .import_tracker
-        .scope_tracker
-        .track_translation_variable(Atom::new("T"), Atom::new("T"), 0);
+        .bindings
+        .track_translation_variable(id("T"), Atom::new("T"), 0);
 
       let mut element = JSXElement {
         span: DUMMY_SP,
@@ -2224,19 +3083,18 @@ mod tests {
         }),
       };
 
-      visitor.track_hash_attributes(&mut element);
+      visitor.inject_hash_attributes(&mut element);
 
-      let counter_id = visitor.string_collector.get_counter();
-      let translation_jsx = visitor.string_collector.get_translation_jsx(counter_id);
-      assert!(translation_jsx.is_some(), "expected translation JSX to be stored");
-      assert_eq!(translation_jsx.unwrap().hash, "");
+      let hash = injected_hash(&element);
+      assert!(hash.is_some(), "expected the hash attribute to be injected");
+      assert_eq!(hash.unwrap(), "");
     }
 
     #[test]
     fn autoderive_on_nested_dynamic_content_produces_empty_hash() {
       let mut visitor = TransformVisitor::new(
         LogLevel::Silent,
-        true,  // compile_time_hash = true
+        true, // compile_time_hash = true
         None,
         false, // disable_build_checks
         true,  // autoderive_jsx = true
@@ -2246,8 +3104,8 @@ mod tests {
       // Track T import
       visitor
         .import_tracker
-        .scope_tracker
-        .track_translation_variable(Atom::new("T"), Atom::new("T"), 0);
+        .bindings
+        .track_translation_variable(id("T"), Atom::new("T"), 0);
 
       // Build <T><b>{name}</b></T>
       let mut element = JSXElement {
@@ -2308,19 +3166,18 @@ mod tests {
         }),
       };
 
-      visitor.track_hash_attributes(&mut element);
+      visitor.inject_hash_attributes(&mut element);
 
-      let counter_id = visitor.string_collector.get_counter();
-      let translation_jsx = visitor.string_collector.get_translation_jsx(counter_id);
-      assert!(translation_jsx.is_some(), "expected translation JSX to be stored");
-      assert_eq!(translation_jsx.unwrap().hash, "");
+      let hash = injected_hash(&element);
+      assert!(hash.is_some(), "expected the hash attribute to be injected");
+      assert_eq!(hash.unwrap(), "");
     }
 
     #[test]
     fn autoderive_on_static_content_produces_nonempty_hash() {
       let mut visitor = TransformVisitor::new(
         LogLevel::Silent,
-        true,  // compile_time_hash = true
+        true, // compile_time_hash = true
         None,
         false, // disable_build_checks
         true,  // autoderive_jsx = true
@@ -2330,8 +3187,8 @@ mod tests {
       // Track T import
       visitor
         .import_tracker
-        .scope_tracker
-        .track_translation_variable(Atom::new("T"), Atom::new("T"), 0);
+        .bindings
+        .track_translation_variable(id("T"), Atom::new("T"), 0);
 
       let mut element = JSXElement {
         span: DUMMY_SP,
@@ -2363,13 +3220,15 @@ mod tests {
         }),
       };
 
-      visitor.track_hash_attributes(&mut element);
+      visitor.inject_hash_attributes(&mut element);
 
-      let counter_id = visitor.string_collector.get_counter();
-      let translation_jsx = visitor.string_collector.get_translation_jsx(counter_id);
-      assert!(translation_jsx.is_some(), "expected translation JSX to be stored");
-      let hash = &translation_jsx.unwrap().hash;
-      assert!(!hash.is_empty(), "expected non-empty hash for static content");
+      let hash = injected_hash(&element);
+      assert!(hash.is_some(), "expected the hash attribute to be injected");
+      let hash = hash.unwrap();
+      assert!(
+        !hash.is_empty(),
+        "expected non-empty hash for static content"
+      );
       assert_eq!(hash.len(), 16, "expected hash to be 16 hex characters");
       assert!(
         hash.chars().all(|c| c.is_ascii_hexdigit()),
@@ -2389,13 +3248,20 @@ mod tests {
         expr: JSXExpr::Expr(Box::new(Expr::Cond(CondExpr {
           span: DUMMY_SP,
           test: Box::new(Expr::Ident(Ident {
-            span: DUMMY_SP, sym: Atom::new("condition"), optional: false, ctxt: SyntaxContext::empty(),
+            span: DUMMY_SP,
+            sym: Atom::new("condition"),
+            optional: false,
+            ctxt: SyntaxContext::empty(),
           })),
           cons: Box::new(Expr::Lit(Lit::Str(Str {
-            span: DUMMY_SP, value: Atom::new("yes").into(), raw: None,
+            span: DUMMY_SP,
+            value: Atom::new("yes").into(),
+            raw: None,
           }))),
           alt: Box::new(Expr::Lit(Lit::Str(Str {
-            span: DUMMY_SP, value: Atom::new("no").into(), raw: None,
+            span: DUMMY_SP,
+            value: Atom::new("no").into(),
+            raw: None,
           }))),
         }))),
       };
@@ -2413,9 +3279,15 @@ mod tests {
         expr: JSXExpr::Expr(Box::new(Expr::Member(MemberExpr {
           span: DUMMY_SP,
           obj: Box::new(Expr::Ident(Ident {
-            span: DUMMY_SP, sym: Atom::new("user"), optional: false, ctxt: SyntaxContext::empty(),
+            span: DUMMY_SP,
+            sym: Atom::new("user"),
+            optional: false,
+            ctxt: SyntaxContext::empty(),
           })),
-          prop: MemberProp::Ident(IdentName { span: DUMMY_SP, sym: Atom::new("name") }),
+          prop: MemberProp::Ident(IdentName {
+            span: DUMMY_SP,
+            sym: Atom::new("name"),
+          }),
         }))),
       };
       visitor.visit_mut_jsx_expr_container(&mut expr_container);
@@ -2430,7 +3302,9 @@ mod tests {
       let mut expr_container = JSXExprContainer {
         span: DUMMY_SP,
         expr: JSXExpr::Expr(Box::new(Expr::Lit(Lit::Str(Str {
-          span: DUMMY_SP, value: Atom::new("static").into(), raw: None,
+          span: DUMMY_SP,
+          value: Atom::new("static").into(),
+          raw: None,
         })))),
       };
       visitor.visit_mut_jsx_expr_container(&mut expr_container);
@@ -2445,7 +3319,9 @@ mod tests {
       let mut expr_container = JSXExprContainer {
         span: DUMMY_SP,
         expr: JSXExpr::Expr(Box::new(Expr::Lit(Lit::Num(Number {
-          span: DUMMY_SP, value: 42.0, raw: None,
+          span: DUMMY_SP,
+          value: 42.0,
+          raw: None,
         })))),
       };
       visitor.visit_mut_jsx_expr_container(&mut expr_container);
@@ -2460,13 +3336,19 @@ mod tests {
       let mut expr1 = JSXExprContainer {
         span: DUMMY_SP,
         expr: JSXExpr::Expr(Box::new(Expr::Ident(Ident {
-          span: DUMMY_SP, sym: Atom::new("firstName"), optional: false, ctxt: SyntaxContext::empty(),
+          span: DUMMY_SP,
+          sym: Atom::new("firstName"),
+          optional: false,
+          ctxt: SyntaxContext::empty(),
         }))),
       };
       let mut expr2 = JSXExprContainer {
         span: DUMMY_SP,
         expr: JSXExpr::Expr(Box::new(Expr::Ident(Ident {
-          span: DUMMY_SP, sym: Atom::new("lastName"), optional: false, ctxt: SyntaxContext::empty(),
+          span: DUMMY_SP,
+          sym: Atom::new("lastName"),
+          optional: false,
+          ctxt: SyntaxContext::empty(),
         }))),
       };
       visitor.visit_mut_jsx_expr_container(&mut expr1);
@@ -2482,13 +3364,19 @@ mod tests {
       let mut expr1 = JSXExprContainer {
         span: DUMMY_SP,
         expr: JSXExpr::Expr(Box::new(Expr::Ident(Ident {
-          span: DUMMY_SP, sym: Atom::new("firstName"), optional: false, ctxt: SyntaxContext::empty(),
+          span: DUMMY_SP,
+          sym: Atom::new("firstName"),
+          optional: false,
+          ctxt: SyntaxContext::empty(),
         }))),
       };
       let mut expr2 = JSXExprContainer {
         span: DUMMY_SP,
         expr: JSXExpr::Expr(Box::new(Expr::Ident(Ident {
-          span: DUMMY_SP, sym: Atom::new("lastName"), optional: false, ctxt: SyntaxContext::empty(),
+          span: DUMMY_SP,
+          sym: Atom::new("lastName"),
+          optional: false,
+          ctxt: SyntaxContext::empty(),
         }))),
       };
       visitor.visit_mut_jsx_expr_container(&mut expr1);
@@ -2504,7 +3392,10 @@ mod tests {
       let mut expr_container = JSXExprContainer {
         span: DUMMY_SP,
         expr: JSXExpr::Expr(Box::new(Expr::Ident(Ident {
-          span: DUMMY_SP, sym: Atom::new("name"), optional: false, ctxt: SyntaxContext::empty(),
+          span: DUMMY_SP,
+          sym: Atom::new("name"),
+          optional: false,
+          ctxt: SyntaxContext::empty(),
         }))),
       };
       visitor.visit_mut_jsx_expr_container(&mut expr_container);
@@ -2520,7 +3411,10 @@ mod tests {
       let mut expr_container = JSXExprContainer {
         span: DUMMY_SP,
         expr: JSXExpr::Expr(Box::new(Expr::Ident(Ident {
-          span: DUMMY_SP, sym: Atom::new("name"), optional: false, ctxt: SyntaxContext::empty(),
+          span: DUMMY_SP,
+          sym: Atom::new("name"),
+          optional: false,
+          ctxt: SyntaxContext::empty(),
         }))),
       };
       visitor.visit_mut_jsx_expr_container(&mut expr_container);
@@ -2532,128 +3426,228 @@ mod tests {
     #[test]
     fn autoderive_on_mixed_static_and_dynamic_produces_empty_hash() {
       let mut visitor = TransformVisitor::new(
-        LogLevel::Silent, true, None, false, true, false, StringCollector::new(),
+        LogLevel::Silent,
+        true,
+        None,
+        false,
+        true,
+        false,
+        StringCollector::new(),
       );
-      visitor.import_tracker.scope_tracker
-        .track_translation_variable(Atom::new("T"), Atom::new("T"), 0);
+      visitor
+        .import_tracker
+        .bindings
+        .track_translation_variable(id("T"), Atom::new("T"), 0);
 
       let mut element = JSXElement {
         span: DUMMY_SP,
         opening: JSXOpeningElement {
           span: DUMMY_SP,
-          name: JSXElementName::Ident(Ident { span: DUMMY_SP, sym: Atom::new("T"), optional: false, ctxt: SyntaxContext::empty() }),
-          attrs: vec![], self_closing: false, type_args: None,
+          name: JSXElementName::Ident(Ident {
+            span: DUMMY_SP,
+            sym: Atom::new("T"),
+            optional: false,
+            ctxt: SyntaxContext::empty(),
+          }),
+          attrs: vec![],
+          self_closing: false,
+          type_args: None,
         },
         children: vec![
-          JSXElementChild::JSXText(JSXText { span: DUMMY_SP, value: Atom::new("Hello "), raw: Atom::new("Hello ") }),
+          JSXElementChild::JSXText(JSXText {
+            span: DUMMY_SP,
+            value: Atom::new("Hello "),
+            raw: Atom::new("Hello "),
+          }),
           JSXElementChild::JSXExprContainer(JSXExprContainer {
             span: DUMMY_SP,
-            expr: JSXExpr::Expr(Box::new(Expr::Ident(Ident { span: DUMMY_SP, sym: Atom::new("name"), optional: false, ctxt: SyntaxContext::empty() }))),
+            expr: JSXExpr::Expr(Box::new(Expr::Ident(Ident {
+              span: DUMMY_SP,
+              sym: Atom::new("name"),
+              optional: false,
+              ctxt: SyntaxContext::empty(),
+            }))),
           }),
         ],
         closing: Some(JSXClosingElement {
           span: DUMMY_SP,
-          name: JSXElementName::Ident(Ident { span: DUMMY_SP, sym: Atom::new("T"), optional: false, ctxt: SyntaxContext::empty() }),
+          name: JSXElementName::Ident(Ident {
+            span: DUMMY_SP,
+            sym: Atom::new("T"),
+            optional: false,
+            ctxt: SyntaxContext::empty(),
+          }),
         }),
       };
 
-      visitor.track_hash_attributes(&mut element);
-      let counter_id = visitor.string_collector.get_counter();
-      let translation_jsx = visitor.string_collector.get_translation_jsx(counter_id);
-      assert!(translation_jsx.is_some());
-      assert_eq!(translation_jsx.unwrap().hash, "");
+      visitor.inject_hash_attributes(&mut element);
+      let hash = injected_hash(&element);
+      assert!(hash.is_some());
+      assert_eq!(hash.unwrap(), "");
     }
 
     #[test]
     fn autoderive_on_number_literal_child_produces_nonempty_hash() {
       let mut visitor = TransformVisitor::new(
-        LogLevel::Silent, true, None, false, true, false, StringCollector::new(),
+        LogLevel::Silent,
+        true,
+        None,
+        false,
+        true,
+        false,
+        StringCollector::new(),
       );
-      visitor.import_tracker.scope_tracker
-        .track_translation_variable(Atom::new("T"), Atom::new("T"), 0);
+      visitor
+        .import_tracker
+        .bindings
+        .track_translation_variable(id("T"), Atom::new("T"), 0);
 
       let mut element = JSXElement {
         span: DUMMY_SP,
         opening: JSXOpeningElement {
           span: DUMMY_SP,
-          name: JSXElementName::Ident(Ident { span: DUMMY_SP, sym: Atom::new("T"), optional: false, ctxt: SyntaxContext::empty() }),
-          attrs: vec![], self_closing: false, type_args: None,
-        },
-        children: vec![
-          JSXElementChild::JSXExprContainer(JSXExprContainer {
+          name: JSXElementName::Ident(Ident {
             span: DUMMY_SP,
-            expr: JSXExpr::Expr(Box::new(Expr::Lit(Lit::Num(Number { span: DUMMY_SP, value: 42.0, raw: None })))),
+            sym: Atom::new("T"),
+            optional: false,
+            ctxt: SyntaxContext::empty(),
           }),
-        ],
+          attrs: vec![],
+          self_closing: false,
+          type_args: None,
+        },
+        children: vec![JSXElementChild::JSXExprContainer(JSXExprContainer {
+          span: DUMMY_SP,
+          expr: JSXExpr::Expr(Box::new(Expr::Lit(Lit::Num(Number {
+            span: DUMMY_SP,
+            value: 42.0,
+            raw: None,
+          })))),
+        })],
         closing: Some(JSXClosingElement {
           span: DUMMY_SP,
-          name: JSXElementName::Ident(Ident { span: DUMMY_SP, sym: Atom::new("T"), optional: false, ctxt: SyntaxContext::empty() }),
+          name: JSXElementName::Ident(Ident {
+            span: DUMMY_SP,
+            sym: Atom::new("T"),
+            optional: false,
+            ctxt: SyntaxContext::empty(),
+          }),
         }),
       };
 
-      visitor.track_hash_attributes(&mut element);
-      let counter_id = visitor.string_collector.get_counter();
-      let translation_jsx = visitor.string_collector.get_translation_jsx(counter_id);
-      assert!(translation_jsx.is_some());
-      let hash = &translation_jsx.unwrap().hash;
-      assert!(!hash.is_empty(), "number literal is allowed content — should produce non-empty hash");
+      visitor.inject_hash_attributes(&mut element);
+      let hash = injected_hash(&element);
+      assert!(hash.is_some());
+      let hash = hash.unwrap();
+      assert!(
+        !hash.is_empty(),
+        "number literal is allowed content — should produce non-empty hash"
+      );
       assert_eq!(hash.len(), 16);
     }
 
     #[test]
     fn autoderive_on_3_level_deep_nesting_produces_empty_hash() {
       let mut visitor = TransformVisitor::new(
-        LogLevel::Silent, true, None, false, true, false, StringCollector::new(),
+        LogLevel::Silent,
+        true,
+        None,
+        false,
+        true,
+        false,
+        StringCollector::new(),
       );
-      visitor.import_tracker.scope_tracker
-        .track_translation_variable(Atom::new("T"), Atom::new("T"), 0);
+      visitor
+        .import_tracker
+        .bindings
+        .track_translation_variable(id("T"), Atom::new("T"), 0);
 
       let mut element = JSXElement {
         span: DUMMY_SP,
         opening: JSXOpeningElement {
           span: DUMMY_SP,
-          name: JSXElementName::Ident(Ident { span: DUMMY_SP, sym: Atom::new("T"), optional: false, ctxt: SyntaxContext::empty() }),
-          attrs: vec![], self_closing: false, type_args: None,
+          name: JSXElementName::Ident(Ident {
+            span: DUMMY_SP,
+            sym: Atom::new("T"),
+            optional: false,
+            ctxt: SyntaxContext::empty(),
+          }),
+          attrs: vec![],
+          self_closing: false,
+          type_args: None,
         },
         children: vec![JSXElementChild::JSXElement(Box::new(JSXElement {
           span: DUMMY_SP,
           opening: JSXOpeningElement {
             span: DUMMY_SP,
-            name: JSXElementName::Ident(Ident { span: DUMMY_SP, sym: Atom::new("div"), optional: false, ctxt: SyntaxContext::empty() }),
-            attrs: vec![], self_closing: false, type_args: None,
+            name: JSXElementName::Ident(Ident {
+              span: DUMMY_SP,
+              sym: Atom::new("div"),
+              optional: false,
+              ctxt: SyntaxContext::empty(),
+            }),
+            attrs: vec![],
+            self_closing: false,
+            type_args: None,
           },
           children: vec![JSXElementChild::JSXElement(Box::new(JSXElement {
             span: DUMMY_SP,
             opening: JSXOpeningElement {
               span: DUMMY_SP,
-              name: JSXElementName::Ident(Ident { span: DUMMY_SP, sym: Atom::new("span"), optional: false, ctxt: SyntaxContext::empty() }),
-              attrs: vec![], self_closing: false, type_args: None,
+              name: JSXElementName::Ident(Ident {
+                span: DUMMY_SP,
+                sym: Atom::new("span"),
+                optional: false,
+                ctxt: SyntaxContext::empty(),
+              }),
+              attrs: vec![],
+              self_closing: false,
+              type_args: None,
             },
             children: vec![JSXElementChild::JSXExprContainer(JSXExprContainer {
               span: DUMMY_SP,
-              expr: JSXExpr::Expr(Box::new(Expr::Ident(Ident { span: DUMMY_SP, sym: Atom::new("name"), optional: false, ctxt: SyntaxContext::empty() }))),
+              expr: JSXExpr::Expr(Box::new(Expr::Ident(Ident {
+                span: DUMMY_SP,
+                sym: Atom::new("name"),
+                optional: false,
+                ctxt: SyntaxContext::empty(),
+              }))),
             })],
             closing: Some(JSXClosingElement {
               span: DUMMY_SP,
-              name: JSXElementName::Ident(Ident { span: DUMMY_SP, sym: Atom::new("span"), optional: false, ctxt: SyntaxContext::empty() }),
+              name: JSXElementName::Ident(Ident {
+                span: DUMMY_SP,
+                sym: Atom::new("span"),
+                optional: false,
+                ctxt: SyntaxContext::empty(),
+              }),
             }),
           }))],
           closing: Some(JSXClosingElement {
             span: DUMMY_SP,
-            name: JSXElementName::Ident(Ident { span: DUMMY_SP, sym: Atom::new("div"), optional: false, ctxt: SyntaxContext::empty() }),
+            name: JSXElementName::Ident(Ident {
+              span: DUMMY_SP,
+              sym: Atom::new("div"),
+              optional: false,
+              ctxt: SyntaxContext::empty(),
+            }),
           }),
         }))],
         closing: Some(JSXClosingElement {
           span: DUMMY_SP,
-          name: JSXElementName::Ident(Ident { span: DUMMY_SP, sym: Atom::new("T"), optional: false, ctxt: SyntaxContext::empty() }),
+          name: JSXElementName::Ident(Ident {
+            span: DUMMY_SP,
+            sym: Atom::new("T"),
+            optional: false,
+            ctxt: SyntaxContext::empty(),
+          }),
         }),
       };
 
-      visitor.track_hash_attributes(&mut element);
-      let counter_id = visitor.string_collector.get_counter();
-      let translation_jsx = visitor.string_collector.get_translation_jsx(counter_id);
-      assert!(translation_jsx.is_some());
-      assert_eq!(translation_jsx.unwrap().hash, "");
+      visitor.inject_hash_attributes(&mut element);
+      let hash = injected_hash(&element);
+      assert!(hash.is_some());
+      assert_eq!(hash.unwrap(), "");
     }
   }
 
@@ -2704,11 +3698,11 @@ mod tests {
 
       visitor.track_variable_assignment(&var_declarator);
 
-      // Should not track non-function assignments in scope tracker
+      // Should not track non-function assignments in binding tracker
       assert!(visitor
         .import_tracker
-        .scope_tracker
-        .get_translation_variable(&Atom::new("message"))
+        .bindings
+        .get_translation_variable(&id("message"))
         .is_none());
     }
 
@@ -2719,11 +3713,11 @@ mod tests {
 
       visitor.track_variable_assignment(&var_declarator);
 
-      // Should not track non-translation functions in scope tracker
+      // Should not track non-translation functions in binding tracker
       assert!(visitor
         .import_tracker
-        .scope_tracker
-        .get_translation_variable(&Atom::new("result"))
+        .bindings
+        .get_translation_variable(&id("result"))
         .is_none());
     }
   }
@@ -2769,11 +3763,18 @@ mod tests {
 
     #[test]
     fn calculates_hash_for_empty_element() {
-      let mut visitor =
-        TransformVisitor::new(LogLevel::Silent, false, None, false, false, false, StringCollector::new());
+      let visitor = TransformVisitor::new(
+        LogLevel::Silent,
+        false,
+        None,
+        false,
+        false,
+        false,
+        StringCollector::new(),
+      );
       let element = create_jsx_element("T", vec![]);
 
-      let mut traversal = crate::ast::JsxTraversal::new(&mut visitor);
+      let mut traversal = crate::ast::JsxTraversal::new(&visitor);
       let (hash, json_string) = traversal.calculate_element_hash(&element);
 
       assert!(!hash.is_empty());
@@ -2783,8 +3784,15 @@ mod tests {
 
     #[test]
     fn hash_changes_with_different_content() {
-      let mut visitor =
-        TransformVisitor::new(LogLevel::Silent, false, None, false, false, false, StringCollector::new());
+      let visitor = TransformVisitor::new(
+        LogLevel::Silent,
+        false,
+        None,
+        false,
+        false,
+        false,
+        StringCollector::new(),
+      );
 
       let element1 = create_jsx_element("T", vec![]);
       let mut element2 = create_jsx_element("T", vec![]);
@@ -2794,10 +3802,10 @@ mod tests {
         raw: Atom::new("Hello"),
       })];
 
-      let mut traversal1 = crate::ast::JsxTraversal::new(&mut visitor);
+      let mut traversal1 = crate::ast::JsxTraversal::new(&visitor);
       let (hash1, _) = traversal1.calculate_element_hash(&element1);
 
-      let mut traversal2 = crate::ast::JsxTraversal::new(&mut visitor);
+      let mut traversal2 = crate::ast::JsxTraversal::new(&visitor);
       let (hash2, _) = traversal2.calculate_element_hash(&element2);
 
       assert_ne!(hash1, hash2);
@@ -2841,7 +3849,8 @@ mod tests {
         visitor.calculate_hash_for_call_expr(&different_message, Some(&first_options));
       assert_ne!(first_hash, content_hash, "Content should change hash");
 
-      visitor.string_collector.initialize_aggregator(0);
+      let counter_id = visitor.string_collector.increment_counter();
+      visitor.string_collector.initialize_aggregator(counter_id);
       let call_expr = CallExpr {
         span: DUMMY_SP,
         ctxt: SyntaxContext::empty(),
@@ -2854,9 +3863,12 @@ mod tests {
         args: vec![message.clone(), first_options.clone()],
         type_args: None,
       };
-      visitor.track_translation_callback(&call_expr, &message, 0);
+      visitor.track_translation_callback(&call_expr, &message, counter_id);
 
-      let collected = visitor.string_collector.get_translation_data(0).unwrap();
+      let collected = visitor
+        .string_collector
+        .get_translation_data(counter_id)
+        .unwrap();
       assert_eq!(collected.content[0].id.as_deref(), Some("first-id"));
     }
   }
@@ -2902,7 +3914,7 @@ mod tests {
       assert!(is_translation_ns);
 
       // Test warning generation
-      let warning = create_dynamic_content_warning(Some("test.tsx"), "T");
+      let warning = create_dynamic_content_warning("T", DUMMY_SP).to_log_message(Some("test.tsx"));
       assert!(warning.contains("in test.tsx"));
     }
   }
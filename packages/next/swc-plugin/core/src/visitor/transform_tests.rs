@@ -0,0 +1,652 @@
+use crate::config::PluginConfig;
+use crate::test_utils::{
+  config, hash_of, injected_hash, parse, transform_with_diagnostics, ReportedDiagnostic,
+};
+use swc_core::ecma::codegen::to_code;
+
+mod message_function {
+  use super::*;
+
+  #[test]
+  fn injects_hash_into_msg_calls() {
+    let source = r#"
+      import { msg } from 'gt-next';
+      const greeting = msg("Hello, world!", { $context: "homepage" });
+    "#;
+    let (output, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+    assert!(diagnostics.is_empty());
+    assert!(output.contains("$context: \"homepage\""));
+    assert!(!injected_hash(&output).is_empty());
+  }
+
+  #[test]
+  fn msg_and_t_hash_the_same_message_identically() {
+    let (msg_output, _) = transform_with_diagnostics(
+      "import { msg } from 'gt-i18n';\nconst m = msg('Save');\n",
+      &config(true, false),
+    );
+    let (t_output, _) = transform_with_diagnostics(
+      "import { useGT } from 'gt-next';\nconst t = useGT();\nt('Save');\n",
+      &config(true, false),
+    );
+
+    assert_eq!(injected_hash(&msg_output), injected_hash(&t_output));
+  }
+
+  #[test]
+  fn validates_msg_arguments() {
+    let source = "import { msg as m } from 'gt-next';\nconst a = m('Hi ' + name);\n";
+    let (output, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code.as_deref(), Some("GT002"));
+    assert!(diagnostics[0].message.contains("m() function call"));
+    assert!(!output.contains("$_hash"));
+  }
+
+  #[test]
+  fn checks_each_entry_of_a_message_array() {
+    let source = "import { msg } from 'gt-next';\n\
+      const greetings = msg(['Hello, Alice!', `Hello, Bob!`], { $id: 'greetings' });\n\
+      const broken = msg(['Hi', 'Hi ' + name, '{count, plural, one {#}']);\n";
+    let (output, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+    let codes: Vec<_> = diagnostics.iter().map(|d| d.code.as_deref()).collect();
+    assert_eq!(codes, [Some("GT002"), Some("GT009")]);
+    assert_eq!(diagnostics[0].line, 3);
+    // The runtime hashes every entry itself, a shared $_hash would be wrong for all but one
+    assert!(!output.contains("$_hash"));
+  }
+
+  #[test]
+  fn ignores_shadowed_msg() {
+    let source = r#"
+      import { msg } from 'gt-next';
+      function format(msg) {
+        return msg('Hi ' + name);
+      }
+    "#;
+    let (output, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+    assert!(diagnostics.is_empty());
+    assert!(!output.contains("$_hash"));
+  }
+}
+
+mod module_translation_function {
+  use super::*;
+
+  #[test]
+  fn hashes_direct_calls() {
+    let source = "import { t } from 'gt-i18n';\nconst a = t('Hello', { $context: 'greeting' });\n";
+    let (output, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+    assert!(diagnostics.is_empty());
+    assert!(output.contains("$context: 'greeting'"));
+    assert!(!hash_of(source).is_empty());
+  }
+
+  #[test]
+  fn leaves_tagged_templates_as_they_are() {
+    let source = "import { t } from 'gt-i18n';\nconst a = t`Hello ${user.name}, you have ${count} messages`;\n";
+    let (output, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+    // The runtime looks up `Hello ${name}` interpolated before "Hello {0}", a call would not
+    assert!(diagnostics.is_empty());
+    assert_eq!(output, to_code(&parse(source)));
+  }
+
+  #[test]
+  fn hashes_calls_nested_in_tagged_templates() {
+    let source = "import { t } from 'gt-i18n';\nt`Outer ${t('Inner')}`;\n";
+    let (output, _) = transform_with_diagnostics(source, &config(true, false));
+
+    let inner = hash_of("import { t } from 'gt-i18n';\nt('Inner');\n");
+    assert!(output.contains("t`Outer ${t('Inner', {"));
+    assert_eq!(injected_hash(&output), inner);
+  }
+
+  #[test]
+  fn leaves_derive_templates_to_the_cli() {
+    let source = "import { t, derive } from 'gt-i18n';\nconst a = t`Hello ${derive(getName())}`;\n";
+    let (output, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+    assert!(diagnostics.is_empty());
+    assert_eq!(output, to_code(&parse(source)));
+  }
+
+  #[test]
+  fn validates_derive_interpolations() {
+    let source = "import { t, derive } from 'gt-i18n';\nconst a = t`Hello ${derive(name)}`;\n";
+    let (_, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code.as_deref(), Some("GT003"));
+  }
+
+  #[test]
+  fn ignores_t_from_other_packages() {
+    let source = "import { t } from 'gt-next';\nconst a = t`Hello ${name}`;\nt('Hi ' + name);\n";
+    let (output, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+    assert!(diagnostics.is_empty());
+    assert_eq!(output, to_code(&parse(source)));
+  }
+}
+
+mod declare_var {
+  use super::*;
+
+  #[test]
+  fn hashes_template_literals_like_the_runtime_encoding() {
+    let source = "import { useGT, declareVar } from 'gt-next';
+const t = useGT();
+t(`Hello ${declareVar(name, { $name: 'user' })}`);
+";
+    let (_, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+    assert!(diagnostics.is_empty());
+    assert_eq!(
+      hash_of(source),
+      hash_of("import { useGT } from 'gt-next';\nconst t = useGT();\nt('Hello {_gt_1, select, other {} _gt_var_name {user}}');\n")
+    );
+  }
+
+  #[test]
+  fn hashes_concatenations() {
+    let source = "import { msg, declareVar } from 'gt-next';
+msg('Hi ' + declareVar(first) + ' and ' + declareVar(second));
+";
+    let (_, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+    assert!(diagnostics.is_empty());
+    assert_eq!(
+      hash_of(source),
+      hash_of("import { msg } from 'gt-next';\nmsg('Hi {_gt_1, select, other {}} and {_gt_2, select, other {}}');\n")
+    );
+  }
+
+  #[test]
+  fn reports_dynamic_names() {
+    let source = "import { useGT, declareVar } from 'gt-next';
+const t = useGT();
+t(`Hello ${declareVar(name, { $name: label })}`);
+";
+    let (_, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code.as_deref(), Some("GT002"));
+  }
+
+  #[test]
+  fn skips_non_icu_formats() {
+    let source = "import { useGT, declareVar } from 'gt-next';
+const t = useGT();
+t(`Hello ${declareVar(name)}`, { $format: 'STRING' });
+";
+    let (output, _) = transform_with_diagnostics(source, &config(true, false));
+
+    assert!(!output.contains("$_hash"));
+  }
+
+  #[test]
+  fn unhashable_calls_do_not_shift_later_hashes() {
+    let source = "import { useGT } from 'gt-next';
+const t = useGT();
+// gt-ignore-next-line
+t('Hi ' + name);
+t('Bye');
+";
+    let (output, _) = transform_with_diagnostics(source, &config(true, false));
+
+    let bye = hash_of("import { useGT } from 'gt-next';\nconst t = useGT();\nt('Bye');\n");
+    assert!(output.contains("t('Hi ' + name);"));
+    assert!(output.contains(&format!("t('Bye', {{\n    $_hash: \"{bye}\"")));
+  }
+}
+
+mod import_sources {
+  use super::*;
+  use crate::config::{ImportSourceConfig, ImportSourcePreset};
+
+  fn config_with_sources(sources: &str) -> PluginConfig {
+    let mut config: PluginConfig = serde_json::from_str(&format!(
+      r#"{{"compileTimeHash": true, "importSources": {sources}}}"#
+    ))
+    .unwrap();
+    config.log_level = crate::logging::LogLevel::Silent;
+    config
+  }
+
+  #[test]
+  fn deserializes_modules_and_wrappers() {
+    let config =
+      config_with_sources(r#"["@acme/i18n", {"source": "@acme/strings", "preset": "i18n"}]"#);
+
+    assert_eq!(
+      config.import_sources,
+      vec![
+        ImportSourceConfig::Module("@acme/i18n".to_string()),
+        ImportSourceConfig::Wrapper {
+          source: "@acme/strings".to_string(),
+          preset: ImportSourcePreset::I18n,
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn tracks_every_framework_package() {
+    for module in [
+      "gt-react",
+      "gt-react-native",
+      "gt-tanstack-start",
+      "gt-tanstack-start/server",
+    ] {
+      let source =
+        format!("import {{ useGT }} from '{module}';\nconst t = useGT();\nt('Hi ' + name);\n");
+      let (_, diagnostics) = transform_with_diagnostics(&source, &config(true, false));
+
+      assert_eq!(diagnostics.len(), 1, "expected a violation for {module}");
+    }
+  }
+
+  #[test]
+  fn gt_react_exports_the_module_translation_function() {
+    let source = "import { t } from 'gt-react';\nconst a = t('Hello');\n";
+    let (output, _) = transform_with_diagnostics(source, &config(true, false));
+
+    assert_eq!(
+      injected_hash(&output),
+      hash_of("import { t } from 'gt-i18n';\nt('Hello');\n")
+    );
+  }
+
+  #[test]
+  fn gt_tanstack_start_exports_the_module_translation_function() {
+    let source = "import { t } from 'gt-tanstack-start';\nconst a = t('Hello');\n";
+    let (output, _) = transform_with_diagnostics(source, &config(true, false));
+
+    assert_eq!(
+      injected_hash(&output),
+      hash_of("import { t } from 'gt-i18n';\nt('Hello');\n")
+    );
+  }
+
+  #[test]
+  fn tracks_configured_wrapper_modules() {
+    let source = "import { T } from '@acme/i18n';\nconst el = <T>Hello {user.name}</T>;\n";
+    let (_, untracked) = transform_with_diagnostics(source, &config(true, false));
+    let (_, tracked) =
+      transform_with_diagnostics(source, &config_with_sources(r#"["@acme/i18n"]"#));
+
+    assert!(untracked.is_empty());
+    assert_eq!(tracked.len(), 1);
+    assert_eq!(tracked[0].code.as_deref(), Some("GT001"));
+  }
+
+  #[test]
+  fn wrappers_inherit_their_preset() {
+    let source = "import { t } from '@acme/strings';\nconst a = t('Hello');\n";
+    let (as_next, _) =
+      transform_with_diagnostics(source, &config_with_sources(r#"["@acme/strings"]"#));
+    let (as_i18n, _) = transform_with_diagnostics(
+      source,
+      &config_with_sources(r#"[{"source": "@acme/strings", "preset": "i18n"}]"#),
+    );
+
+    assert_eq!(as_next, to_code(&parse(source)));
+    assert!(!injected_hash(&as_i18n).is_empty());
+  }
+}
+
+mod commonjs_imports {
+  use super::*;
+
+  #[test]
+  fn tracks_destructured_require() {
+    let source = "const { T, useGT: useTranslate } = require('gt-next');\nconst t = useTranslate();\nt('Hi ' + name);\nconst el = <T>Hello {user.name}</T>;\n";
+    let (_, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+    let codes: Vec<_> = diagnostics.iter().map(|d| d.code.as_deref()).collect();
+    assert_eq!(codes, vec![Some("GT002"), Some("GT001")]);
+  }
+
+  #[test]
+  fn tracks_namespace_require() {
+    let source = "const GT = require('gt-next');\nconst el = <GT.T>Hello {user.name}</GT.T>;\n";
+    let (_, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code.as_deref(), Some("GT001"));
+  }
+
+  #[test]
+  fn tracks_awaited_dynamic_import() {
+    let source = "async function Page() {\n  const { getGT } = await import('gt-next/server');\n  const t = await getGT();\n  return t('Hello');\n}\n";
+    let (output, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+    assert!(diagnostics.is_empty());
+    assert!(output.contains("$_hash"));
+  }
+
+  #[test]
+  fn ignores_other_modules() {
+    let source = "const { T } = require('./components');\nconst el = <T>Hello {user.name}</T>;\n";
+    let (_, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+    assert!(diagnostics.is_empty());
+  }
+
+  #[test]
+  fn required_bindings_shadow_outer_translators() {
+    let source = "import { useGT } from 'gt-next';\nconst t = useGT();\nfunction f() {\n  const { t } = require('gt-next');\n  return t('Hi ' + name);\n}\n";
+    let (_, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+    assert!(diagnostics.is_empty());
+  }
+}
+
+mod namespace_calls {
+  use super::*;
+
+  #[test]
+  fn tracks_namespace_translation_hooks() {
+    let source =
+      "import * as GT from 'gt-next';\nconst t = GT.useGT();\nt('Hello');\nt('Hi ' + name);\n";
+    let (output, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code.as_deref(), Some("GT002"));
+    assert!(output.contains("GT.useGT(["));
+    assert!(output.contains("message: \"Hello\""));
+  }
+
+  #[test]
+  fn tracks_awaited_namespace_calls() {
+    let source = "import * as GTServer from 'gt-next/server';\nasync function Page() {\n  const t = await GTServer.getGT();\n  return t('Hello');\n}\n";
+    let (output, _) = transform_with_diagnostics(source, &config(true, false));
+
+    assert!(output.contains("GTServer.getGT(["));
+    assert!(output.contains("$_hash"));
+  }
+
+  #[test]
+  fn tracks_namespace_msg_and_derive() {
+    let source = "const GT = require('gt-next');\nGT.msg(`Hi ${GT.derive(getName())}`);\nGT.msg('Hi ' + name);\n";
+    let (_, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].message.contains("GT.msg"));
+  }
+
+  #[test]
+  fn tracks_namespace_module_translation_function() {
+    let source = "import * as I18n from 'gt-i18n';\nI18n.t('Hello');\nI18n.t`Hello ${name}`;\n";
+    let (output, _) = transform_with_diagnostics(source, &config(true, false));
+
+    assert!(output.contains("I18n.t('Hello', {"));
+    assert!(output.contains("I18n.t`Hello ${name}`"));
+  }
+
+  #[test]
+  fn ignores_shadowed_namespaces() {
+    let source = "import * as GT from 'gt-next';\nfunction f(GT) {\n  const t = GT.useGT();\n  return t('Hi ' + name);\n}\n";
+    let (_, diagnostics) = transform_with_diagnostics(source, &config(true, false));
+
+    assert!(diagnostics.is_empty());
+  }
+}
+
+mod auto_wrap_var {
+  use super::*;
+
+  fn wrap(source: &str) -> (String, Vec<ReportedDiagnostic>) {
+    let config = PluginConfig {
+      auto_wrap_var: true,
+      ..config(true, false)
+    };
+    transform_with_diagnostics(source, &config)
+  }
+
+  #[test]
+  fn wraps_with_the_imported_alias() {
+    let source = "import { T, Var as V } from 'gt-next';
+const el = <T>Hello {user.name}</T>;
+";
+    let (output, diagnostics) = wrap(source);
+
+    assert!(diagnostics.is_empty());
+    assert!(output.contains("Hello <V>{user.name}</V></T>"));
+    assert!(!output.contains("GTVar"));
+  }
+
+  #[test]
+  fn imports_var_when_missing() {
+    let source = "'use client';
+import { T } from 'gt-react';
+const el = <T>Hello {user.name}</T>;
+";
+    let (output, diagnostics) = wrap(source);
+
+    assert!(diagnostics.is_empty());
+    assert!(output.starts_with("'use client';\nimport { Var as GTVar } from \"gt-react\";"));
+    assert!(output.contains("<GTVar>{user.name}</GTVar>"));
+  }
+
+  #[test]
+  fn wraps_namespace_components_with_the_namespace() {
+    let source = "import * as GT from 'gt-next';
+const el = <GT.T>Hi <>{name}</></GT.T>;
+";
+    let (output, diagnostics) = wrap(source);
+
+    assert!(diagnostics.is_empty());
+    assert!(output.contains("<><GT.Var>{name}</GT.Var></>"));
+  }
+
+  #[test]
+  fn hashes_like_hand_written_var() {
+    let (wrapped, _) = wrap(
+      "import { T } from 'gt-next';
+const el = <T>Hello {user.name}</T>;
+",
+    );
+    let (written, _) = wrap(
+      "import { T, Var } from 'gt-next';
+const el = <T>Hello <Var>{user.name}</Var></T>;
+",
+    );
+
+    assert_eq!(injected_hash(&wrapped), injected_hash(&written));
+  }
+
+  #[test]
+  fn leaves_static_content_alone() {
+    let source = "import { T } from 'gt-next';
+const el = <T>Hello {\"world\"}</T>;
+";
+    let (output, _) = wrap(source);
+
+    assert!(!output.contains("Var"));
+  }
+
+  #[test]
+  fn reports_content_it_cannot_wrap() {
+    // A CommonJS script cannot receive the generated import
+    let source = "const { T } = require('gt-next');\nconst el = <T>Hello {user.name}</T>;\n";
+    let (output, diagnostics) = wrap(source);
+
+    assert!(!output.contains("GTVar"));
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code.as_deref(), Some("GT001"));
+  }
+}
+
+mod auto_icu_templates {
+  use super::*;
+
+  fn rewrite(source: &str) -> (String, Vec<ReportedDiagnostic>) {
+    let config = PluginConfig {
+      auto_icu_templates: true,
+      ..config(true, false)
+    };
+    transform_with_diagnostics(source, &config)
+  }
+
+  #[test]
+  fn rewrites_interpolations_into_named_placeholders() {
+    let source = "import { useGT } from 'gt-next';\nconst t = useGT();\nt(`Hello ${user.name}, you have ${count} items`);\n";
+    let (output, diagnostics) = rewrite(source);
+
+    assert!(diagnostics.is_empty());
+    assert!(output.contains("t(\"Hello {name}, you have {count} items\", {\n    name: user.name,\n    count,\n    $_hash: \""));
+    assert!(output.contains("message: \"Hello {name}, you have {count} items\""));
+  }
+
+  #[test]
+  fn hashes_like_the_hand_written_icu_message() {
+    let (rewritten, _) = rewrite(
+      "import { useGT } from 'gt-next';\nconst t = useGT();\nt(`Hi ${name}`, { $context: 'greeting' });\n",
+    );
+    let (written, _) = rewrite(
+      "import { useGT } from 'gt-next';\nconst t = useGT();\nt('Hi {name}', { name, $context: 'greeting' });\n",
+    );
+
+    assert_eq!(injected_hash(&rewritten), injected_hash(&written));
+  }
+
+  #[test]
+  fn falls_back_to_positions() {
+    let source = "import { msg } from 'gt-next';\nmsg(`${a.name} and ${b.name} own ${count()}, ${a.name}'s ${'${x}'}`);\n";
+    let (output, diagnostics) = rewrite(source);
+
+    assert!(diagnostics.is_empty());
+    assert!(output.contains("msg(\"{name} and {1} own {2}, {name}'s {4}\""));
+  }
+
+  #[test]
+  fn escapes_apostrophes_before_placeholders() {
+    let source = "import { useGT } from 'gt-next';\nconst t = useGT();\nt(`Say '${word}'`);\n";
+    let (output, _) = rewrite(source);
+
+    assert!(output.contains("t(\"Say ''{word}'\""));
+  }
+
+  #[test]
+  fn leaves_templates_it_cannot_rewrite_to_the_checks() {
+    let cases = [
+      "import { useGT } from 'gt-next';\nconst t = useGT();\nt(`Hi {${name}}`);\n",
+      "import { useGT } from 'gt-next';\nconst t = useGT();\nt(`Hi ${name}`, opts);\n",
+      "import { useGT } from 'gt-next';\nconst t = useGT();\nt(`Hi ${name}`, { $format: 'STRING' });\n",
+    ];
+    for source in cases {
+      let (output, diagnostics) = rewrite(source);
+
+      assert!(output.contains("t(`Hi "), "{source}");
+      assert_eq!(diagnostics.len(), 1, "{source}");
+      assert_eq!(diagnostics[0].code.as_deref(), Some("GT002"));
+    }
+  }
+
+  #[test]
+  fn leaves_derive_templates_alone() {
+    let source = "import { useGT, derive } from 'gt-next';\nconst t = useGT();\nt(`Hi ${derive(getName())}`);\n";
+    let (output, diagnostics) = rewrite(source);
+
+    assert!(diagnostics.is_empty());
+    assert!(output.contains("t(`Hi ${derive(getName())}`"));
+  }
+}
+
+mod branch_components {
+  use super::*;
+
+  fn branch_diagnostics(source: &str) -> Vec<ReportedDiagnostic> {
+    let (_, diagnostics) = transform_with_diagnostics(source, &config(false, false));
+    diagnostics
+      .into_iter()
+      .filter(|diagnostic| {
+        matches!(
+          diagnostic.code.as_deref(),
+          Some("GT016" | "GT017" | "GT018" | "GT019")
+        )
+      })
+      .collect()
+  }
+
+  fn codes(diagnostics: &[ReportedDiagnostic]) -> Vec<&str> {
+    diagnostics
+      .iter()
+      .filter_map(|diagnostic| diagnostic.code.as_deref())
+      .collect()
+  }
+
+  #[test]
+  fn accepts_complete_branches() {
+    let source = "import { Branch } from 'gt-next';\n\
+      export const el = <Branch branch={role} admin=\"Admin\" user={<b>User</b>}>\n\
+      Guest</Branch>;\n";
+
+    assert!(branch_diagnostics(source).is_empty());
+  }
+
+  #[test]
+  fn reports_missing_branch_prop() {
+    let source = "import { Branch } from 'gt-next';\n\
+      export const el = <Branch admin=\"Admin\">Guest</Branch>;\n";
+    let diagnostics = branch_diagnostics(source);
+
+    assert_eq!(codes(&diagnostics), vec!["GT016"]);
+    assert!(!diagnostics[0].is_error());
+    assert_eq!((diagnostics[0].line, diagnostics[0].col), (2, 20));
+  }
+
+  #[test]
+  fn reports_literal_keys_without_a_branch() {
+    let source = "import { Branch as Choice } from 'gt-next';\n\
+      export const a = <Choice branch=\"owner\" admin=\"Admin\">Guest</Choice>;\n\
+      export const b = <Choice branch=\"data-admin\" data-admin=\"Admin\">Guest</Choice>;\n";
+    let diagnostics = branch_diagnostics(source);
+
+    assert_eq!(codes(&diagnostics), vec!["GT017", "GT017"]);
+    assert!(diagnostics[0].message.contains("no \"owner\" branch"));
+    assert_eq!((diagnostics[0].line, diagnostics[0].col), (2, 26));
+  }
+
+  #[test]
+  fn reports_empty_branches() {
+    let source = "import * as GT from 'gt-next';\n\
+      export const el = <GT.Branch branch={role} admin=\" \" user={null} guest\n\
+      member={undefined} staff={name} owner={false}>Nobody</GT.Branch>;\n";
+    let diagnostics = branch_diagnostics(source);
+    let empty: Vec<&str> = diagnostics
+      .iter()
+      .map(|diagnostic| diagnostic.message.split('"').nth(1).unwrap())
+      .collect();
+
+    assert_eq!(codes(&diagnostics), vec!["GT018"; 5]);
+    // Dynamic content is dropped from the hash too, but it renders
+    assert_eq!(empty, vec!["admin", "user", "guest", "member", "owner"]);
+  }
+
+  #[test]
+  fn reports_children_a_literal_key_skips() {
+    let source = "import { Branch } from 'gt-next';\n\
+      export const a = <Branch branch=\"admin\" admin=\"Admin\">Guest</Branch>;\n\
+      export const b = <Branch branch=\"admin\" admin=\"Admin\" />;\n";
+    let diagnostics = branch_diagnostics(source);
+
+    assert_eq!(codes(&diagnostics), vec!["GT019"]);
+    assert_eq!(diagnostics[0].line, 2);
+  }
+
+  #[test]
+  fn skips_branches_with_spread_props() {
+    let source = "import { Branch } from 'gt-next';\n\
+      export const el = <Branch {...props} admin=\"\">Guest</Branch>;\n";
+
+    assert!(branch_diagnostics(source).is_empty());
+  }
+}
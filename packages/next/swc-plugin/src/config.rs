@@ -1,63 +0,0 @@
-use crate::logging::LogLevel;
-use serde::Deserialize;
-
-// For plugin configuration and settings
-#[derive(Debug)]
-pub struct PluginSettings {
-  /// Log level for the plugin
-  pub log_level: LogLevel,
-  /// Experimental feature: inject compile-time hash attributes
-  pub compile_time_hash: bool,
-  /// Optional filename for better error messages
-  pub filename: Option<String>,
-  /// Disable dynamic content check
-  pub disable_build_checks: bool,
-  /// When true, bare variables/calls in JSX expression containers are allowed
-  pub autoderive_jsx: bool,
-  /// When true, bare variables/calls in template literals and concatenations are allowed
-  pub autoderive_strings: bool,
-}
-
-impl PluginSettings {
-  pub fn new(log_level: LogLevel, compile_time_hash: bool, filename: Option<String>, disable_build_checks: bool, autoderive_jsx: bool, autoderive_strings: bool) -> Self {
-    Self {
-      log_level,
-      compile_time_hash,
-      filename,
-      disable_build_checks,
-      autoderive_jsx,
-      autoderive_strings,
-    }
-  }
-}
-
-/// Plugin configuration options
-#[derive(Deserialize, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct PluginConfig {
-  #[serde(default)]
-  pub log_level: LogLevel,
-  #[serde(default)]
-  pub compile_time_hash: bool,
-  #[serde(default)]
-  pub filename: Option<String>,
-  #[serde(default)]
-  pub disable_build_checks: bool,
-  #[serde(default)]
-  pub autoderive_jsx: bool,
-  #[serde(default)]
-  pub autoderive_strings: bool,
-}
-
-impl Default for PluginConfig {
-  fn default() -> Self {
-    Self {
-      log_level: LogLevel::Warn,
-      compile_time_hash: false,
-      filename: None,
-      disable_build_checks: false,
-      autoderive_jsx: false,
-      autoderive_strings: false,
-    }
-  }
-}
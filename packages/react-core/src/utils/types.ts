@@ -7,6 +7,7 @@ import type {
   VariableTransformationSuffix,
   TransformationPrefix,
   InjectionType,
+  StringFormat,
 } from 'generaltranslation/types';
 import React from 'react';
 
@@ -121,6 +122,7 @@ export type _Message = {
   $id?: string;
   $context?: string;
   $maxChars?: number;
+  $format?: StringFormat;
   $_hash?: string;
 };
 export type _Messages = _Message[];